@@ -0,0 +1,104 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+
+use serde::Deserialize;
+
+const TITLES_PATH: &str = "src/game_list/titles.jsonl";
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct Title {
+    #[serde(rename = "TitleID")]
+    title_id: String,
+
+    #[serde(rename = "HBTitleID")]
+    hb_title_id: String,
+
+    name: String,
+    title_type: String,
+
+    #[serde(rename = "MediaIDCount")]
+    media_id_count: String,
+
+    newest_content: String,
+}
+
+fn main() {
+    generate_titles();
+}
+
+/// Writes the title list as two files: a fixed-size record index, sorted
+/// by title ID, and a string pool the records point into. The pool holds
+/// most of the bytes, so it is what gets compressed.
+///
+/// Record layout, all little-endian:
+///
+/// | offset | size | field                                   |
+/// |--------|------|-----------------------------------------|
+/// | 0x00   | 4    | title ID                                |
+/// | 0x04   | 4    | homebrew title ID, or 0                 |
+/// | 0x08   | 4    | media ID count                          |
+/// | 0x0c   | 4    | offset of the name in the pool          |
+/// | 0x10   | 2    | name length                             |
+/// | 0x12   | 1    | newest content length, right after name |
+/// | 0x13   | 1    | title type, 0 if unknown                |
+fn generate_titles() {
+    println!("cargo::rerun-if-changed={TITLES_PATH}");
+
+    let titles = fs::read_to_string(TITLES_PATH).expect("error reading titles DB");
+
+    let mut titles: Vec<Title> = titles
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| serde_json::from_str(line).expect("error parsing titles DB"))
+        .collect();
+
+    titles.sort_by_key(|t| parse_id(&t.title_id));
+    titles.dedup_by_key(|t| parse_id(&t.title_id));
+
+    let mut index = Vec::with_capacity(titles.len() * 0x14);
+    let mut pool = String::new();
+
+    for title in titles.iter() {
+        let title_type: u8 = match title.title_type.as_str() {
+            "360" => 1,
+            "XBLA" => 2,
+            "Xbox1" => 3,
+            "HomeBrew" => 4,
+            _ => 0,
+        };
+
+        let name = title.name.trim();
+        let pool_offset = pool.len() as u32;
+        pool.push_str(name);
+        pool.push_str(&title.newest_content);
+
+        index.extend(parse_id(&title.title_id).to_le_bytes());
+        index.extend(parse_id(&title.hb_title_id).to_le_bytes());
+        index.extend(
+            title
+                .media_id_count
+                .parse::<u32>()
+                .unwrap_or(0)
+                .to_le_bytes(),
+        );
+        index.extend(pool_offset.to_le_bytes());
+        index.extend(u16::try_from(name.len()).unwrap().to_le_bytes());
+        index.push(u8::try_from(title.newest_content.len()).unwrap());
+        index.push(title_type);
+    }
+
+    #[cfg(feature = "compressed-db")]
+    let pool = miniz_oxide::deflate::compress_to_vec(pool.as_bytes(), 10);
+    #[cfg(not(feature = "compressed-db"))]
+    let pool = pool.into_bytes();
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("titles.idx"), index).expect("error writing titles DB");
+    fs::write(Path::new(&out_dir).join("titles.pool"), pool).expect("error writing titles DB");
+}
+
+fn parse_id(id: &str) -> u32 {
+    u32::from_str_radix(id, 16).unwrap_or_else(|_| panic!("invalid title ID {id:?} in titles DB"))
+}
@@ -0,0 +1,63 @@
+use std::fs::File;
+use std::io::BufReader;
+use std::path::PathBuf;
+
+use anyhow::{Context, Error};
+
+use clap::Parser;
+
+use iso2god::executable::TitleInfo;
+use iso2god::stfs;
+
+#[derive(Parser)]
+#[command(author, version, about, long_about = None)]
+#[command(color = clap::ColorChoice::Never)]
+struct Cli {
+    /// STFS package (CON, LIVE or PIRS)
+    source_package: PathBuf,
+}
+
+fn main() -> Result<(), Error> {
+    let args = Cli::parse();
+
+    let package_file = File::open(&args.source_package).context("error opening package file")?;
+
+    let mut package = stfs::StfsReader::read(BufReader::new(package_file))
+        .context("error reading STFS package")?;
+
+    let header = &package.header;
+    println!("{:?} package, {}", header.package_type, header.content_type);
+    println!("title ID: {:08X}", header.execution_info.title_id);
+    println!("title name: {}", header.title_name);
+    for (locale, name) in header.display_names.iter() {
+        println!("display name ({locale}): {name}");
+    }
+    println!("{:?}", package.volume_descriptor);
+
+    match package.verify() {
+        Ok(()) => println!("hashes: ok"),
+        Err(e) => println!("hashes: {e:#}"),
+    }
+    match package.header.verify_signature() {
+        Ok(true) => println!("signature: ok"),
+        Ok(false) => println!("signature: invalid"),
+        Err(e) => println!("signature: {e:#}"),
+    }
+
+    for index in 0..package.files.len() {
+        let entry = &package.files[index];
+        if !entry.is_directory() {
+            println!("{:9} {}", entry.size, package.entry_path(index));
+        }
+    }
+
+    if let Ok(title_info) = TitleInfo::from_stfs(&mut package) {
+        let exe_info = &title_info.execution_info;
+        println!(
+            "executable: title ID {:08X}, media ID {:08X}, version {:08X}",
+            exe_info.title_id, exe_info.media_id, exe_info.version
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use std::fs::{self, File};
+use std::io::BufWriter;
+use std::path::PathBuf;
+
+use anyhow::{Context, Error};
+
+use clap::Parser;
+
+use iso2god::god::{self, ContentType, PackageType};
+use iso2god::stfs;
+
+#[derive(Parser)]
+#[command(author, version, about, long_about = None)]
+#[command(color = clap::ColorChoice::Never)]
+struct Cli {
+    /// Folder to package
+    source_dir: PathBuf,
+
+    /// STFS package file to write
+    dest_package: PathBuf,
+
+    /// Title ID, in hex
+    #[arg(long, value_parser = parse_hex)]
+    title_id: u32,
+
+    /// Content type, like "Marketplace Content", "Installer" or "0x000D0000"
+    #[arg(long, default_value = "Marketplace Content")]
+    content_type: ContentType,
+
+    /// Name shown in the dashboard
+    #[arg(long)]
+    display_name: String,
+
+    /// PNG, JPEG or BMP icon; resized to fit
+    #[arg(long)]
+    icon: Option<PathBuf>,
+
+    /// Write a PIRS package instead of a LIVE one
+    #[arg(long)]
+    pirs: bool,
+}
+
+fn parse_hex(s: &str) -> Result<u32, std::num::ParseIntError> {
+    u32::from_str_radix(s.trim_start_matches("0x"), 16)
+}
+
+fn main() -> Result<(), Error> {
+    let args = Cli::parse();
+
+    let package_type = if args.pirs {
+        PackageType::Pirs
+    } else {
+        PackageType::Live
+    };
+
+    let mut header = god::ConHeaderBuilder::new()
+        .with_package_type(package_type)
+        .with_content_type(args.content_type)
+        .with_title_id(args.title_id)
+        .with_game_title(&args.display_name);
+
+    if let Some(icon_path) = &args.icon {
+        let icon = god::load_icon(&fs::read(icon_path).context("error reading icon")?)?;
+        header = header.with_game_icon(Some(&icon))?;
+    }
+
+    let dest_package = File::create(&args.dest_package).context("error creating package file")?;
+
+    stfs::write_stfs_package(&args.source_dir, header, BufWriter::new(dest_package))
+        .context("error writing package")?;
+
+    let package = File::open(&args.dest_package)?;
+    stfs::StfsReader::read(package)?
+        .verify()
+        .context("written package does not verify")?;
+
+    println!("done");
+
+    Ok(())
+}
@@ -1,18 +1,17 @@
-use std::io::{Seek, SeekFrom, Write};
+use std::io::{Read, Seek, SeekFrom, Write};
 
 use std::fs;
 use std::fs::File;
 use std::path::{Path, PathBuf};
-use std::sync::atomic::{AtomicUsize, Ordering};
 
-use anyhow::{Context, Error};
+use anyhow::{Context, Error, bail};
 
-use clap::{Parser, ValueEnum, arg, command};
+use clap::{Parser, ValueEnum};
 
-use rayon::prelude::*;
-
-use iso2god::executable::TitleInfo;
-use iso2god::god::ContentType;
+use iso2god::executable::integrity::XexPublicKey;
+use iso2god::executable::{Executable, TitleInfo};
+use iso2god::god::{ContentType, Locale};
+use iso2god::metadata::{self, ProviderChain};
 use iso2god::{game_list, god, iso};
 
 #[derive(Parser)]
@@ -20,10 +19,73 @@ use iso2god::{game_list, god, iso};
 #[command(color = clap::ColorChoice::Never)]
 struct Cli {
     /// ISO file to convert
-    source_iso: PathBuf,
+    #[arg(required_unless_present_any = ["lookup", "update_header", "recheck"])]
+    source_iso: Option<PathBuf>,
 
     /// A folder to write resulting GOD files to
-    dest_dir: PathBuf,
+    #[arg(required_unless_present_any = ["lookup", "update_header", "recheck"])]
+    dest_dir: Option<PathBuf>,
+
+    /// Do not convert anything, just look up a title by name or title ID
+    #[arg(long, value_name = "NAME_OR_ID")]
+    lookup: Option<String>,
+
+    /// Do not convert anything, just update the metadata in the header of an existing
+    /// GOD package, leaving its data parts untouched
+    #[arg(
+        verbatim_doc_comment,
+        long,
+        value_name = "FILE",
+        conflicts_with_all = ["source_iso", "dest_dir", "lookup", "recheck"]
+    )]
+    update_header: Option<PathBuf>,
+
+    /// Do not convert anything, just check the files of an existing GOD package
+    /// against the checksum manifest written with --manifest
+    #[arg(
+        verbatim_doc_comment,
+        long,
+        value_name = "FILE",
+        conflicts_with_all = ["source_iso", "dest_dir", "lookup"]
+    )]
+    recheck: Option<PathBuf>,
+
+    /// Set the media ID, with --update-header
+    #[arg(long, value_name = "ID", value_parser = parse_hex_u32, requires = "update_header")]
+    media_id: Option<u32>,
+
+    /// Set the disc number and count, like "2/3", with --update-header
+    #[arg(long, value_name = "N/COUNT", value_parser = parse_disc, requires = "update_header")]
+    disc: Option<(u8, u8)>,
+
+    /// Extra title database (JSONL like the built-in one, or CSV with
+    /// title_id,name[,title_type] rows) to override or extend the built-in one
+    #[arg(
+        verbatim_doc_comment,
+        long,
+        value_name = "FILE",
+        env = "ISO2GOD_TITLE_DB"
+    )]
+    title_db: Option<PathBuf>,
+
+    /// Media ID database (JSONL) to identify disc revisions and regions; there is no built-in one
+    #[arg(long, value_name = "FILE", env = "ISO2GOD_MEDIA_DB")]
+    media_db: Option<PathBuf>,
+
+    /// Look up title names on XboxUnity if no local source knows them
+    #[cfg(feature = "online")]
+    #[arg(long)]
+    online: bool,
+
+    /// Base URL of an XboxUnity-compatible server to use with --online
+    #[cfg(feature = "online")]
+    #[arg(long, value_name = "URL", default_value = metadata::XBOX_UNITY_URL)]
+    metadata_url: String,
+
+    /// Folder to cache online title metadata in
+    #[cfg(feature = "online")]
+    #[arg(long, value_name = "DIR", env = "ISO2GOD_METADATA_CACHE")]
+    metadata_cache: Option<PathBuf>,
 
     /// Do not convert anything, just print the title info
     #[arg(long)]
@@ -33,6 +95,110 @@ struct Cli {
     #[arg(long, value_name = "TITLE")]
     game_title: Option<String>,
 
+    /// Set the game description shown on the dashboard
+    #[arg(long, value_name = "TEXT")]
+    description: Option<String>,
+
+    /// Set the game publisher shown on the dashboard
+    #[arg(long, value_name = "NAME")]
+    publisher: Option<String>,
+
+    /// PNG, JPEG or BMP image to use as the game icon instead of the one in the executable;
+    /// it is resized to 64x64
+    #[arg(long, value_name = "FILE")]
+    icon: Option<PathBuf>,
+
+    /// Image to use as the thumbnail of the package alone, instead of --icon
+    #[arg(long, value_name = "FILE")]
+    content_icon: Option<PathBuf>,
+
+    /// Image to use as the thumbnail of the game alone, instead of --icon
+    #[arg(long, value_name = "FILE")]
+    title_icon: Option<PathBuf>,
+
+    /// Who may use the package: "anyone", or an XUID or console ID in hex;
+    /// can be given up to 16 times
+    #[arg(
+        verbatim_doc_comment,
+        long = "license",
+        value_name = "ID",
+        value_parser = parse_license
+    )]
+    licenses: Vec<god::LicenseEntry>,
+
+    /// XUID of the profile owning the package, in hex
+    #[arg(long, value_name = "XUID", value_parser = parse_hex_u64)]
+    profile_id: Option<u64>,
+
+    /// ID of the console the package was made on, as 10 hex digits
+    #[arg(long, value_name = "ID", value_parser = parse_hex_bytes::<{ god::CONSOLE_ID_SIZE }>)]
+    console_id: Option<[u8; god::CONSOLE_ID_SIZE]>,
+
+    /// ID of the storage device the package is bound to, as 40 hex digits
+    #[arg(long, value_name = "ID", value_parser = parse_hex_bytes::<{ god::DEVICE_ID_SIZE }>)]
+    device_id: Option<[u8; god::DEVICE_ID_SIZE]>,
+
+    /// Transfer flags, like "PROFILE_ID_TRANSFER | DEVICE_ID_TRANSFER" or "0xc0"
+    #[arg(long, value_name = "FLAGS", value_parser = parse_transfer_flags)]
+    transfer_flags: Option<god::TransferFlags>,
+
+    /// Language to pick the game title in, like "de" or "ja", if the title database
+    /// or the SPA resource of the executable has it
+    #[arg(long, value_name = "LOCALE", env = "ISO2GOD_LOCALE")]
+    locale: Option<Locale>,
+
+    /// Abort the conversion if the executable fails integrity verification,
+    /// or does not match the title type from the title database;
+    /// with --update-header, abort if the package does not match its part files
+    #[arg(verbatim_doc_comment, long)]
+    strict: bool,
+
+    /// XEX signing public key, as an XECRYPT_RSAPUB_2048 dump, to check executable signatures;
+    /// it is not bundled, so without it the signature check is skipped
+    #[arg(
+        verbatim_doc_comment,
+        long,
+        value_name = "FILE",
+        env = "ISO2GOD_XEX_PUBLIC_KEY"
+    )]
+    xex_public_key: Option<PathBuf>,
+
+    /// Path to the executable inside the image, if it is not default.xex or default.xbe
+    #[arg(long, value_name = "PATH")]
+    executable: Option<String>,
+
+    /// Write an Installed Game package instead of a Games on Demand one
+    #[arg(long)]
+    installed_game: bool,
+
+    /// Decrypted keyvault to sign the package with, making it a CON package of that console
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["console_certificate", "console_private_key"])]
+    keyvault: Option<PathBuf>,
+
+    /// Console certificate to sign the package with; needs --console-private-key
+    #[arg(long, value_name = "FILE", requires = "console_private_key")]
+    console_certificate: Option<PathBuf>,
+
+    /// Console private key to sign the package with; needs --console-certificate
+    #[arg(long, value_name = "FILE", requires = "console_certificate")]
+    console_private_key: Option<PathBuf>,
+
+    /// Write a checksum manifest of the source image and the package next to the CON header;
+    /// the source is hashed as it is converted, the part files are read once more afterwards
+    #[arg(verbatim_doc_comment, long)]
+    manifest: bool,
+
+    /// CON header to start from instead of the built-in template, keeping the fields
+    /// iso2god does not set, but not its signature;
+    /// an existing GOD header or package works too
+    #[arg(
+        verbatim_doc_comment,
+        long,
+        value_name = "FILE",
+        conflicts_with = "update_header"
+    )]
+    header_template: Option<PathBuf>,
+
     /// Whether to trim off unused space from the ISO image;
     /// passing no --trim flag at all is equivalent to "from-end"
     #[arg(
@@ -45,9 +211,11 @@ struct Cli {
     )]
     trim: Option<TrimMode>,
 
-    /// Number of worker threads to use
-    #[arg(long, short = 'j', value_name = "N", default_value_t = 1)]
-    num_threads: usize,
+    /// Number of threads to compute the part hash tables with; the source is read, and the
+    /// part files are written and checksummed, by one thread each, so this is safe for
+    /// hard drives too. Defaults to the number of CPUs
+    #[arg(verbatim_doc_comment, long, short = 'j', value_name = "N")]
+    num_threads: Option<usize>,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Default, ValueEnum)]
@@ -65,51 +233,201 @@ enum TrimMode {
 fn main() -> Result<(), Error> {
     let args = Cli::parse();
 
-    if args.num_threads == 1 {
-        eprintln!(
-            "The default number of threads was changed to 1 because of the problems witn Windows and/or hard drives."
-        );
-        eprintln!(
-            "If you don't use Windows or use and SSD, might be worth increasing it with the -j <N> flag!"
+    let mut title_db = game_list::TitleDatabase::new();
+    if let Some(title_db_path) = &args.title_db {
+        title_db
+            .load_overlay(title_db_path)
+            .with_context(|| format!("error loading {}", title_db_path.display()))?;
+    }
+    if let Some(media_db_path) = &args.media_db {
+        title_db
+            .load_media_overlay(media_db_path)
+            .with_context(|| format!("error loading {}", media_db_path.display()))?;
+    }
+
+    if let Some(query) = &args.lookup {
+        lookup_title(&title_db, query);
+        return Ok(());
+    }
+
+    if let Some(header_path) = &args.recheck {
+        return recheck(header_path);
+    }
+
+    let console_keys = match (
+        &args.keyvault,
+        &args.console_certificate,
+        &args.console_private_key,
+    ) {
+        (Some(keyvault), _, _) => Some(god::ConsoleKeys::load_keyvault(keyvault)?),
+        (None, Some(certificate), Some(private_key)) => {
+            Some(god::ConsoleKeys::load(certificate, private_key)?)
+        }
+        _ => None,
+    };
+
+    let con_header_template = match &args.header_template {
+        Some(path) => {
+            let mut template = File::open(path).context("error opening header template")?;
+            let mut magic = [0_u8; 4];
+            template.read_exact(&mut magic)?;
+            if god::PackageType::from_magic(&magic) == Some(god::PackageType::Con)
+                && console_keys.is_none()
+            {
+                println!(
+                    " Warning: the header template is a CON package, pass console keys to sign the package as one"
+                );
+            }
+            template.seek(SeekFrom::Start(0))?;
+            Some(god::ConHeaderBuilder::from_template(template)?)
+        }
+        None => None,
+    };
+
+    if args.licenses.len() > god::LICENSE_ENTRY_COUNT {
+        bail!(
+            "--license can be given at most {} times",
+            god::LICENSE_ENTRY_COUNT
         );
     }
 
-    rayon::ThreadPoolBuilder::new()
-        .num_threads(args.num_threads)
-        .build_global()?;
+    let icons = Icons::load(&args)?;
+
+    if let Some(header_path) = &args.update_header {
+        return update_header(&args, title_db, header_path, console_keys, icons);
+    }
+
+    let source_iso_path = args.source_iso.as_deref().context("no source ISO given")?;
+    let dest_dir = args
+        .dest_dir
+        .as_deref()
+        .context("no destination folder given")?;
+
+    let num_threads = args
+        .num_threads
+        .or_else(|| std::thread::available_parallelism().ok().map(|n| n.get()))
+        .unwrap_or(1);
 
     println!("extracting ISO metadata");
 
-    let source_iso_file = File::open(&args.source_iso).context("error opening source ISO file")?;
+    let source_iso_file = File::open(source_iso_path).context("error opening source ISO file")?;
 
     let source_iso_file_meta =
-        fs::metadata(&args.source_iso).context("error reading source ISO file metadata")?;
+        fs::metadata(source_iso_path).context("error reading source ISO file metadata")?;
 
     let mut source_iso =
         iso::IsoReader::read(source_iso_file).context("error reading source ISO")?;
 
-    let title_info =
-        TitleInfo::from_image(&mut source_iso).context("error reading image executable")?;
+    let title_info = if let Some(executable) = &args.executable {
+        let path = executable.replace('/', "\\");
+        TitleInfo::from_image_executable(&mut source_iso, &path.as_str().into())
+            .context("error reading image executable")?
+            .with_context(|| format!("no executable found at {executable}"))?
+    } else {
+        TitleInfo::from_image(&mut source_iso).context("error reading image executable")?
+    };
+
+    let exe_info = title_info.execution_info.clone();
+    let content_type = match title_info.content_type {
+        ContentType::XboxOriginal if args.installed_game => {
+            bail!("original Xbox titles can not be written as installed games");
+        }
+        _ if args.installed_game => ContentType::InstalledGame,
+        content_type => content_type,
+    };
+
+    let media = title_db
+        .find_media_by_id(exe_info.title_id, exe_info.media_id)
+        .cloned();
+
+    let providers = metadata_providers(&args, title_db, Some(&title_info))?;
+    let mut title_metadata = match providers.lookup(exe_info.title_id) {
+        Ok(answer) => answer,
+        Err(e) => {
+            eprintln!("warning: error looking up title metadata: {e:#}");
+            None
+        }
+    };
 
-    let exe_info = title_info.execution_info;
-    let content_type = title_info.content_type;
+    // databases rarely know every language the game itself does
+    if let (Some(answer), Some(spa)) = (&mut title_metadata, &title_info.spa) {
+        answer
+            .metadata
+            .add_missing_localized_names(&spa.title_names);
+    }
 
     {
         let title_id = format!("{:08X}", exe_info.title_id);
-        let name = game_list::find_title_by_id(exe_info.title_id).unwrap_or("(unknown)".to_owned());
 
         println!("Title ID: {title_id}");
-        println!("    Name: {name}");
-        match content_type {
-            ContentType::GamesOnDemand => println!("    Type: Games on Demand"),
-            ContentType::XboxOriginal => println!("    Type: Xbox Original"),
+        match &title_metadata {
+            Some(answer) => println!(
+                "    Name: {} (from {})",
+                answer.metadata.name_for(args.locale),
+                answer.provider
+            ),
+            None => println!("    Name: (unknown)"),
+        }
+        println!("    Type: {content_type}");
+        if let Some(regions) = title_info.executable.regions() {
+            println!("  Region: {regions}");
         }
+        if let Executable::Xex(_) = &title_info.executable {
+            println!("Media ID: {:08X}", exe_info.media_id);
+        }
+        match &media {
+            Some(media) => println!("    Disc: {media}"),
+            None if exe_info.disc_count > 1 => println!(
+                "    Disc: {} of {}",
+                exe_info.disc_number, exe_info.disc_count
+            ),
+            None => {}
+        }
+    }
+
+    let title_type_mismatch = title_metadata
+        .as_ref()
+        .and_then(|answer| answer.metadata.title_type)
+        .filter(|title_type| !title_type.matches_content_type(content_type));
+
+    if let Some(title_type) = title_type_mismatch {
+        let executable_name = title_info.executable_path.components.join("\\");
+        println!(
+            " Warning: this title ID is listed as {title_type}, but the image has {executable_name};"
+        );
+        println!("          the image may be mislabelled or modified");
+    }
+
+    let integrity_report = {
+        let executable = source_iso
+            .read_entry(&title_info.executable_path)
+            .context("error reading image executable")?
+            .context("image executable disappeared")?;
+        let xex_public_key = match &args.xex_public_key {
+            Some(path) => Some(XexPublicKey::load(path)?),
+            None => None,
+        };
+        title_info
+            .executable
+            .verify(&executable, xex_public_key.as_ref())
+    };
+
+    for check in integrity_report.checks.iter() {
+        println!("  Verify: {check}");
     }
 
     if args.dry_run {
         return Ok(());
     }
 
+    if args.strict && !integrity_report.is_ok() {
+        bail!("executable failed integrity verification");
+    }
+
+    if args.strict && title_type_mismatch.is_some() {
+        bail!("title type does not match the image executable");
+    }
+
     let data_size = if args.trim.unwrap_or_default() == TrimMode::FromEnd {
         source_iso.get_max_used_prefix_size()
     } else {
@@ -120,7 +438,13 @@ fn main() -> Result<(), Error> {
     let block_count = data_size.div_ceil(god::BLOCK_SIZE);
     let part_count = block_count.div_ceil(god::BLOCKS_PER_PART);
 
-    let file_layout = god::FileLayout::new(&args.dest_dir, &exe_info, content_type);
+    let root_offset = source_iso.volume_descriptor.root_offset;
+    let read_size = god::data_volume_read_size(
+        block_count,
+        source_iso_file_meta.len().saturating_sub(root_offset),
+    );
+
+    let file_layout = god::FileLayout::new(dest_dir, &exe_info, content_type);
 
     println!("clearing data directory");
 
@@ -128,46 +452,36 @@ fn main() -> Result<(), Error> {
 
     println!("writing part files:  0/{part_count}");
 
-    let progress = AtomicUsize::new(0);
-
-    (0..part_count).into_par_iter().try_for_each(|part_index| {
-        let mut iso_data_volume = File::open(&args.source_iso)?;
-        iso_data_volume.seek(SeekFrom::Start(source_iso.volume_descriptor.root_offset))?;
-
-        let part_file = file_layout.part_file_path(part_index);
-
-        let part_file = File::options()
-            .write(true)
-            .create(true)
-            .truncate(true)
-            .open(&part_file)
-            .context("error creating part file")?;
-
-        god::write_part(iso_data_volume, part_index, part_file)
-            .context("error writing part file")?;
-
-        let cur = 1 + progress.fetch_add(1, Ordering::Relaxed);
-        println!("writing part files: {cur:2}/{part_count}");
-
-        Ok::<_, anyhow::Error>(())
-    })?;
-
-    println!("calculating MHT hash chain");
-
-    let mut mht =
-        read_part_mht(&file_layout, part_count - 1).context("error reading part file MHT")?;
-
-    for prev_part_index in (0..part_count - 1).rev() {
-        let mut prev_mht =
-            read_part_mht(&file_layout, prev_part_index).context("error reading part file MHT")?;
-
-        prev_mht.add_hash(&mht.digest());
-
-        write_part_mht(&file_layout, prev_part_index, &prev_mht)
-            .context("error writing part file MHT")?;
+    let mut source_iso_data_volume = File::open(source_iso_path)?;
+
+    // for the manifest, the whole source is hashed on the way, rather than read again
+    let (mhts, source_checksums) = if args.manifest {
+        let mut source = god::ChecksumReader::new(source_iso_data_volume);
+        source.skip(root_offset)?;
+        let mhts = write_part_files(
+            &file_layout,
+            part_count,
+            (&mut source).take(read_size),
+            num_threads,
+        )?;
+
+        let name = source_iso_path.file_name().unwrap_or_default();
+        let checksums = source
+            .finish(name.to_string_lossy().into_owned())
+            .context("error reading source ISO file")?;
+        (mhts, Some(checksums))
+    } else {
+        source_iso_data_volume.seek(SeekFrom::Start(root_offset))?;
+        let mhts = write_part_files(
+            &file_layout,
+            part_count,
+            source_iso_data_volume.take(read_size),
+            num_threads,
+        )?;
+        (mhts, None)
+    };
 
-        mht = prev_mht;
-    }
+    let mht = &mhts[0];
 
     let last_part_size = fs::metadata(file_layout.part_file_path(part_count - 1))
         .map(|m| m.len())
@@ -175,21 +489,37 @@ fn main() -> Result<(), Error> {
 
     println!("writing con header");
 
-    let mut con_header = god::ConHeaderBuilder::new()
+    let mut con_header = con_header_template
+        .unwrap_or_default()
         .with_execution_info(&exe_info)
-        .with_block_counts(block_count as u32, 0)
+        .with_svod_volume_descriptor(&god::SvodVolumeDescriptor::new(
+            mht.digest(),
+            block_count as u32,
+        ))
         .with_data_parts_info(
             part_count as u32,
             last_part_size + (part_count - 1) * god::BLOCK_SIZE * 0xa290,
         )
-        .with_content_type(content_type)
-        .with_mht_hash(&mht.digest());
+        .with_content_type(content_type);
+
+    if let Some(game_title) = &args.game_title {
+        con_header = con_header.with_game_title(game_title);
+    } else if let Some(answer) = &title_metadata {
+        con_header = con_header.with_game_title(answer.metadata.name_for(args.locale));
 
-    let game_title = args
-        .game_title
-        .or(game_list::find_title_by_id(exe_info.title_id));
-    if let Some(game_title) = game_title {
-        con_header = con_header.with_game_title(&game_title);
+        for (locale, name) in answer.metadata.localized_names.iter() {
+            con_header = con_header.with_localized_game_title(*locale, name);
+        }
+    }
+
+    if let Some(icon) = icons.game.as_ref().or(title_info.icon.as_ref()) {
+        con_header = con_header.with_game_icon(Some(icon))?;
+    }
+
+    con_header = with_package_options(con_header, &args, &icons)?;
+
+    if let Some(console_keys) = console_keys {
+        con_header = con_header.with_console_keys(console_keys);
     }
 
     let con_header = con_header.finalize();
@@ -205,32 +535,372 @@ fn main() -> Result<(), Error> {
         .write_all(&con_header)
         .context("error writing con header file")?;
 
+    if args.manifest {
+        println!("writing checksum manifest");
+
+        let header_path = file_layout.con_header_file_path();
+        let manifest = god::Manifest::create(&header_path, source_checksums)?;
+        manifest.save(&god::Manifest::path_for(&header_path))?;
+    }
+
     println!("done");
 
     Ok(())
 }
 
-fn ensure_empty_dir(path: &Path) -> Result<(), Error> {
-    if fs::exists(path)? {
-        fs::remove_dir_all(path)?;
+fn write_part_files<R: Read + Send>(
+    file_layout: &god::FileLayout,
+    part_count: u64,
+    data_volume: R,
+    num_threads: usize,
+) -> Result<Vec<god::HashList>, Error> {
+    god::write_parts(
+        data_volume,
+        num_threads,
+        |part_index| {
+            File::options()
+                .write(true)
+                .create(true)
+                .truncate(true)
+                .open(file_layout.part_file_path(part_index))
+                .context("error creating part file")
+        },
+        |part_index| println!("writing part files: {:2}/{part_count}", part_index + 1),
+    )
+    .context("error writing part files")
+}
+
+fn update_header(
+    args: &Cli,
+    title_db: game_list::TitleDatabase,
+    header_path: &Path,
+    console_keys: Option<god::ConsoleKeys>,
+    icons: Icons,
+) -> Result<(), Error> {
+    let mut header_file = File::options()
+        .read(true)
+        .write(!args.dry_run)
+        .open(header_path)
+        .context("error opening con header file")?;
+
+    let header = god::ConHeader::read(&mut header_file).context("error reading con header")?;
+    let title_id = header.execution_info.title_id;
+
+    if header.volume_type == god::VolumeType::Svod {
+        let validation = header.svod_volume_descriptor().and_then(|descriptor| {
+            descriptor.validate_parts(&god::data_dir_for_header(header_path))
+        });
+
+        if let Err(e) = validation {
+            if args.strict {
+                bail!("package does not match its part files: {e:#}");
+            }
+            println!(" Warning: package does not match its part files: {e:#}");
+        }
+    }
+
+    let title_metadata = if args.game_title.is_some() {
+        None
+    } else {
+        let providers = metadata_providers(args, title_db, None)?;
+        match providers.lookup(title_id) {
+            Ok(answer) => answer,
+            Err(e) => {
+                eprintln!("warning: error looking up title metadata: {e:#}");
+                None
+            }
+        }
     };
-    fs::create_dir_all(path)?;
+
+    let mut con_header = god::ConHeaderBuilder::from_header(&header);
+
+    if let Some(game_title) = &args.game_title {
+        con_header = con_header.with_game_title(game_title);
+    } else if let Some(answer) = &title_metadata {
+        con_header = con_header.with_game_title(answer.metadata.name_for(args.locale));
+
+        for (locale, name) in answer.metadata.localized_names.iter() {
+            con_header = con_header.with_localized_game_title(*locale, name);
+        }
+    }
+
+    if let Some(icon) = &icons.game {
+        con_header = con_header.with_game_icon(Some(icon))?;
+    }
+
+    con_header = with_package_options(con_header, args, &icons)?;
+
+    if let Some(media_id) = args.media_id {
+        con_header = con_header.with_media_id(media_id);
+    }
+
+    if let Some((disc_number, disc_count)) = args.disc {
+        con_header = con_header.with_disc_info(disc_number, disc_count);
+    }
+
+    match console_keys {
+        Some(console_keys) => con_header = con_header.with_console_keys(console_keys),
+        None if header.package_type == god::PackageType::Con => {
+            println!(
+                " Warning: the package signature will no longer match, pass console keys to re-sign it"
+            );
+        }
+        None => {}
+    }
+
+    let con_header = con_header.finalize();
+    let updated = god::ConHeader::read(&con_header[..]).context("error re-reading con header")?;
+
+    println!("Title ID: {title_id:08X}");
+    match &title_metadata {
+        Some(answer) => println!(
+            "    Name: {} (from {})",
+            updated.title_name, answer.provider
+        ),
+        None => println!("    Name: {}", updated.title_name),
+    }
+    println!("Media ID: {:08X}", updated.execution_info.media_id);
+    if updated.execution_info.disc_count > 1 {
+        println!(
+            "    Disc: {} of {}",
+            updated.execution_info.disc_number, updated.execution_info.disc_count
+        );
+    }
+
+    if args.dry_run {
+        return Ok(());
+    }
+
+    println!("writing con header");
+
+    // only the header is rewritten, whatever follows it stays as it is
+    header_file.seek(SeekFrom::Start(0))?;
+    header_file
+        .write_all(&con_header)
+        .context("error writing con header file")?;
+    drop(header_file);
+
+    let manifest_path = god::Manifest::path_for(header_path);
+    if manifest_path.exists() {
+        println!("updating checksum manifest");
+
+        let mut manifest = god::Manifest::load(&manifest_path)?;
+        manifest.update_header(header_path)?;
+        manifest.save(&manifest_path)?;
+    }
+
+    println!("done");
+
     Ok(())
 }
 
-fn read_part_mht(file_layout: &god::FileLayout, part_index: u64) -> Result<god::HashList, Error> {
-    let part_file = file_layout.part_file_path(part_index);
-    let mut part_file = File::options().read(true).open(part_file)?;
-    god::HashList::read(&mut part_file)
+fn recheck(header_path: &Path) -> Result<(), Error> {
+    let manifest = god::Manifest::load(&god::Manifest::path_for(header_path))?;
+
+    println!("Title ID: {}", manifest.title_id);
+    if let Some(source) = &manifest.source {
+        println!("  Source: {} (SHA-1 {})", source.name, source.sha1);
+    }
+
+    println!("checking {} part files", manifest.parts.len());
+    let mismatches = manifest.check(header_path)?;
+
+    for mismatch in &mismatches {
+        println!("{mismatch}");
+    }
+
+    if !mismatches.is_empty() {
+        bail!("{} files do not match the manifest", mismatches.len());
+    }
+
+    println!("done, everything matches");
+
+    Ok(())
 }
 
-fn write_part_mht(
-    file_layout: &god::FileLayout,
-    part_index: u64,
-    mht: &god::HashList,
-) -> Result<(), Error> {
-    let part_file = file_layout.part_file_path(part_index);
-    let mut part_file = File::options().write(true).open(part_file)?;
-    mht.write(&mut part_file)?;
+fn parse_hex_u32(s: &str) -> Result<u32, String> {
+    u32::from_str_radix(s.trim_start_matches("0x"), 16).map_err(|e| e.to_string())
+}
+
+fn parse_hex_u64(s: &str) -> Result<u64, String> {
+    u64::from_str_radix(s.trim_start_matches("0x"), 16).map_err(|e| e.to_string())
+}
+
+fn parse_hex_bytes<const N: usize>(s: &str) -> Result<[u8; N], String> {
+    let s = s.trim_start_matches("0x");
+    if s.len() != N * 2 {
+        return Err(format!("expected {} hex digits", N * 2));
+    }
+
+    let mut bytes = [0_u8; N];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = s
+            .get(i * 2..i * 2 + 2)
+            .and_then(|digits| u8::from_str_radix(digits, 16).ok())
+            .ok_or("invalid hex digits")?;
+    }
+    Ok(bytes)
+}
+
+fn parse_license(s: &str) -> Result<god::LicenseEntry, String> {
+    if s.eq_ignore_ascii_case("anyone") {
+        return Ok(god::LicenseEntry::ANYONE);
+    }
+    Ok(god::LicenseEntry {
+        licensee_id: parse_hex_u64(s)?,
+        license_bits: 0,
+        license_flags: 0,
+    })
+}
+
+fn parse_transfer_flags(s: &str) -> Result<god::TransferFlags, String> {
+    bitflags::parser::from_str(s).map_err(|e| e.to_string())
+}
+
+fn parse_disc(s: &str) -> Result<(u8, u8), String> {
+    let (number, count) = s.split_once('/').ok_or("expected N/COUNT, like 1/2")?;
+    let number: u8 = number
+        .parse()
+        .map_err(|e| format!("invalid disc number: {e}"))?;
+    let count: u8 = count
+        .parse()
+        .map_err(|e| format!("invalid disc count: {e}"))?;
+    if number == 0 || number > count {
+        return Err(format!("disc {number} of {count} makes no sense"));
+    }
+    Ok((number, count))
+}
+
+/// Images are loaded up front, so that a bad one fails before the conversion does
+struct Icons {
+    game: Option<Vec<u8>>,
+    content: Option<Vec<u8>>,
+    title: Option<Vec<u8>>,
+}
+
+impl Icons {
+    fn load(args: &Cli) -> Result<Icons, Error> {
+        let read = |path: &Option<PathBuf>| path.as_deref().map(read_icon).transpose();
+        Ok(Icons {
+            game: read(&args.icon)?,
+            content: read(&args.content_icon)?,
+            title: read(&args.title_icon)?,
+        })
+    }
+}
+
+/// The fields that are set the same way when converting and with --update-header
+fn with_package_options(
+    mut con_header: god::ConHeaderBuilder,
+    args: &Cli,
+    icons: &Icons,
+) -> Result<god::ConHeaderBuilder, Error> {
+    if let Some(icon) = &icons.content {
+        con_header = con_header.with_content_thumbnail(Some(icon))?;
+    }
+
+    if let Some(icon) = &icons.title {
+        con_header = con_header.with_title_thumbnail(Some(icon))?;
+    }
+
+    if let Some(description) = &args.description {
+        con_header = con_header.with_description(description);
+    }
+
+    if let Some(publisher) = &args.publisher {
+        con_header = con_header.with_publisher(publisher);
+    }
+
+    if !args.licenses.is_empty() {
+        con_header = con_header.with_licenses(&args.licenses)?;
+    }
+
+    if let Some(profile_id) = args.profile_id {
+        con_header = con_header.with_profile_id(profile_id);
+    }
+
+    if let Some(console_id) = &args.console_id {
+        con_header = con_header.with_console_id(console_id);
+    }
+
+    if let Some(device_id) = &args.device_id {
+        con_header = con_header.with_device_id(device_id);
+    }
+
+    if let Some(transfer_flags) = args.transfer_flags {
+        con_header = con_header.with_transfer_flags(transfer_flags);
+    }
+
+    Ok(con_header)
+}
+
+fn read_icon(path: &Path) -> Result<Vec<u8>, Error> {
+    let icon = fs::read(path).with_context(|| format!("error reading {}", path.display()))?;
+    god::load_icon(&icon).with_context(|| format!("error loading icon {}", path.display()))
+}
+
+fn lookup_title(title_db: &game_list::TitleDatabase, query: &str) {
+    let by_id = u32::from_str_radix(query, 16)
+        .ok()
+        .filter(|_| query.len() == 8)
+        .and_then(|title_id| title_db.find_entry_by_id(title_id));
+
+    let entries: Vec<_> = match by_id {
+        Some(entry) => vec![entry],
+        None => title_db
+            .search_titles(query, 10)
+            .into_iter()
+            .map(|m| m.entry)
+            .collect(),
+    };
+
+    if entries.is_empty() {
+        println!("no matching titles found");
+    }
+
+    for entry in entries {
+        match entry.title_type {
+            Some(title_type) => println!("{:08X}  {} ({title_type})", entry.title_id, entry.name),
+            None => println!("{:08X}  {}", entry.title_id, entry.name),
+        }
+    }
+}
+
+/// Local sources come first, so that the network is only hit for unknown titles
+fn metadata_providers(
+    args: &Cli,
+    title_db: game_list::TitleDatabase,
+    title_info: Option<&TitleInfo>,
+) -> Result<ProviderChain, Error> {
+    let mut providers = ProviderChain::new()
+        .with_provider(metadata::TitleDatabaseProvider { title_db })
+        .with_provider(metadata::BuiltInProvider);
+
+    if let Some(title_info) = title_info {
+        providers = providers.with_provider(metadata::ExecutableProvider::new(title_info));
+    }
+
+    #[cfg(feature = "online")]
+    if args.online {
+        let http = metadata::HttpProvider::new(&args.metadata_url)?;
+        providers = match &args.metadata_cache {
+            Some(cache_dir) => {
+                providers.with_provider(metadata::CachingProvider::new(http, cache_dir.clone()))
+            }
+            None => providers.with_provider(http),
+        };
+    }
+
+    #[cfg(not(feature = "online"))]
+    let _ = args;
+
+    Ok(providers)
+}
+
+fn ensure_empty_dir(path: &Path) -> Result<(), Error> {
+    if fs::exists(path)? {
+        fs::remove_dir_all(path)?;
+    };
+    fs::create_dir_all(path)?;
     Ok(())
 }
@@ -0,0 +1,574 @@
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Error, bail};
+use byteorder::{BE, ByteOrder};
+use rsa::traits::PublicKeyParts;
+use rsa::{BigUint, RsaPublicKey};
+use sha1::{Digest, Sha1};
+
+use super::xbe::{XbeHeader, XbeMediaTypes};
+use super::xex::{
+    XEX2_DEVKIT_KEY, XEX2_RETAIL_KEY, XexCompression, XexEncryption, XexHeader, XexSecurityInfo,
+    decrypt_payload, decrypt_session_key,
+};
+
+/// Salt of the XeCrypt signatures over XEX2 image info
+const XEX2_SIGNATURE_SALT: &[u8; 10] = b"XBOX360XEX";
+
+/// Size of an `XECRYPT_RSAPUB_2048` structure
+pub const XEX_PUBLIC_KEY_SIZE: usize = 0x110;
+
+/// The 2048 bit key XEX2 image info is signed with, like the retail one.
+/// No copy of it ships with iso2god, so it has to be supplied by the user.
+#[derive(Clone, Debug)]
+pub struct XexPublicKey {
+    key: RsaPublicKey,
+}
+
+impl XexPublicKey {
+    /// `bytes` is an `XECRYPT_RSAPUB_2048` structure, as dumped from a console
+    pub fn parse(bytes: &[u8]) -> Result<XexPublicKey, Error> {
+        if bytes.len() != XEX_PUBLIC_KEY_SIZE {
+            bail!("XEX public key must be {XEX_PUBLIC_KEY_SIZE:#x} bytes long");
+        }
+
+        // header: size in 64-bit words, public exponent, reserved
+        if BE::read_u32(&bytes[0x00..]) != 0x20 {
+            bail!("XEX public key is not a 2048 bit key");
+        }
+
+        let e = BigUint::from(BE::read_u32(&bytes[0x04..]));
+        let n = BigUint::from_bytes_be(&from_xecrypt(&bytes[0x10..]));
+        let key = RsaPublicKey::new(n, e).context("invalid XEX public key")?;
+
+        Ok(XexPublicKey { key })
+    }
+
+    pub fn load(path: &Path) -> Result<XexPublicKey, Error> {
+        Self::parse(&fs::read(path).context("error reading XEX public key")?)
+    }
+
+    /// XeCrypt signatures are RSA-PSS with SHA-1, a fixed salt and a zero padding string
+    fn verify(&self, digest: &[u8; 20], signature: &[u8; 0x100], salt: &[u8; 10]) -> bool {
+        let signature = BigUint::from_bytes_be(&from_xecrypt(signature));
+        if signature >= *self.key.n() {
+            return false;
+        }
+
+        let encoded = signature.modpow(self.key.e(), self.key.n()).to_bytes_be();
+        let Some(padding) = 0x100_usize.checked_sub(encoded.len()) else {
+            return false;
+        };
+        let mut encoded_message = [0_u8; 0x100];
+        encoded_message[padding..].copy_from_slice(&encoded);
+
+        let (masked_db, rest) = encoded_message.split_at(0xeb);
+        let (hash, trailer) = rest.split_at(20);
+        if trailer != [0xbc] {
+            return false;
+        }
+
+        // MGF1 with SHA-1
+        let mut db = masked_db.to_vec();
+        for (counter, chunk) in db.chunks_mut(20).enumerate() {
+            let mask = Sha1::new()
+                .chain_update(hash)
+                .chain_update((counter as u32).to_be_bytes())
+                .finalize();
+            for (b, m) in chunk.iter_mut().zip(mask) {
+                *b ^= m;
+            }
+        }
+        db[0] &= 0x7f;
+
+        let (padding, rest) = db.split_at(0xeb - 11);
+        if padding.iter().any(|b| *b != 0) || rest[0] != 0x01 || rest[1..] != salt[..] {
+            return false;
+        }
+
+        let expected = Sha1::new()
+            .chain_update([0_u8; 8])
+            .chain_update(digest)
+            .chain_update(salt)
+            .finalize();
+        expected[..] == *hash
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CheckStatus {
+    Passed,
+    Failed(String),
+    /// Worth pointing out, but not a reason to refuse a conversion
+    Warning(String),
+    Skipped(String),
+}
+
+#[derive(Clone, Debug)]
+pub struct IntegrityCheck {
+    pub name: &'static str,
+    pub status: CheckStatus,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct IntegrityReport {
+    pub checks: Vec<IntegrityCheck>,
+}
+
+impl IntegrityReport {
+    fn add(&mut self, name: &'static str, status: CheckStatus) {
+        self.checks.push(IntegrityCheck { name, status });
+    }
+
+    /// Skipped checks do not count as failures
+    pub fn is_ok(&self) -> bool {
+        !self
+            .checks
+            .iter()
+            .any(|c| matches!(c.status, CheckStatus::Failed(_)))
+    }
+}
+
+impl fmt::Display for IntegrityCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.status {
+            CheckStatus::Passed => write!(f, "{}: ok", self.name),
+            CheckStatus::Failed(reason) => write!(f, "{}: FAILED ({reason})", self.name),
+            CheckStatus::Warning(reason) => write!(f, "{}: warning ({reason})", self.name),
+            CheckStatus::Skipped(reason) => write!(f, "{}: skipped ({reason})", self.name),
+        }
+    }
+}
+
+/// Checks the header hash, the image hash chain and, given the key, the signature of a whole XEX file.
+pub fn verify_xex(
+    header: &XexHeader,
+    data: &[u8],
+    public_key: Option<&XexPublicKey>,
+) -> IntegrityReport {
+    let mut report = IntegrityReport::default();
+
+    report.add("header hash", verify_xex_header_hash(header, data));
+    report.add("image hashes", verify_xex_image(header, data));
+    report.add(
+        "RSA signature",
+        verify_xex_signature(header, data, public_key),
+    );
+
+    report
+}
+
+/// Checks the section digests and the allowed media of a whole XBE file.
+/// The header signature is not verified.
+pub fn verify_xbe(header: &XbeHeader, data: &[u8]) -> IntegrityReport {
+    let mut report = IntegrityReport::default();
+
+    report.add("section digests", verify_xbe_sections(header, data));
+    report.add("allowed media", verify_xbe_allowed_media(header));
+
+    report
+}
+
+fn verify_xbe_sections(header: &XbeHeader, data: &[u8]) -> CheckStatus {
+    let mismatched: Vec<_> = header
+        .sections
+        .iter()
+        .filter(|section| {
+            let start = section.raw_addr as usize;
+            let Some(raw) = data.get(start..start + section.raw_size as usize) else {
+                return true;
+            };
+
+            // the digest is prefixed with the raw size of the section
+            let mut hasher = Sha1::new();
+            hasher.update(section.raw_size.to_le_bytes());
+            hasher.update(raw);
+            hasher.finalize()[..] != section.digest
+        })
+        .map(|section| section.name.as_str())
+        .collect();
+
+    if mismatched.is_empty() {
+        CheckStatus::Passed
+    } else {
+        CheckStatus::Failed(format!("modified sections: {}", mismatched.join(", ")))
+    }
+}
+
+/// Retail discs never allow running from the hard disk; media patches add that.
+fn verify_xbe_allowed_media(header: &XbeHeader) -> CheckStatus {
+    let Some(certificate) = &header.fields.certificate else {
+        return CheckStatus::Skipped("no certificate".to_owned());
+    };
+
+    let patched_media = XbeMediaTypes::HARD_DISK
+        | XbeMediaTypes::NONSECURE_HARD_DISK
+        | XbeMediaTypes::NONSECURE_MODE;
+
+    if certificate.allowed_media.intersects(patched_media) {
+        CheckStatus::Warning(format!(
+            "media patched, allowed media is {:#010x}",
+            certificate.allowed_media.bits()
+        ))
+    } else {
+        CheckStatus::Passed
+    }
+}
+
+fn verify_xex_header_hash(header: &XexHeader, data: &[u8]) -> CheckStatus {
+    let security_offset = header.certificate_offset as usize;
+    let code_offset = header.code_offset as usize;
+    let hashed_offset = security_offset + XexSecurityInfo::PAGE_DESCRIPTORS_OFFSET as usize;
+
+    if code_offset > data.len() || hashed_offset > code_offset {
+        return CheckStatus::Failed("header is truncated".to_owned());
+    }
+
+    // the hash covers everything but the signed part of the security info
+    let mut hasher = Sha1::new();
+    hasher.update(&data[hashed_offset..code_offset]);
+    hasher.update(&data[..security_offset + 8]);
+
+    if hasher.finalize()[..] == header.security_info.header_hash {
+        CheckStatus::Passed
+    } else {
+        CheckStatus::Failed("header hash mismatch".to_owned())
+    }
+}
+
+/// The signature covers the image info, which holds the header hash, which covers the rest
+fn verify_xex_signature(
+    header: &XexHeader,
+    data: &[u8],
+    public_key: Option<&XexPublicKey>,
+) -> CheckStatus {
+    let Some(public_key) = public_key else {
+        return CheckStatus::Skipped("no public key to check against".to_owned());
+    };
+
+    let security_info = &header.security_info;
+    let start = header.certificate_offset as usize + XexSecurityInfo::IMAGE_INFO_OFFSET as usize;
+    let Some(len) = (security_info.image_info_size as usize).checked_sub(0x100) else {
+        return CheckStatus::Failed("invalid image info size".to_owned());
+    };
+    let Some(image_info) = data.get(start..start + len) else {
+        return CheckStatus::Failed("header is truncated".to_owned());
+    };
+
+    let digest: [u8; 20] = Sha1::digest(image_info).into();
+    if public_key.verify(&digest, &security_info.rsa_signature, XEX2_SIGNATURE_SALT) {
+        CheckStatus::Passed
+    } else {
+        CheckStatus::Failed("signature does not match the public key".to_owned())
+    }
+}
+
+fn verify_xex_image(header: &XexHeader, data: &[u8]) -> CheckStatus {
+    let Some(file_format) = &header.fields.file_format else {
+        return CheckStatus::Skipped("no file format info in header".to_owned());
+    };
+
+    if let XexCompression::Delta = file_format.compression {
+        return CheckStatus::Skipped("delta patches carry no full image".to_owned());
+    }
+
+    let Some(payload) = data.get(header.code_offset as usize..) else {
+        return CheckStatus::Failed("image is truncated".to_owned());
+    };
+
+    let keys: &[[u8; 16]] = match file_format.encryption {
+        XexEncryption::None => &[],
+        XexEncryption::Normal => &[XEX2_RETAIL_KEY, XEX2_DEVKIT_KEY],
+    };
+
+    // there is no telling retail and devkit images apart, so try both
+    let verified = if keys.is_empty() {
+        verify_xex_payload(header, &file_format.compression, payload)
+    } else {
+        keys.iter().any(|key| {
+            let session_key = decrypt_session_key(key, &header.security_info.file_key);
+            let payload = decrypt_payload(&session_key, payload);
+            verify_xex_payload(header, &file_format.compression, &payload)
+        })
+    };
+
+    if verified {
+        CheckStatus::Passed
+    } else {
+        CheckStatus::Failed("image data does not match its hashes".to_owned())
+    }
+}
+
+fn verify_xex_payload(header: &XexHeader, compression: &XexCompression, payload: &[u8]) -> bool {
+    // compressed blocks are hash-chained too, which is cheap to check before decompressing
+    let chained = match compression {
+        XexCompression::Normal {
+            first_block_size,
+            first_block_hash,
+            ..
+        } => verify_block_chain(payload, *first_block_size, *first_block_hash),
+        _ => true,
+    };
+
+    chained
+        && header
+            .decompress_image(compression, payload)
+            .is_ok_and(|image| verify_pages(&header.security_info, &image))
+}
+
+/// The image hash covers the first page descriptor, and the digest in each descriptor
+/// covers its pages along with the next descriptor, so that the chain ends at the last page
+fn verify_pages(security_info: &XexSecurityInfo, image: &[u8]) -> bool {
+    let descriptors = &security_info.page_descriptors;
+    let Some(first) = descriptors.first() else {
+        return false;
+    };
+    if Sha1::digest(first.to_bytes())[..] != security_info.image_hash {
+        return false;
+    }
+
+    let page_size = security_info.page_size() as usize;
+    let mut position = 0;
+
+    for (i, descriptor) in descriptors.iter().enumerate() {
+        let end = position + descriptor.page_count as usize * page_size;
+        let Some(pages) = image.get(position..end) else {
+            return false;
+        };
+
+        let mut hasher = Sha1::new();
+        hasher.update(pages);
+        if let Some(next) = descriptors.get(i + 1) {
+            hasher.update(next.to_bytes());
+        }
+        if hasher.finalize()[..] != descriptor.digest {
+            return false;
+        }
+        position = end;
+    }
+
+    true
+}
+
+/// Each compressed block starts with the size and the hash of the next one.
+fn verify_block_chain(payload: &[u8], mut block_size: u32, mut block_hash: [u8; 20]) -> bool {
+    let mut position = 0;
+
+    while block_size != 0 {
+        let end = position + block_size as usize;
+        let Some(block) = payload.get(position..end).filter(|b| b.len() >= 24) else {
+            return false;
+        };
+        if Sha1::digest(block)[..] != block_hash {
+            return false;
+        }
+
+        block_size = BE::read_u32(block);
+        block_hash.copy_from_slice(&block[4..24]);
+        position = end;
+    }
+
+    true
+}
+
+/// XeCrypt stores big numbers as 64-bit big-endian words, least significant word first
+fn from_xecrypt(bytes: &[u8]) -> Vec<u8> {
+    bytes.chunks_exact(8).rev().flatten().copied().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::executable::xex::{XexGameRegions, XexPageDescriptor};
+    use rsa::RsaPrivateKey;
+    use rsa::traits::PrivateKeyParts;
+
+    fn hex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    const TEST_P: &str = "d61b62b77a9d32d068f1416146c359949fcd56b45a9aba054bf50c894b0347c69a49af022fb9c0d54facd243f8ba382772505bb7e3597609ef68e40d38f85e6724eecf4c6efdbc7ab9e45b8d2c26ec05646bf2669e8c4575052e07a12da22ea94d2d15ebdbf204a3d4fd4ff03416142277b6cedbbfba6db2216446e24c46c915";
+    const TEST_Q: &str = "b61ba7694a385c7dc7cf1e0fe384a2e766e6b9f40d13712eceddc8ef8a9d387768b8444e89aca3e285793dfc46d05dfeb38206d6df856863f88f3a25a64d460849bc70ac4de213890ab3219c07d6152e5967319102ab86752b8d292feb28f2d8576fe8b31d2e273572e57e6fe26134751656cb5a0cbdb4122c5bd009f9032ae7";
+
+    /// A made-up 2048 bit signing key, and its public half in XeCrypt form
+    fn test_keys() -> (RsaPrivateKey, XexPublicKey) {
+        let p = BigUint::from_bytes_be(&hex(TEST_P));
+        let q = BigUint::from_bytes_be(&hex(TEST_Q));
+        let private_key = RsaPrivateKey::from_p_q(p, q, BigUint::from(3_u32)).unwrap();
+
+        let mut public_key = [0_u8; XEX_PUBLIC_KEY_SIZE];
+        BE::write_u32(&mut public_key[0x00..], 0x20);
+        BE::write_u32(&mut public_key[0x04..], 3);
+        public_key[0x10..].copy_from_slice(&from_xecrypt(&private_key.n().to_bytes_be()));
+
+        (private_key, XexPublicKey::parse(&public_key).unwrap())
+    }
+
+    /// The other half of `XexPublicKey::verify`, with a plain mask and no randomness
+    fn xecrypt_sign(private_key: &RsaPrivateKey, digest: &[u8; 20]) -> [u8; 0x100] {
+        let hash = Sha1::new()
+            .chain_update([0_u8; 8])
+            .chain_update(digest)
+            .chain_update(XEX2_SIGNATURE_SALT)
+            .finalize();
+
+        let mut encoded_message = [0_u8; 0x100];
+        encoded_message[0xe0] = 0x01;
+        encoded_message[0xe1..0xeb].copy_from_slice(XEX2_SIGNATURE_SALT);
+        for (counter, chunk) in encoded_message[..0xeb].chunks_mut(20).enumerate() {
+            let mask = Sha1::new()
+                .chain_update(hash)
+                .chain_update((counter as u32).to_be_bytes())
+                .finalize();
+            for (b, m) in chunk.iter_mut().zip(mask) {
+                *b ^= m;
+            }
+        }
+        encoded_message[0] &= 0x7f;
+        encoded_message[0xeb..0xff].copy_from_slice(&hash);
+        encoded_message[0xff] = 0xbc;
+
+        let signature = BigUint::from_bytes_be(&encoded_message)
+            .modpow(private_key.d(), private_key.n())
+            .to_bytes_be();
+        let mut padded = [0_u8; 0x100];
+        padded[0x100 - signature.len()..].copy_from_slice(&signature);
+        from_xecrypt(&padded).try_into().unwrap()
+    }
+
+    fn chain_block(next_size: u32, next_hash: &[u8], body: &[u8]) -> Vec<u8> {
+        let mut block = next_size.to_be_bytes().to_vec();
+        block.extend_from_slice(next_hash);
+        block.extend_from_slice(body);
+        block
+    }
+
+    #[test]
+    fn xbe_section_digest_covers_size_and_data() {
+        let mut data = vec![0_u8; 0x20];
+        data.extend_from_slice(b"section data");
+
+        let mut hasher = Sha1::new();
+        hasher.update(12_u32.to_le_bytes());
+        hasher.update(b"section data");
+
+        let header = XbeHeader {
+            signature: Box::new([0; 256]),
+            dw_base_addr: 0x10000,
+            dw_certificate_addr: 0,
+            sections: vec![crate::executable::xbe::XbeSectionHeader {
+                flags: 0,
+                virtual_addr: 0,
+                virtual_size: 12,
+                raw_addr: 0x20,
+                raw_size: 12,
+                name_addr: 0,
+                name: ".text".to_owned(),
+                digest: hasher.finalize().into(),
+            }],
+            fields: Default::default(),
+        };
+
+        assert_eq!(verify_xbe_sections(&header, &data), CheckStatus::Passed);
+
+        data[0x20] ^= 1;
+        assert_eq!(
+            verify_xbe_sections(&header, &data),
+            CheckStatus::Failed("modified sections: .text".to_owned())
+        );
+    }
+
+    #[test]
+    fn block_chain_detects_corruption() {
+        let last = chain_block(0, &[0; 20], b"last block");
+        let last_hash: [u8; 20] = Sha1::digest(&last).into();
+        let first = chain_block(last.len() as u32, &last_hash, b"first block");
+        let first_hash: [u8; 20] = Sha1::digest(&first).into();
+
+        let mut payload = [first.clone(), last].concat();
+        assert!(verify_block_chain(&payload, first.len() as u32, first_hash));
+
+        *payload.last_mut().unwrap() ^= 1;
+        assert!(!verify_block_chain(
+            &payload,
+            first.len() as u32,
+            first_hash
+        ));
+
+        payload.truncate(payload.len() - 1);
+        assert!(!verify_block_chain(
+            &payload,
+            first.len() as u32,
+            first_hash
+        ));
+    }
+
+    #[test]
+    fn xecrypt_signatures_verify() {
+        let (private_key, public_key) = test_keys();
+        let digest = [0x42_u8; 20];
+        let signature = xecrypt_sign(&private_key, &digest);
+
+        assert!(public_key.verify(&digest, &signature, XEX2_SIGNATURE_SALT));
+        assert!(!public_key.verify(&[0x43; 20], &signature, XEX2_SIGNATURE_SALT));
+        assert!(!public_key.verify(&digest, &signature, b"XBOX360XEY"));
+    }
+
+    #[test]
+    fn page_hashes_chain_from_the_image_hash() {
+        let image: Vec<u8> = (0..3 * 0x1000).map(|i| (i / 0x1000) as u8).collect();
+
+        // built from the last descriptor back, as each digest covers the next descriptor
+        let mut descriptors = vec![XexPageDescriptor {
+            page_count: 1,
+            info: 0,
+            digest: Sha1::digest(&image[0x2000..]).into(),
+        }];
+        let next = descriptors[0].to_bytes();
+        descriptors.insert(
+            0,
+            XexPageDescriptor {
+                page_count: 2,
+                info: 1,
+                digest: Sha1::new()
+                    .chain_update(&image[..0x2000])
+                    .chain_update(next)
+                    .finalize()
+                    .into(),
+            },
+        );
+
+        let mut security_info = XexSecurityInfo {
+            header_size: 0,
+            image_size: image.len() as u32,
+            rsa_signature: Box::new([0; 0x100]),
+            image_info_size: 0x174,
+            image_flags: 0x10000000,
+            load_address: 0,
+            image_hash: Sha1::digest(descriptors[0].to_bytes()).into(),
+            import_table_count: 0,
+            import_table_hash: [0; 20],
+            media_id: [0; 16],
+            file_key: [0; 16],
+            export_table: 0,
+            header_hash: [0; 20],
+            game_regions: XexGameRegions::empty(),
+            allowed_media_types: 0,
+            page_descriptors: descriptors,
+        };
+        assert!(verify_pages(&security_info, &image));
+        assert!(!verify_pages(&security_info, &image[..0x2800]));
+
+        let mut patched = image.clone();
+        patched[0x2010] ^= 1;
+        assert!(!verify_pages(&security_info, &patched));
+
+        security_info.image_hash[0] ^= 1;
+        assert!(!verify_pages(&security_info, &image));
+    }
+}
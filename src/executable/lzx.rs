@@ -0,0 +1,572 @@
+// LZX decompression, as used for "normal" XEX compression; follows the
+// LZX DELTA format description in [MS-PATCH], minus the delta part
+
+use anyhow::{Error, bail};
+
+const MIN_MATCH: usize = 2;
+const NUM_CHARS: usize = 256;
+const NUM_PRIMARY_LENGTHS: usize = 7;
+const PRETREE_NUM_ELEMENTS: usize = 20;
+const ALIGNED_NUM_ELEMENTS: usize = 8;
+const LENGTH_NUM_ELEMENTS: usize = 249;
+const MAX_CODE_LENGTH: usize = 16;
+
+/// Output is produced in frames, and the input is realigned after each one
+const FRAME_SIZE: usize = 0x8000;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum BlockType {
+    Verbatim,
+    Aligned,
+    Uncompressed,
+}
+
+/// Decompresses a whole LZX stream with a `1 << window_bits` window into `output_size` bytes
+pub fn decompress(input: &[u8], window_bits: u32, output_size: usize) -> Result<Vec<u8>, Error> {
+    let position_slots = match window_bits {
+        15 => 30,
+        16 => 32,
+        17 => 34,
+        18 => 36,
+        19 => 38,
+        20 => 42,
+        21 => 50,
+        _ => bail!("unsupported LZX window size 2^{window_bits}"),
+    };
+
+    Decoder::new(input, position_slots).decompress(output_size)
+}
+
+struct Decoder<'a> {
+    bits: BitReader<'a>,
+    output: Vec<u8>,
+
+    /// Most recently used match offsets
+    r: [usize; 3],
+
+    block_type: BlockType,
+    block_length: usize,
+    block_remaining: usize,
+
+    // code lengths are delta-coded against the ones of the previous block
+    main_lengths: Vec<u8>,
+    length_lengths: Vec<u8>,
+    main_tree: Huffman,
+    length_tree: Huffman,
+    aligned_tree: Huffman,
+
+    intel_file_size: i32,
+    intel_started: bool,
+    /// Frames are only translated once a block enabled it
+    intel_first_frame: Option<usize>,
+}
+
+impl<'a> Decoder<'a> {
+    fn new(input: &'a [u8], position_slots: usize) -> Decoder<'a> {
+        Decoder {
+            bits: BitReader::new(input),
+            output: Vec::new(),
+            r: [1; 3],
+            block_type: BlockType::Verbatim,
+            block_length: 0,
+            block_remaining: 0,
+            main_lengths: vec![0; NUM_CHARS + position_slots * 8],
+            length_lengths: vec![0; LENGTH_NUM_ELEMENTS],
+            main_tree: Huffman::default(),
+            length_tree: Huffman::default(),
+            aligned_tree: Huffman::default(),
+            intel_file_size: 0,
+            intel_started: false,
+            intel_first_frame: None,
+        }
+    }
+
+    fn decompress(mut self, output_size: usize) -> Result<Vec<u8>, Error> {
+        self.output.reserve_exact(output_size);
+
+        if self.bits.read(1)? == 1 {
+            let high = self.bits.read(16)?;
+            let low = self.bits.read(16)?;
+            self.intel_file_size = (high << 16 | low) as i32;
+        }
+
+        while self.output.len() < output_size {
+            let frame_start = self.output.len();
+            let frame_end = (frame_start + FRAME_SIZE).min(output_size);
+
+            while self.output.len() < frame_end {
+                if self.block_remaining == 0 {
+                    self.read_block_header()?;
+                }
+
+                let run_start = self.output.len();
+                let run_end = (run_start + self.block_remaining).min(frame_end);
+
+                match self.block_type {
+                    BlockType::Uncompressed => {
+                        let data = self.bits.read_bytes(run_end - run_start)?;
+                        self.output.extend_from_slice(data);
+                    }
+                    BlockType::Verbatim | BlockType::Aligned => {
+                        while self.output.len() < run_end {
+                            self.decode_symbol()?;
+                        }
+                    }
+                }
+
+                // a match may run past the end of the run, but not past the end of the block
+                let produced = self.output.len() - run_start;
+                let Some(block_remaining) = self.block_remaining.checked_sub(produced) else {
+                    bail!("LZX match runs past the end of its block");
+                };
+                self.block_remaining = block_remaining;
+            }
+
+            if self.output.len() != frame_end {
+                bail!("LZX match runs past the end of its frame");
+            }
+
+            if self.intel_started && self.intel_first_frame.is_none() {
+                self.intel_first_frame = Some(frame_start / FRAME_SIZE);
+            }
+
+            self.bits.align();
+        }
+
+        let intel_file_size = self.intel_file_size;
+        if let Some(first_frame) = self.intel_first_frame.filter(|_| intel_file_size != 0) {
+            self.undo_e8_translation(first_frame);
+        }
+
+        Ok(self.output)
+    }
+
+    fn read_block_header(&mut self) -> Result<(), Error> {
+        if self.block_type == BlockType::Uncompressed && self.block_length % 2 == 1 {
+            self.bits.read_bytes(1)?;
+        }
+
+        self.block_type = match self.bits.read(3)? {
+            1 => BlockType::Verbatim,
+            2 => BlockType::Aligned,
+            3 => BlockType::Uncompressed,
+            block_type => bail!("invalid LZX block type {block_type}"),
+        };
+
+        let high = self.bits.read(16)?;
+        let low = self.bits.read(8)?;
+        self.block_length = (high << 8 | low) as usize;
+        self.block_remaining = self.block_length;
+
+        match self.block_type {
+            BlockType::Aligned => {
+                let mut aligned_lengths = [0_u8; ALIGNED_NUM_ELEMENTS];
+                for length in aligned_lengths.iter_mut() {
+                    *length = self.bits.read(3)? as u8;
+                }
+                self.aligned_tree = Huffman::new(&aligned_lengths);
+                self.read_trees()
+            }
+            BlockType::Verbatim => self.read_trees(),
+            BlockType::Uncompressed => {
+                // one to sixteen bits of padding up to the next 16-bit boundary
+                self.bits.align_for_bytes();
+                let r = self.bits.read_bytes(12)?;
+                for (i, r) in r.chunks_exact(4).enumerate() {
+                    self.r[i] = u32::from_le_bytes(r.try_into().unwrap()) as usize;
+                }
+                self.intel_started = true;
+                Ok(())
+            }
+        }
+    }
+
+    fn read_trees(&mut self) -> Result<(), Error> {
+        let mut main_lengths = std::mem::take(&mut self.main_lengths);
+        self.read_lengths(&mut main_lengths[..NUM_CHARS])?;
+        self.read_lengths(&mut main_lengths[NUM_CHARS..])?;
+        self.main_tree = Huffman::new(&main_lengths);
+        if main_lengths[0xe8] != 0 {
+            self.intel_started = true;
+        }
+        self.main_lengths = main_lengths;
+
+        let mut length_lengths = std::mem::take(&mut self.length_lengths);
+        self.read_lengths(&mut length_lengths)?;
+        self.length_tree = Huffman::new(&length_lengths);
+        self.length_lengths = length_lengths;
+
+        Ok(())
+    }
+
+    /// Code lengths are sent through a pretree, as differences to the previous ones
+    fn read_lengths(&mut self, lengths: &mut [u8]) -> Result<(), Error> {
+        let mut pretree_lengths = [0_u8; PRETREE_NUM_ELEMENTS];
+        for length in pretree_lengths.iter_mut() {
+            *length = self.bits.read(4)? as u8;
+        }
+        let pretree = Huffman::new(&pretree_lengths);
+
+        let delta = |previous: u8, code: u16| ((previous as u16 + 17 - code) % 17) as u8;
+
+        let mut i = 0;
+        while i < lengths.len() {
+            let (run, value) = match pretree.decode(&mut self.bits)? {
+                17 => (self.bits.read(4)? as usize + 4, None),
+                18 => (self.bits.read(5)? as usize + 20, None),
+                19 => {
+                    let run = self.bits.read(1)? as usize + 4;
+                    let code = pretree.decode(&mut self.bits)?;
+                    if code > 16 {
+                        bail!("invalid LZX pretree code {code}");
+                    }
+                    (run, Some(code))
+                }
+                code => (1, Some(code)),
+            };
+
+            let Some(run_lengths) = lengths.get_mut(i..i + run) else {
+                bail!("LZX code lengths run past the end of their tree");
+            };
+            // a run repeats the new length of its first element
+            let length = match value {
+                Some(code) => delta(run_lengths[0], code),
+                None => 0,
+            };
+            run_lengths.fill(length);
+            i += run;
+        }
+
+        Ok(())
+    }
+
+    fn decode_symbol(&mut self) -> Result<(), Error> {
+        let main_element = self.main_tree.decode(&mut self.bits)? as usize;
+        if main_element < NUM_CHARS {
+            self.output.push(main_element as u8);
+            return Ok(());
+        }
+
+        let main_element = main_element - NUM_CHARS;
+
+        let mut match_length = main_element & 7;
+        if match_length == NUM_PRIMARY_LENGTHS {
+            match_length += self.length_tree.decode(&mut self.bits)? as usize;
+        }
+        match_length += MIN_MATCH;
+
+        let position_slot = main_element >> 3;
+        let match_offset = match position_slot {
+            0 => self.r[0],
+            1 => {
+                self.r.swap(0, 1);
+                self.r[0]
+            }
+            2 => {
+                self.r.swap(0, 2);
+                self.r[0]
+            }
+            _ => {
+                let offset = self.read_match_offset(position_slot)?;
+                self.r = [offset, self.r[0], self.r[1]];
+                offset
+            }
+        };
+
+        let Some(start) = self.output.len().checked_sub(match_offset) else {
+            bail!("LZX match offset points before the start of the data");
+        };
+        if match_offset == 0 {
+            bail!("invalid LZX match offset 0");
+        }
+
+        // the source may overlap with what is being written
+        for i in start..start + match_length {
+            self.output.push(self.output[i]);
+        }
+
+        Ok(())
+    }
+
+    fn read_match_offset(&mut self, position_slot: usize) -> Result<usize, Error> {
+        let extra = extra_bits(position_slot);
+        let base = position_base(position_slot) - 2;
+
+        if self.block_type == BlockType::Aligned && extra >= 3 {
+            // the lowest three bits are sent through the aligned offset tree
+            let verbatim = self.bits.read(extra - 3)? as usize;
+            let aligned = self.aligned_tree.decode(&mut self.bits)? as usize;
+            Ok(base + (verbatim << 3) + aligned)
+        } else {
+            Ok(base + self.bits.read(extra)? as usize)
+        }
+    }
+
+    /// Turns relative CALL targets back into absolute ones, frame by frame
+    fn undo_e8_translation(&mut self, first_frame: usize) {
+        let file_size = self.intel_file_size;
+
+        let frames = self.output.chunks_mut(FRAME_SIZE).enumerate();
+        for (frame_index, frame) in frames.skip(first_frame) {
+            if frame.len() <= 10 || frame_index * FRAME_SIZE >= 0x40000000 {
+                continue;
+            }
+
+            let mut current = (frame_index * FRAME_SIZE) as i32;
+            let end = frame.len() - 10;
+            let mut i = 0;
+
+            while i < end {
+                if frame[i] != 0xe8 {
+                    i += 1;
+                    current += 1;
+                    continue;
+                }
+
+                let absolute = i32::from_le_bytes(frame[i + 1..i + 5].try_into().unwrap());
+                if absolute >= -current && absolute < file_size {
+                    let relative = if absolute >= 0 {
+                        absolute - current
+                    } else {
+                        absolute + file_size
+                    };
+                    frame[i + 1..i + 5].copy_from_slice(&relative.to_le_bytes());
+                }
+
+                i += 5;
+                current += 5;
+            }
+        }
+    }
+}
+
+fn extra_bits(position_slot: usize) -> u32 {
+    match position_slot {
+        0..4 => 0,
+        slot => ((slot / 2 - 1) as u32).min(17),
+    }
+}
+
+fn position_base(position_slot: usize) -> usize {
+    (0..position_slot).map(|slot| 1 << extra_bits(slot)).sum()
+}
+
+/// Reads bits from the most significant end of little-endian 16-bit words
+struct BitReader<'a> {
+    input: &'a [u8],
+    position: usize,
+    /// Zero words read past the end of the input, as the last codes may be looked past
+    overrun: usize,
+    buffer: u64,
+    /// Bits left in `buffer`, from its most significant end
+    count: u32,
+}
+
+impl<'a> BitReader<'a> {
+    fn new(input: &'a [u8]) -> BitReader<'a> {
+        BitReader {
+            input,
+            position: 0,
+            overrun: 0,
+            buffer: 0,
+            count: 0,
+        }
+    }
+
+    fn ensure(&mut self, count: u32) -> Result<(), Error> {
+        while self.count < count {
+            let word = match self.input.get(self.position..self.position + 2) {
+                Some(word) => u16::from_le_bytes([word[0], word[1]]) as u64,
+                None if self.overrun < 2 => {
+                    self.overrun += 1;
+                    0
+                }
+                None => bail!("LZX data is truncated"),
+            };
+            self.buffer |= word << (48 - self.count);
+            self.count += 16;
+            self.position += 2;
+        }
+        Ok(())
+    }
+
+    fn peek(&self, count: u32) -> u32 {
+        (self.buffer >> (64 - count)) as u32
+    }
+
+    fn remove(&mut self, count: u32) {
+        self.buffer <<= count;
+        self.count -= count;
+    }
+
+    fn read(&mut self, count: u32) -> Result<u32, Error> {
+        if count == 0 {
+            return Ok(0);
+        }
+        self.ensure(count)?;
+        let value = self.peek(count);
+        self.remove(count);
+        Ok(value)
+    }
+
+    /// Drops the rest of a partially read word, as done after each frame
+    fn align(&mut self) {
+        self.remove(self.count % 16);
+    }
+
+    /// Before an uncompressed block; the padding is a whole word if already aligned
+    fn align_for_bytes(&mut self) {
+        if self.count == 0 {
+            self.position += 2;
+        }
+        self.buffer = 0;
+        self.count = 0;
+    }
+
+    /// Only valid while no bits are buffered, like in uncompressed blocks
+    fn read_bytes(&mut self, len: usize) -> Result<&'a [u8], Error> {
+        let Some(bytes) = self.input.get(self.position..self.position + len) else {
+            bail!("LZX data is truncated");
+        };
+        self.position += len;
+        Ok(bytes)
+    }
+}
+
+/// A canonical Huffman code, decoded one code length at a time
+#[derive(Default)]
+struct Huffman {
+    /// Number of codes of each length
+    counts: [u16; MAX_CODE_LENGTH + 1],
+    /// Symbols ordered by code length, and then by value
+    symbols: Vec<u16>,
+}
+
+impl Huffman {
+    fn new(lengths: &[u8]) -> Huffman {
+        let mut counts = [0_u16; MAX_CODE_LENGTH + 1];
+        for length in lengths.iter().filter(|l| **l != 0) {
+            counts[*length as usize] += 1;
+        }
+
+        let mut symbols: Vec<u16> = (0..lengths.len() as u16)
+            .filter(|s| lengths[*s as usize] != 0)
+            .collect();
+        symbols.sort_by_key(|s| lengths[*s as usize]);
+
+        Huffman { counts, symbols }
+    }
+
+    fn decode(&self, bits: &mut BitReader) -> Result<u16, Error> {
+        // codes are never longer than 16 bits, so that many are always looked ahead
+        bits.ensure(MAX_CODE_LENGTH as u32)?;
+        let lookahead = bits.peek(MAX_CODE_LENGTH as u32);
+
+        let mut first = 0;
+        let mut index = 0;
+        for length in 1..=MAX_CODE_LENGTH {
+            let code = lookahead >> (MAX_CODE_LENGTH - length);
+            let count = self.counts[length] as u32;
+            if code < first + count {
+                bits.remove(length as u32);
+                return Ok(self.symbols[(index + code - first) as usize]);
+            }
+            index += count;
+            first = (first + count) << 1;
+        }
+
+        bail!("invalid LZX Huffman code")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Packs bits the way `BitReader` unpacks them
+    #[derive(Default)]
+    struct BitWriter {
+        words: Vec<u16>,
+        current: u32,
+        count: u32,
+    }
+
+    impl BitWriter {
+        fn write(&mut self, value: u32, count: u32) {
+            for i in (0..count).rev() {
+                self.current = self.current << 1 | (value >> i) & 1;
+                self.count += 1;
+                if self.count == 16 {
+                    self.words.push(self.current as u16);
+                    self.current = 0;
+                    self.count = 0;
+                }
+            }
+        }
+
+        fn finish(mut self) -> Vec<u8> {
+            if self.count > 0 {
+                let count = self.count;
+                self.write(0, 16 - count);
+            }
+            self.words.iter().flat_map(|w| w.to_le_bytes()).collect()
+        }
+    }
+
+    /// Sends `lengths` through a pretree with every code 5 bits long, so that code `z` is `z`
+    fn write_lengths(bits: &mut BitWriter, lengths: &[u8]) {
+        for _ in 0..PRETREE_NUM_ELEMENTS {
+            bits.write(5, 4);
+        }
+        for length in lengths {
+            bits.write((17 - *length as u32) % 17, 5);
+        }
+    }
+
+    #[test]
+    fn decodes_verbatim_blocks() {
+        // "a" and "b", then "b" seven times through a new offset of 1,
+        // then three times more through the repeated offset
+        let a = b'a' as usize;
+        let b = b'b' as usize;
+        let new_offset_match = NUM_CHARS + (3 << 3) + 5;
+        let repeated_match = NUM_CHARS + 1;
+
+        let mut main_lengths = vec![0_u8; NUM_CHARS + 30 * 8];
+        for symbol in [a, b, repeated_match, new_offset_match] {
+            main_lengths[symbol] = 2;
+        }
+
+        let mut bits = BitWriter::default();
+        bits.write(0, 1);
+        bits.write(1, 3);
+        bits.write(0, 16);
+        bits.write(12, 8);
+        write_lengths(&mut bits, &main_lengths[..NUM_CHARS]);
+        write_lengths(&mut bits, &main_lengths[NUM_CHARS..]);
+        write_lengths(&mut bits, &[0; LENGTH_NUM_ELEMENTS]);
+
+        // codes are assigned in symbol order
+        for code in [0b00, 0b01, 0b11, 0b10] {
+            bits.write(code, 2);
+        }
+
+        let output = decompress(&bits.finish(), 15, 12).unwrap();
+        assert_eq!(output, b"abbbbbbbbbbb");
+    }
+
+    #[test]
+    fn decodes_uncompressed_blocks() {
+        let mut bits = BitWriter::default();
+        bits.write(0, 1);
+        bits.write(3, 3);
+        bits.write(0, 16);
+        bits.write(5, 8);
+        let mut input = bits.finish();
+        input.extend_from_slice(&[1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0]);
+        input.extend_from_slice(b"hello");
+
+        assert_eq!(decompress(&input, 16, 5).unwrap(), b"hello");
+        assert!(decompress(&input[..input.len() - 1], 16, 5).is_err());
+    }
+}
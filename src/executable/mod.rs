@@ -1,11 +1,20 @@
+use crate::god;
 use crate::god::ContentType;
-use crate::iso::IsoReader;
+use crate::iso::{IsoReader, WindowsPath};
+use crate::stfs::StfsReader;
 use anyhow::{Context, Error, bail};
-use byteorder::{BE, LE, ReadBytesExt};
-use std::io::{Read, Seek, SeekFrom};
+use bitflags::bitflags;
+use byteorder::{BE, ReadBytesExt};
+use std::fmt;
+use std::io::{Cursor, Read, Seek, SeekFrom};
+use std::str::FromStr;
 
+pub mod integrity;
+mod lzx;
+pub mod spa;
 pub mod xbe;
 pub mod xex;
+pub mod xpr;
 
 #[derive(Clone, Debug)]
 pub struct TitleExecutionInfo {
@@ -19,9 +28,31 @@ pub struct TitleExecutionInfo {
     pub disc_count: u8,
 }
 
+bitflags! {
+    /// Regions a title is allowed to run in, common to both executable formats
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct GameRegions: u8 {
+        const NTSC_U = 0x01;
+        const NTSC_J = 0x02;
+        const PAL = 0x04;
+        const OTHER = 0x08;
+    }
+}
+
+pub enum Executable {
+    Xex(xex::XexHeader),
+    Xbe(xbe::XbeHeader),
+}
+
 pub struct TitleInfo {
     pub content_type: ContentType,
     pub execution_info: TitleExecutionInfo,
+    /// PNG icon that fits into the CON header image slots, if the executable has one
+    pub icon: Option<Vec<u8>>,
+    /// Title names from the SPA resource, if the executable is an XEX that has one
+    pub spa: Option<spa::Spa>,
+    pub executable_path: WindowsPath,
+    pub executable: Executable,
 }
 
 impl TitleExecutionInfo {
@@ -38,54 +69,390 @@ impl TitleExecutionInfo {
         })
     }
 
-    pub fn from_xbe<R: Read + Seek>(mut reader: R) -> Result<TitleExecutionInfo, Error> {
-        reader.seek(SeekFrom::Current(8))?;
-        let title_id = reader.read_u32::<LE>()?;
-
-        reader.seek(SeekFrom::Current(164))?;
-        let version = reader.read_u32::<LE>()?;
-
-        Ok(TitleExecutionInfo {
+    pub fn from_xbe(certificate: &xbe::XbeCertificate) -> TitleExecutionInfo {
+        TitleExecutionInfo {
             media_id: 0,
-            version,
+            version: certificate.version,
             base_version: 0,
-            title_id,
+            title_id: certificate.title_id,
             platform: 0,
             executable_type: 0,
             disc_number: 1,
             disc_count: 1,
-        })
+        }
     }
 }
 
-impl TitleInfo {
-    pub fn from_image<R: Read + Seek>(iso_image: &mut IsoReader<R>) -> Result<TitleInfo, Error> {
-        if let Some(mut executable) = iso_image.get_entry(&"\\default.xex".into())? {
-            let default_xex_header =
-                xex::XexHeader::read(&mut executable).context("error reading default.xex")?;
-            let execution_info = default_xex_header
+impl Executable {
+    /// Where the launcher is looked for when no explicit path is given
+    pub const DEFAULT_PATHS: [&'static str; 2] = ["\\default.xex", "\\default.xbe"];
+
+    /// Reads either an XEX or an XBE header, telling them apart by the magic bytes.
+    pub fn read<R: Read + Seek>(mut reader: R) -> Result<Executable, Error> {
+        let mut magic_bytes = [0_u8; 4];
+        reader.read_exact(&mut magic_bytes)?;
+        reader.seek(SeekFrom::Current(-4))?;
+
+        match &magic_bytes {
+            b"XEX2" => Ok(Executable::Xex(xex::XexHeader::read(reader)?)),
+            b"XBEH" => Ok(Executable::Xbe(xbe::XbeHeader::read(reader)?)),
+            _ => bail!("unknown executable format"),
+        }
+    }
+
+    pub fn content_type(&self) -> ContentType {
+        match self {
+            Executable::Xex(_) => ContentType::GamesOnDemand,
+            Executable::Xbe(_) => ContentType::XboxOriginal,
+        }
+    }
+
+    pub fn execution_info(&self) -> Option<&TitleExecutionInfo> {
+        match self {
+            Executable::Xex(xex) => xex.fields.execution_info.as_ref(),
+            Executable::Xbe(xbe) => xbe.fields.execution_info.as_ref(),
+        }
+    }
+
+    pub fn title_id(&self) -> Option<u32> {
+        self.execution_info().map(|i| i.title_id)
+    }
+
+    pub fn media_id(&self) -> Option<u32> {
+        self.execution_info().map(|i| i.media_id)
+    }
+
+    pub fn version(&self) -> Option<u32> {
+        self.execution_info().map(|i| i.version)
+    }
+
+    /// Disc number and disc count
+    pub fn disc_info(&self) -> Option<(u8, u8)> {
+        self.execution_info().map(|i| (i.disc_number, i.disc_count))
+    }
+
+    /// Only XBE certificates carry a title name; XEX names live in the SPA resource
+    pub fn title_name(&self) -> Option<&str> {
+        match self {
+            Executable::Xex(_) => None,
+            Executable::Xbe(xbe) => xbe
                 .fields
-                .execution_info
-                .context("no execution info in default.xex header")?;
+                .certificate
+                .as_ref()
+                .map(|c| c.title_name.as_str())
+                .filter(|s| !s.is_empty()),
+        }
+    }
 
-            Ok(TitleInfo {
-                content_type: ContentType::GamesOnDemand,
-                execution_info,
-            })
-        } else if let Some(mut executable) = iso_image.get_entry(&"\\default.xbe".into())? {
-            let default_xbe_header =
-                xbe::XbeHeader::read(&mut executable).context("error reading default.xbe")?;
-            let execution_info = default_xbe_header
+    pub fn regions(&self) -> Option<GameRegions> {
+        match self {
+            Executable::Xex(xex) => Some(xex.security_info.game_regions.into()),
+            Executable::Xbe(xbe) => xbe
                 .fields
-                .execution_info
-                .context("no execution info in default.xbe header")?;
+                .certificate
+                .as_ref()
+                .map(|c| c.game_region.into()),
+        }
+    }
+
+    /// PNG icon that fits into the CON header image slots
+    pub fn icon(&self) -> Result<Option<Vec<u8>>, Error> {
+        match self {
+            Executable::Xex(_) => Ok(None),
+            Executable::Xbe(xbe) => match &xbe.fields.title_image {
+                Some(xpr) => Ok(Some(god::encode_icon(&xpr::decode_xpr_texture(xpr)?)?)),
+                None => Ok(None),
+            },
+        }
+    }
+
+    /// Reads the SPA resource named after the title ID out of an XEX image;
+    /// `data` is the whole executable file
+    pub fn read_spa(&self, data: &[u8]) -> Result<Option<spa::Spa>, Error> {
+        let Executable::Xex(xex) = self else {
+            return Ok(None);
+        };
+        let Some(title_id) = self.title_id() else {
+            return Ok(None);
+        };
+
+        let name = format!("{title_id:08X}");
+        let Some(resource) = xex.fields.resources.iter().find(|r| r.name == name) else {
+            return Ok(None);
+        };
+
+        let image = xex.read_image(data)?;
+        let Some(start) = resource.address.checked_sub(xex.security_info.load_address) else {
+            bail!("SPA resource lies outside of the XEX image");
+        };
+        let start = start as usize;
+        let Some(bytes) = image.get(start..start + resource.size as usize) else {
+            bail!("SPA resource lies outside of the XEX image");
+        };
+
+        spa::Spa::parse(bytes).map(Some)
+    }
+
+    /// Checks the integrity of the whole executable file, as read into `data`;
+    /// XEX signatures are only checked given the key they are signed with
+    pub fn verify(
+        &self,
+        data: &[u8],
+        xex_public_key: Option<&integrity::XexPublicKey>,
+    ) -> integrity::IntegrityReport {
+        match self {
+            Executable::Xex(xex) => integrity::verify_xex(xex, data, xex_public_key),
+            Executable::Xbe(xbe) => integrity::verify_xbe(xbe, data),
+        }
+    }
+}
+
+impl fmt::Display for GameRegions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return write!(f, "none");
+        }
+
+        let names: Vec<_> = self
+            .iter()
+            .map(|region| match region {
+                GameRegions::NTSC_U => "NTSC-U",
+                GameRegions::NTSC_J => "NTSC-J",
+                GameRegions::PAL => "PAL",
+                _ => "other",
+            })
+            .collect();
+
+        write!(f, "{}", names.join("/"))
+    }
+}
+
+/// The inverse of `Display`, like "NTSC-U/PAL"
+impl FromStr for GameRegions {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<GameRegions, Error> {
+        if s.eq_ignore_ascii_case("none") {
+            return Ok(GameRegions::empty());
+        }
 
-            Ok(TitleInfo {
-                content_type: ContentType::XboxOriginal,
-                execution_info,
+        s.split('/')
+            .map(|region| match region.trim().to_ascii_uppercase().as_str() {
+                "NTSC-U" => Ok(GameRegions::NTSC_U),
+                "NTSC-J" => Ok(GameRegions::NTSC_J),
+                "PAL" => Ok(GameRegions::PAL),
+                "OTHER" => Ok(GameRegions::OTHER),
+                _ => bail!("unknown region {region:?}"),
             })
-        } else {
-            bail!("no executable found in this image");
+            .collect()
+    }
+}
+
+impl TitleInfo {
+    pub fn from_image<R: Read + Seek>(iso_image: &mut IsoReader<R>) -> Result<TitleInfo, Error> {
+        for path in Executable::DEFAULT_PATHS {
+            if let Some(title_info) = Self::from_image_executable(iso_image, &path.into())? {
+                return Ok(title_info);
+            }
+        }
+
+        bail!("no executable found in this image");
+    }
+
+    /// Like `from_image`, but with an explicit executable path; returns
+    /// `None` if there is no such file in the image.
+    pub fn from_image_executable<R: Read + Seek>(
+        iso_image: &mut IsoReader<R>,
+        path: &WindowsPath,
+    ) -> Result<Option<TitleInfo>, Error> {
+        let Some(data) = iso_image.read_entry(path)? else {
+            return Ok(None);
+        };
+
+        Self::from_executable_data(&data, path).map(Some)
+    }
+
+    /// Like `from_image`, but for the executable inside an STFS package, such as an arcade title
+    pub fn from_stfs<R: Read + Seek>(stfs: &mut StfsReader<R>) -> Result<TitleInfo, Error> {
+        for path in Executable::DEFAULT_PATHS {
+            let path = path.into();
+            if let Some(data) = stfs.read_entry(&path)? {
+                return Self::from_executable_data(&data, &path);
+            }
+        }
+
+        bail!("no executable found in this package");
+    }
+
+    fn from_executable_data(data: &[u8], path: &WindowsPath) -> Result<TitleInfo, Error> {
+        let name = path.components.join("\\");
+        let executable =
+            Executable::read(Cursor::new(data)).with_context(|| format!("error reading {name}"))?;
+
+        let execution_info = executable
+            .execution_info()
+            .cloned()
+            .with_context(|| format!("no execution info in {name} header"))?;
+
+        // a broken title image should not prevent the conversion itself
+        let icon = executable.icon().ok().flatten();
+        // neither should a broken SPA
+        let spa = executable.read_spa(data).ok().flatten();
+
+        Ok(TitleInfo {
+            content_type: executable.content_type(),
+            execution_info,
+            icon,
+            spa,
+            executable_path: path.clone(),
+            executable,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use byteorder::{ByteOrder, LE};
+
+    const XBE_BASE: u32 = 0x10000;
+    const XBE_CERTIFICATE: usize = 0x200;
+    const XBE_SECTION_HEADERS: usize = 0x300;
+    const XBE_SECTION_NAMES: usize = 0x400;
+    const XBE_TITLE_IMAGE: usize = 0x1000;
+
+    /// A 4x4 A8R8G8B8 texture, all opaque red
+    fn xpr_texture() -> Vec<u8> {
+        let header_size = 0x800_u32;
+        let mut xpr = vec![0_u8; header_size as usize];
+        xpr[0..4].copy_from_slice(b"XPR0");
+        LE::write_u32(&mut xpr[8..], header_size);
+        LE::write_u32(&mut xpr[24..], (0x06 << 8) | (2 << 20) | (2 << 24));
+        for _ in 0..16 {
+            xpr.extend_from_slice(&0xffff0000_u32.to_le_bytes());
+        }
+        xpr
+    }
+
+    /// An XBE mapped at its base address as it is in the file, with a `.text` section
+    /// and a `$$XTIMAGE` section of `title_image_size` bytes claimed in its header
+    fn build_xbe(title_image: &[u8], title_image_size: u32) -> Vec<u8> {
+        let mut xbe = vec![0_u8; XBE_TITLE_IMAGE];
+        xbe[0..4].copy_from_slice(b"XBEH");
+        xbe[0x4..0x104].fill(0x5a);
+        LE::write_u32(&mut xbe[0x104..], XBE_BASE);
+        LE::write_u32(&mut xbe[0x118..], XBE_BASE + XBE_CERTIFICATE as u32);
+        LE::write_u32(&mut xbe[0x11c..], 2);
+        LE::write_u32(&mut xbe[0x120..], XBE_BASE + XBE_SECTION_HEADERS as u32);
+
+        let certificate = &mut xbe[XBE_CERTIFICATE..];
+        LE::write_u32(&mut certificate[0x00..], 0x1d0);
+        LE::write_u32(&mut certificate[0x08..], 0x4d530004);
+        for (i, c) in "Halo".encode_utf16().enumerate() {
+            LE::write_u16(&mut certificate[0x0c + i * 2..], c);
+        }
+        // alternate title IDs, then allowed media, region, ratings, disk number and version
+        LE::write_u32(&mut certificate[0x9c..], 0x00000002);
+        LE::write_u32(&mut certificate[0xa0..], 0x00000005);
+        LE::write_u32(&mut certificate[0xa8..], 1);
+        LE::write_u32(&mut certificate[0xac..], 0x00000003);
+
+        let names = [".text", "$$XTIMAGE"];
+        let mut name_offset = XBE_SECTION_NAMES;
+        for (i, name) in names.iter().enumerate() {
+            let header = &mut xbe[XBE_SECTION_HEADERS + i * 0x38..];
+            LE::write_u32(&mut header[0x14..], XBE_BASE + name_offset as u32);
+            if *name == "$$XTIMAGE" {
+                LE::write_u32(&mut header[0x0c..], XBE_TITLE_IMAGE as u32);
+                LE::write_u32(&mut header[0x10..], title_image_size);
+            }
+
+            xbe[name_offset..name_offset + name.len()].copy_from_slice(name.as_bytes());
+            name_offset += name.len() + 1;
         }
+
+        xbe.extend_from_slice(title_image);
+        xbe
+    }
+
+    /// An XEX header with only an execution ID, and no image to go with it
+    fn build_xex() -> Vec<u8> {
+        let mut xex = vec![0_u8; 0x300];
+        xex[0..4].copy_from_slice(b"XEX2");
+        BE::write_u32(&mut xex[0x04..], 0x01);
+        BE::write_u32(&mut xex[0x08..], 0x300);
+        BE::write_u32(&mut xex[0x10..], 0x100);
+        BE::write_u32(&mut xex[0x14..], 1);
+        BE::write_u32(&mut xex[0x18..], 0x00040006);
+        BE::write_u32(&mut xex[0x1c..], 0x80);
+
+        let execution_info = &mut xex[0x80..];
+        BE::write_u32(&mut execution_info[0x00..], 0x12345678);
+        BE::write_u32(&mut execution_info[0x0c..], 0x4d5307e6);
+        execution_info[0x12] = 1;
+        execution_info[0x13] = 2;
+
+        let security_info = &mut xex[0x100..];
+        BE::write_u32(&mut security_info[0x04..], 0x10000);
+        BE::write_u32(&mut security_info[0x178..], 0x00fe00ff);
+        xex
+    }
+
+    #[test]
+    fn reads_xbe_certificate_and_title_image() {
+        let texture = xpr_texture();
+        let xbe = build_xbe(&texture, texture.len() as u32);
+
+        let header = xbe::XbeHeader::read(Cursor::new(&xbe)).unwrap();
+        let names: Vec<_> = header.sections.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, [".text", "$$XTIMAGE"]);
+        assert_eq!(header.fields.title_image.as_deref(), Some(&texture[..]));
+
+        let certificate = header.fields.certificate.as_ref().unwrap();
+        assert_eq!(certificate.title_id, 0x4d530004);
+        assert_eq!(certificate.title_name, "Halo");
+        assert_eq!(certificate.allowed_media, xbe::XbeMediaTypes::DVD_X2);
+        assert_eq!(certificate.disk_number, 1);
+        assert_eq!(certificate.version, 3);
+
+        let executable = Executable::read(Cursor::new(&xbe)).unwrap();
+        assert_eq!(executable.content_type(), ContentType::XboxOriginal);
+        assert_eq!(executable.title_id(), Some(0x4d530004));
+        assert_eq!(executable.title_name(), Some("Halo"));
+        assert_eq!(
+            executable.regions(),
+            Some(GameRegions::NTSC_U | GameRegions::PAL)
+        );
+
+        let icon = executable.icon().unwrap().unwrap();
+        assert!(icon.starts_with(b"\x89PNG\r\n\x1a\n"));
+    }
+
+    #[test]
+    fn refuses_oversized_xbe_title_images() {
+        let xbe = build_xbe(&xpr_texture(), 0x100001);
+        assert!(xbe::XbeHeader::read(Cursor::new(&xbe)).is_err());
+    }
+
+    #[test]
+    fn reads_xex_execution_info() {
+        let xex = build_xex();
+
+        let executable = Executable::read(Cursor::new(&xex)).unwrap();
+        assert_eq!(executable.content_type(), ContentType::GamesOnDemand);
+        assert_eq!(executable.title_id(), Some(0x4d5307e6));
+        assert_eq!(executable.media_id(), Some(0x12345678));
+        assert_eq!(executable.disc_info(), Some((1, 2)));
+        assert_eq!(
+            executable.regions(),
+            Some(GameRegions::NTSC_U | GameRegions::PAL)
+        );
+        assert_eq!(executable.title_name(), None);
+        assert_eq!(executable.icon().unwrap(), None);
+    }
+
+    #[test]
+    fn refuses_unknown_executables() {
+        assert!(Executable::read(Cursor::new(b"MZ\x90\x00")).is_err());
     }
 }
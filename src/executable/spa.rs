@@ -0,0 +1,227 @@
+use anyhow::{Error, bail};
+use byteorder::{BE, ByteOrder};
+
+use crate::god::Locale;
+
+// based on https://free60.org/System-Software/Formats/XDBF/
+const XDBF_HEADER_SIZE: usize = 0x18;
+const XDBF_ENTRY_SIZE: usize = 0x12;
+const XDBF_FREE_ENTRY_SIZE: usize = 0x08;
+
+const SECTION_METADATA: u16 = 1;
+const SECTION_STRING_TABLE: u16 = 3;
+
+/// `XSTC`, the entry that holds the default language
+const XSTC_ID: u64 = 0x58535443;
+const TITLE_STRING_ID: u16 = 0x8000;
+
+/// Title strings from the SPA (an XDBF file) embedded into an XEX as a resource
+#[derive(Clone, Debug, Default)]
+pub struct Spa {
+    pub default_locale: Option<Locale>,
+    /// The title name in every language the SPA has it in
+    pub title_names: Vec<(Locale, String)>,
+}
+
+struct XdbfEntry<'a> {
+    section: u16,
+    id: u64,
+    data: &'a [u8],
+}
+
+impl Spa {
+    pub fn parse(bytes: &[u8]) -> Result<Spa, Error> {
+        let entries = read_entries(bytes)?;
+
+        let default_locale = entries
+            .iter()
+            .find(|e| e.section == SECTION_METADATA && e.id == XSTC_ID)
+            .filter(|e| e.data.len() >= 0x10 && &e.data[..4] == b"XSTC")
+            .and_then(|e| locale_from_language(BE::read_u32(&e.data[0x0c..]) as u64));
+
+        let mut title_names = Vec::new();
+        for entry in entries.iter().filter(|e| e.section == SECTION_STRING_TABLE) {
+            let Some(locale) = locale_from_language(entry.id) else {
+                continue;
+            };
+            if let Some(name) = find_string(entry.data, TITLE_STRING_ID)? {
+                title_names.push((locale, name));
+            }
+        }
+
+        Ok(Spa {
+            default_locale,
+            title_names,
+        })
+    }
+
+    /// The title name in the default language, or in English if there is no default
+    pub fn title_name(&self) -> Option<&str> {
+        let locale = self.default_locale.unwrap_or(Locale::English);
+        self.title_names
+            .iter()
+            .find(|(l, _)| *l == locale)
+            .or(self.title_names.first())
+            .map(|(_, name)| name.as_str())
+    }
+}
+
+fn read_entries(bytes: &[u8]) -> Result<Vec<XdbfEntry<'_>>, Error> {
+    if bytes.len() < XDBF_HEADER_SIZE || &bytes[..4] != b"XDBF" {
+        bail!("missing 'XDBF' magic bytes in SPA");
+    }
+
+    let entry_table_len = BE::read_u32(&bytes[0x08..]) as usize;
+    let entry_count = BE::read_u32(&bytes[0x0c..]) as usize;
+    let free_table_len = BE::read_u32(&bytes[0x10..]) as usize;
+
+    if entry_count > entry_table_len {
+        bail!("SPA entry count is larger than its entry table");
+    }
+
+    let data_offset = XDBF_HEADER_SIZE
+        + entry_table_len * XDBF_ENTRY_SIZE
+        + free_table_len * XDBF_FREE_ENTRY_SIZE;
+
+    (0..entry_count)
+        .map(|i| {
+            let offset = XDBF_HEADER_SIZE + i * XDBF_ENTRY_SIZE;
+            let Some(entry) = bytes.get(offset..offset + XDBF_ENTRY_SIZE) else {
+                bail!("SPA entry table is truncated");
+            };
+
+            let start = data_offset + BE::read_u32(&entry[0x0a..]) as usize;
+            let len = BE::read_u32(&entry[0x0e..]) as usize;
+            let Some(data) = bytes.get(start..start + len) else {
+                bail!("SPA entry data is truncated");
+            };
+
+            Ok(XdbfEntry {
+                section: BE::read_u16(&entry[0x00..]),
+                id: BE::read_u64(&entry[0x02..]),
+                data,
+            })
+        })
+        .collect()
+}
+
+/// Looks up a string in an `XSTR` string table; strings are UTF-8
+fn find_string(table: &[u8], id: u16) -> Result<Option<String>, Error> {
+    if table.len() < 0x0e || &table[..4] != b"XSTR" {
+        bail!("missing 'XSTR' magic bytes in SPA string table");
+    }
+
+    let count = BE::read_u16(&table[0x0c..]);
+    let mut strings = &table[0x0e..];
+
+    for _ in 0..count {
+        if strings.len() < 4 {
+            bail!("SPA string table is truncated");
+        }
+        let string_id = BE::read_u16(&strings[0x00..]);
+        let len = BE::read_u16(&strings[0x02..]) as usize;
+        let Some(string) = strings.get(4..4 + len) else {
+            bail!("SPA string table is truncated");
+        };
+
+        if string_id == id {
+            let string = String::from_utf8_lossy(string);
+            let string = string.trim_end_matches('\0').trim();
+            return Ok(Some(string.to_owned()).filter(|s| !s.is_empty()));
+        }
+        strings = &strings[4 + len..];
+    }
+
+    Ok(None)
+}
+
+/// XDBF language IDs count from 1, in CON header slot order
+fn locale_from_language(language: u64) -> Option<Locale> {
+    let index = language.checked_sub(1)?;
+    Locale::ALL.get(usize::try_from(index).ok()?).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn string_table(strings: &[(u16, &str)]) -> Vec<u8> {
+        let mut table = b"XSTR".to_vec();
+        table.extend_from_slice(&1_u32.to_be_bytes());
+        table.extend_from_slice(&0_u32.to_be_bytes());
+        table.extend_from_slice(&(strings.len() as u16).to_be_bytes());
+        for (id, string) in strings {
+            table.extend_from_slice(&id.to_be_bytes());
+            table.extend_from_slice(&(string.len() as u16).to_be_bytes());
+            table.extend_from_slice(string.as_bytes());
+        }
+        table
+    }
+
+    fn xdbf(entries: &[(u16, u64, Vec<u8>)]) -> Vec<u8> {
+        let entry_table_len = entries.len() + 2;
+        let free_table_len = 1;
+
+        let mut bytes = b"XDBF".to_vec();
+        for value in [0x10000, entry_table_len, entries.len(), free_table_len, 0] {
+            bytes.extend_from_slice(&(value as u32).to_be_bytes());
+        }
+
+        let mut data = Vec::new();
+        for (section, id, contents) in entries {
+            bytes.extend_from_slice(&section.to_be_bytes());
+            bytes.extend_from_slice(&id.to_be_bytes());
+            bytes.extend_from_slice(&(data.len() as u32).to_be_bytes());
+            bytes.extend_from_slice(&(contents.len() as u32).to_be_bytes());
+            data.extend_from_slice(contents);
+        }
+
+        let data_offset = XDBF_HEADER_SIZE
+            + entry_table_len * XDBF_ENTRY_SIZE
+            + free_table_len * XDBF_FREE_ENTRY_SIZE;
+        bytes.resize(data_offset, 0);
+        bytes.extend_from_slice(&data);
+        bytes
+    }
+
+    #[test]
+    fn reads_title_names() {
+        let mut xstc = b"XSTC".to_vec();
+        for value in [1_u32, 4, 3] {
+            xstc.extend_from_slice(&value.to_be_bytes());
+        }
+
+        let spa = xdbf(&[
+            (SECTION_METADATA, XSTC_ID, xstc),
+            (
+                SECTION_STRING_TABLE,
+                1,
+                string_table(&[(1, "Achievement"), (TITLE_STRING_ID, "Halo 3")]),
+            ),
+            (
+                SECTION_STRING_TABLE,
+                3,
+                string_table(&[(TITLE_STRING_ID, "Halo 3 (DE)\0")]),
+            ),
+            (SECTION_STRING_TABLE, 12, string_table(&[(1, "no title")])),
+        ]);
+
+        let spa = Spa::parse(&spa).unwrap();
+        assert_eq!(spa.default_locale, Some(Locale::German));
+        assert_eq!(
+            spa.title_names,
+            [
+                (Locale::English, "Halo 3".to_owned()),
+                (Locale::German, "Halo 3 (DE)".to_owned()),
+            ]
+        );
+        assert_eq!(spa.title_name(), Some("Halo 3 (DE)"));
+    }
+
+    #[test]
+    fn refuses_truncated_entries() {
+        let mut spa = xdbf(&[(SECTION_STRING_TABLE, 1, string_table(&[]))]);
+        spa.truncate(spa.len() - 1);
+        assert!(Spa::parse(&spa).is_err());
+    }
+}
@@ -1,41 +1,167 @@
-use crate::executable::TitleExecutionInfo;
+use crate::executable::{GameRegions, TitleExecutionInfo};
 use anyhow::{Error, bail};
+use bitflags::bitflags;
 use byteorder::{LE, ReadBytesExt};
 use std::io::{Read, Seek, SeekFrom};
 
+/// Title images are small textures, usually 128x128; anything past this is a corrupt header
+const MAX_TITLE_IMAGE_SIZE: u32 = 0x100000;
+
 pub struct XbeHeader {
-    // We only need these fields to get the cert address
+    pub signature: Box<[u8; 256]>,
     pub dw_base_addr: u32,
     pub dw_certificate_addr: u32,
+    pub sections: Vec<XbeSectionHeader>,
     pub fields: XbeHeaderFields,
 }
 
+#[derive(Clone, Debug)]
+pub struct XbeSectionHeader {
+    pub flags: u32,
+    pub virtual_addr: u32,
+    pub virtual_size: u32,
+    pub raw_addr: u32,
+    pub raw_size: u32,
+    pub name_addr: u32,
+    pub name: String,
+    pub digest: [u8; 20],
+}
+
+// based on https://xboxdevwiki.net/Xbe
+#[derive(Clone, Debug)]
+pub struct XbeCertificate {
+    pub size: u32,
+    pub time_date: u32,
+    pub title_id: u32,
+    pub title_name: String,
+    pub alternate_title_ids: [u32; 16],
+    pub allowed_media: XbeMediaTypes,
+    pub game_region: XbeGameRegion,
+    pub game_ratings: u32,
+    pub disk_number: u32,
+    pub version: u32,
+}
+
+bitflags! {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct XbeGameRegion: u32 {
+        const NORTH_AMERICA = 0x01;
+        const JAPAN = 0x02;
+        const REST_OF_WORLD = 0x04;
+        const MANUFACTURING = 0x80000000;
+    }
+}
+
+bitflags! {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct XbeMediaTypes: u32 {
+        const HARD_DISK = 0x00000001;
+        const DVD_X2 = 0x00000002;
+        const DVD_CD = 0x00000004;
+        const CD = 0x00000008;
+        const DVD_5_RO = 0x00000010;
+        const DVD_9_RO = 0x00000020;
+        const DVD_5_RW = 0x00000040;
+        const DVD_9_RW = 0x00000080;
+        const DONGLE = 0x00000100;
+        const MEDIA_BOARD = 0x00000200;
+        const NONSECURE_HARD_DISK = 0x40000000;
+        const NONSECURE_MODE = 0x80000000;
+    }
+}
+
+impl From<XbeGameRegion> for GameRegions {
+    fn from(region: XbeGameRegion) -> GameRegions {
+        let mut regions = GameRegions::empty();
+        regions.set(
+            GameRegions::NTSC_U,
+            region.contains(XbeGameRegion::NORTH_AMERICA),
+        );
+        regions.set(GameRegions::NTSC_J, region.contains(XbeGameRegion::JAPAN));
+        regions.set(
+            GameRegions::PAL,
+            region.contains(XbeGameRegion::REST_OF_WORLD),
+        );
+        regions
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct XbeHeaderFields {
     pub execution_info: Option<TitleExecutionInfo>,
+    pub certificate: Option<XbeCertificate>,
+    /// Raw contents of the `$$XTIMAGE` (or `$$XSIMAGE`) section, an XPR0 texture
+    pub title_image: Option<Vec<u8>>,
 }
 
 impl XbeHeader {
     pub fn read<R: Read + Seek>(mut reader: R) -> Result<XbeHeader, Error> {
+        let offset = reader.stream_position()?;
+
         Self::check_magic_bytes(&mut reader)?;
 
+        let mut signature = Box::new([0_u8; 256]);
+        reader.read_exact(signature.as_mut())?;
+
         // Offset 0x0104
-        reader.seek(SeekFrom::Current(256))?;
         let dw_base_addr = reader.read_u32::<LE>()?;
 
         // Offset 0x0118
         reader.seek(SeekFrom::Current(16))?;
         let dw_certificate_addr = reader.read_u32::<LE>()?;
+        let dw_sections = reader.read_u32::<LE>()?;
+        let dw_section_headers_addr = reader.read_u32::<LE>()?;
+
+        let header_offset = |addr: u32| -> Result<u64, Error> {
+            match addr.checked_sub(dw_base_addr) {
+                Some(addr) => Ok(offset + (addr as u64)),
+                None => bail!("XBE header address {addr:#010x} is below the base address"),
+            }
+        };
+
+        reader.seek(SeekFrom::Start(header_offset(dw_section_headers_addr)?))?;
+        let mut sections = (0..dw_sections)
+            .map(|_| XbeSectionHeader::read(&mut reader))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for section in sections.iter_mut() {
+            reader.seek(SeekFrom::Start(header_offset(section.name_addr)?))?;
+            section.name = read_c_string(&mut reader)?;
+        }
+
+        let title_image_section = ["$$XTIMAGE", "$$XSIMAGE"]
+            .iter()
+            .find_map(|name| sections.iter().find(|s| s.name == *name));
 
-        let offset = reader.stream_position()? - 284;
-        let cert_address = dw_certificate_addr - dw_base_addr;
-        reader.seek(SeekFrom::Start(offset + (cert_address as u64)))?;
+        let title_image = if let Some(section) = title_image_section {
+            if section.raw_size > MAX_TITLE_IMAGE_SIZE {
+                bail!(
+                    "XBE section {} is {:#x} bytes long, too long for a title image",
+                    section.name,
+                    section.raw_size
+                );
+            }
+
+            let mut buf = vec![0_u8; section.raw_size as usize];
+            reader.seek(SeekFrom::Start(offset + (section.raw_addr as u64)))?;
+            reader.read_exact(&mut buf)?;
+            Some(buf)
+        } else {
+            None
+        };
+
+        reader.seek(SeekFrom::Start(header_offset(dw_certificate_addr)?))?;
+        let certificate = XbeCertificate::read(&mut reader)?;
 
         Ok(XbeHeader {
+            signature,
             dw_base_addr,
             dw_certificate_addr,
+            sections,
             fields: XbeHeaderFields {
-                execution_info: Some(TitleExecutionInfo::from_xbe(reader)?),
+                execution_info: Some(TitleExecutionInfo::from_xbe(&certificate)),
+                certificate: Some(certificate),
+                title_image,
             },
         })
     }
@@ -51,3 +177,79 @@ impl XbeHeader {
         Ok(())
     }
 }
+
+impl XbeCertificate {
+    fn read<R: Read>(mut reader: R) -> Result<XbeCertificate, Error> {
+        let size = reader.read_u32::<LE>()?;
+        let time_date = reader.read_u32::<LE>()?;
+        let title_id = reader.read_u32::<LE>()?;
+
+        let mut title_name = [0_u16; 40];
+        reader.read_u16_into::<LE>(&mut title_name)?;
+        let title_name_len = title_name.iter().position(|c| *c == 0).unwrap_or(40);
+        let title_name = String::from_utf16_lossy(&title_name[..title_name_len]);
+
+        let mut alternate_title_ids = [0_u32; 16];
+        reader.read_u32_into::<LE>(&mut alternate_title_ids)?;
+
+        let allowed_media = XbeMediaTypes::from_bits_retain(reader.read_u32::<LE>()?);
+        let game_region = XbeGameRegion::from_bits_retain(reader.read_u32::<LE>()?);
+        let game_ratings = reader.read_u32::<LE>()?;
+        let disk_number = reader.read_u32::<LE>()?;
+        let version = reader.read_u32::<LE>()?;
+
+        Ok(XbeCertificate {
+            size,
+            time_date,
+            title_id,
+            title_name,
+            alternate_title_ids,
+            allowed_media,
+            game_region,
+            game_ratings,
+            disk_number,
+            version,
+        })
+    }
+}
+
+impl XbeSectionHeader {
+    fn read<R: Read>(mut reader: R) -> Result<XbeSectionHeader, Error> {
+        let flags = reader.read_u32::<LE>()?;
+        let virtual_addr = reader.read_u32::<LE>()?;
+        let virtual_size = reader.read_u32::<LE>()?;
+        let raw_addr = reader.read_u32::<LE>()?;
+        let raw_size = reader.read_u32::<LE>()?;
+        let name_addr = reader.read_u32::<LE>()?;
+
+        // name ref count, head and tail shared page ref count addresses
+        let _ = reader.read_u32::<LE>()?;
+        let _ = reader.read_u32::<LE>()?;
+        let _ = reader.read_u32::<LE>()?;
+
+        let mut digest = [0_u8; 20];
+        reader.read_exact(&mut digest)?;
+
+        Ok(XbeSectionHeader {
+            flags,
+            virtual_addr,
+            virtual_size,
+            raw_addr,
+            raw_size,
+            name_addr,
+            name: String::new(),
+            digest,
+        })
+    }
+}
+
+fn read_c_string<R: Read>(mut reader: R) -> Result<String, Error> {
+    let mut buf = Vec::new();
+    loop {
+        match reader.read_u8()? {
+            0 => break,
+            b => buf.push(b),
+        }
+    }
+    Ok(String::from_utf8_lossy(&buf).into_owned())
+}
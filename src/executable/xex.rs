@@ -1,21 +1,153 @@
 use std::io::{Read, Seek, SeekFrom};
 
-use byteorder::{BE, ReadBytesExt};
+use byteorder::{BE, ByteOrder, ReadBytesExt};
 
 use bitflags::bitflags;
 use num_enum::TryFromPrimitive;
 
-use crate::executable::TitleExecutionInfo;
-use anyhow::{Error, bail};
+use aes::Aes128;
+use aes::cipher::{BlockDecrypt, KeyInit, generic_array::GenericArray};
+
+use crate::executable::{GameRegions, TitleExecutionInfo, lzx};
+use anyhow::{Context, Error, bail};
+
+// the well-known XEX2 base keys, used to decrypt the per-file session key
+pub(crate) const XEX2_RETAIL_KEY: [u8; 16] = [
+    0x20, 0xb1, 0x85, 0xa5, 0x9d, 0x28, 0xfd, 0xc3, 0x40, 0x58, 0x3f, 0xbb, 0x08, 0x96, 0xbf, 0x91,
+];
+pub(crate) const XEX2_DEVKIT_KEY: [u8; 16] = [0; 16];
+
+/// Titles share 512 MiB with the system; an image past this is a corrupt header
+const MAX_IMAGE_SIZE: u32 = 0x10000000;
 
 #[derive(Clone, Debug)]
 pub struct XexHeader {
     pub module_flags: XexModuleFlags,
     pub code_offset: u32,
     pub certificate_offset: u32,
+    pub security_info: XexSecurityInfo,
     pub fields: XexHeaderFields,
 }
 
+// based on https://free60.org/System-Software/Formats/XEX/
+#[derive(Clone, Debug)]
+pub struct XexSecurityInfo {
+    pub header_size: u32,
+    pub image_size: u32,
+    pub rsa_signature: Box<[u8; 0x100]>,
+    pub image_info_size: u32,
+    pub image_flags: u32,
+    pub load_address: u32,
+    pub image_hash: [u8; 20],
+    pub import_table_count: u32,
+    pub import_table_hash: [u8; 20],
+    pub media_id: [u8; 16],
+    pub file_key: [u8; 16],
+    pub export_table: u32,
+    pub header_hash: [u8; 20],
+    pub game_regions: XexGameRegions,
+    pub allowed_media_types: u32,
+    pub page_descriptors: Vec<XexPageDescriptor>,
+}
+
+impl XexSecurityInfo {
+    /// Offset of the image info (the signed part) from the start of the security info
+    pub const IMAGE_INFO_OFFSET: u32 = 0x108;
+
+    /// Offset of the page descriptor count from the start of the security info;
+    /// everything from here on is covered by the header hash
+    pub const PAGE_DESCRIPTORS_OFFSET: u32 = 0x17c;
+
+    /// Images are split into either 4 KiB or 64 KiB pages
+    pub fn page_size(&self) -> u32 {
+        if self.image_flags & 0x10000000 != 0 {
+            0x1000
+        } else {
+            0x10000
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct XexPageDescriptor {
+    pub page_count: u32,
+    pub info: u8,
+    pub digest: [u8; 20],
+}
+
+#[derive(Clone, Debug)]
+pub struct XexFileFormat {
+    pub encryption: XexEncryption,
+    pub compression: XexCompression,
+}
+
+#[repr(u16)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, TryFromPrimitive)]
+pub enum XexEncryption {
+    None = 0,
+    Normal = 1,
+}
+
+#[derive(Clone, Debug)]
+pub enum XexCompression {
+    None,
+    /// Runs of raw data, each followed by a run of zeros
+    Basic(Vec<XexBasicBlock>),
+    /// LZX compressed data split into hash-chained blocks
+    Normal {
+        window_size: u32,
+        first_block_size: u32,
+        first_block_hash: [u8; 20],
+    },
+    Delta,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct XexBasicBlock {
+    pub data_size: u32,
+    pub zero_size: u32,
+}
+
+bitflags! {
+    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+    pub struct XexGameRegions: u32 {
+        const NTSC_U = 0x000000ff;
+        const NTSC_J_JAPAN = 0x00000100;
+        const NTSC_J_CHINA = 0x00000200;
+        const NTSC_J_OTHER = 0x0000fc00;
+        const PAL_AUSTRALIA = 0x00010000;
+        const PAL_OTHER = 0x00fe0000;
+        const OTHER = 0xff000000;
+    }
+}
+
+impl From<XexGameRegions> for GameRegions {
+    fn from(regions: XexGameRegions) -> GameRegions {
+        let mut result = GameRegions::empty();
+        result.set(
+            GameRegions::NTSC_U,
+            regions.intersects(XexGameRegions::NTSC_U),
+        );
+        result.set(
+            GameRegions::NTSC_J,
+            regions.intersects(
+                XexGameRegions::NTSC_J_JAPAN
+                    | XexGameRegions::NTSC_J_CHINA
+                    | XexGameRegions::NTSC_J_OTHER,
+            ),
+        );
+        result.set(
+            GameRegions::PAL,
+            regions.intersects(XexGameRegions::PAL_AUSTRALIA | XexGameRegions::PAL_OTHER),
+        );
+        result.set(
+            GameRegions::OTHER,
+            regions.intersects(XexGameRegions::OTHER),
+        );
+        result
+    }
+}
+
 bitflags! {
     // based on https://free60.org/System-Software/Formats/XEX/#xex-header
     #[derive(Clone, Copy, PartialEq, Eq, Debug)]
@@ -31,9 +163,19 @@ bitflags! {
     }
 }
 
+/// A named part of the image, like the SPA file named after the title ID
+#[derive(Clone, Debug)]
+pub struct XexResource {
+    pub name: String,
+    pub address: u32,
+    pub size: u32,
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct XexHeaderFields {
     pub execution_info: Option<TitleExecutionInfo>,
+    pub file_format: Option<XexFileFormat>,
+    pub resources: Vec<XexResource>,
     // other fields will be added if and when necessary
 }
 
@@ -81,6 +223,90 @@ impl XexHeader {
         Self::read_checked(reader)
     }
 
+    /// Decrypts and decompresses the image, a PE file, out of the whole XEX file in `data`
+    pub fn read_image(&self, data: &[u8]) -> Result<Vec<u8>, Error> {
+        let Some(file_format) = &self.fields.file_format else {
+            bail!("no file format info in XEX header");
+        };
+        let Some(payload) = data.get(self.code_offset as usize..) else {
+            bail!("XEX image is truncated");
+        };
+
+        let keys: &[[u8; 16]] = match file_format.encryption {
+            XexEncryption::None => return self.decompress_image(&file_format.compression, payload),
+            XexEncryption::Normal => &[XEX2_RETAIL_KEY, XEX2_DEVKIT_KEY],
+        };
+
+        // there is no telling retail and devkit images apart, but either way it is a PE file
+        let mut last_error = None;
+        for key in keys {
+            let session_key = decrypt_session_key(key, &self.security_info.file_key);
+            let payload = decrypt_payload(&session_key, payload);
+            match self.decompress_image(&file_format.compression, &payload) {
+                Ok(image) if image.starts_with(b"MZ") => return Ok(image),
+                Ok(_) => {}
+                Err(e) => last_error = Some(e),
+            }
+        }
+
+        match last_error {
+            Some(e) => Err(e).context("error decrypting XEX image"),
+            None => bail!("error decrypting XEX image"),
+        }
+    }
+
+    /// Unpacks an already decrypted payload; the result is `image_size` bytes long
+    pub(crate) fn decompress_image(
+        &self,
+        compression: &XexCompression,
+        payload: &[u8],
+    ) -> Result<Vec<u8>, Error> {
+        if self.security_info.image_size > MAX_IMAGE_SIZE {
+            bail!(
+                "XEX image is {:#x} bytes long, too long for an executable",
+                self.security_info.image_size
+            );
+        }
+        let image_size = self.security_info.image_size as usize;
+
+        let mut image = match compression {
+            XexCompression::None => payload.to_vec(),
+            XexCompression::Basic(blocks) => {
+                let mut image = Vec::with_capacity(image_size);
+                let mut position = 0;
+                for block in blocks {
+                    let end = position + block.data_size as usize;
+                    let Some(data) = payload.get(position..end) else {
+                        bail!("XEX image is truncated");
+                    };
+                    let unpacked_size = image.len() + data.len() + block.zero_size as usize;
+                    if unpacked_size > image_size {
+                        bail!("XEX image blocks unpack past the image size");
+                    }
+                    image.extend_from_slice(data);
+                    image.resize(unpacked_size, 0);
+                    position = end;
+                }
+                image
+            }
+            XexCompression::Normal {
+                window_size,
+                first_block_size,
+                ..
+            } => {
+                if !window_size.is_power_of_two() {
+                    bail!("invalid XEX compression window size {window_size:#x}");
+                }
+                let compressed = collect_lzx_chunks(payload, *first_block_size)?;
+                lzx::decompress(&compressed, window_size.trailing_zeros(), image_size)?
+            }
+            XexCompression::Delta => bail!("delta patches carry no full image"),
+        };
+
+        image.resize(image_size, 0);
+        Ok(image)
+    }
+
     fn check_magic_bytes<R: Read + Seek>(mut reader: R) -> Result<(), Error> {
         let mut buf = [0_u8; 4];
         reader.read_exact(&mut buf)?;
@@ -124,13 +350,311 @@ impl XexHeader {
                 fields.execution_info = Some(TitleExecutionInfo::from_xex(&mut reader)?);
                 reader.seek(SeekFrom::Start(offset))?;
             };
+
+            if let Some(Key::ResourceInfo) = key {
+                let offset = reader.stream_position()?;
+                reader.seek(SeekFrom::Start(header_offset + (value as u64)))?;
+                fields.resources = XexResource::read_all(&mut reader)?;
+                reader.seek(SeekFrom::Start(offset))?;
+            };
+
+            if let Some(Key::BaseFileFormat) = key {
+                let offset = reader.stream_position()?;
+                reader.seek(SeekFrom::Start(header_offset + (value as u64)))?;
+                fields.file_format = Some(XexFileFormat::read(&mut reader)?);
+                reader.seek(SeekFrom::Start(offset))?;
+            };
         }
 
+        reader.seek(SeekFrom::Start(header_offset + (certificate_offset as u64)))?;
+        let security_info = XexSecurityInfo::read(&mut reader)?;
+
         Ok(XexHeader {
             module_flags,
             code_offset,
             certificate_offset,
+            security_info,
             fields,
         })
     }
 }
+
+/// Compressed blocks start with the size and the hash of the next one, followed by
+/// LZX chunks, each prefixed with its size; the chunks make up a single LZX stream.
+fn collect_lzx_chunks(payload: &[u8], first_block_size: u32) -> Result<Vec<u8>, Error> {
+    let mut compressed = Vec::new();
+    let mut position = 0;
+    let mut block_size = first_block_size as usize;
+
+    while block_size != 0 {
+        let Some(block) = payload
+            .get(position..position + block_size)
+            .filter(|b| b.len() >= 24)
+        else {
+            bail!("XEX image is truncated");
+        };
+
+        let mut chunks = &block[24..];
+        loop {
+            if chunks.len() < 2 {
+                bail!("XEX compressed block is truncated");
+            }
+            let chunk_size = BE::read_u16(chunks) as usize;
+            if chunk_size == 0 {
+                break;
+            }
+            let Some(chunk) = chunks.get(2..2 + chunk_size) else {
+                bail!("XEX compressed block is truncated");
+            };
+            compressed.extend_from_slice(chunk);
+            chunks = &chunks[2 + chunk_size..];
+        }
+
+        block_size = BE::read_u32(block) as usize;
+        position += block.len();
+    }
+
+    Ok(compressed)
+}
+
+pub(crate) fn decrypt_session_key(key: &[u8; 16], file_key: &[u8; 16]) -> [u8; 16] {
+    let cipher = Aes128::new(GenericArray::from_slice(key));
+    let mut block = GenericArray::clone_from_slice(file_key);
+    cipher.decrypt_block(&mut block);
+    block.into()
+}
+
+/// AES-128-CBC with a zero IV; a trailing partial block is left as is
+pub(crate) fn decrypt_payload(session_key: &[u8; 16], payload: &[u8]) -> Vec<u8> {
+    let cipher = Aes128::new(GenericArray::from_slice(session_key));
+    let mut output = payload.to_vec();
+    let mut iv = [0_u8; 16];
+
+    for chunk in output.chunks_exact_mut(16) {
+        let ciphertext: [u8; 16] = (*chunk).try_into().unwrap();
+        cipher.decrypt_block(GenericArray::from_mut_slice(chunk));
+        for (b, v) in chunk.iter_mut().zip(iv) {
+            *b ^= v;
+        }
+        iv = ciphertext;
+    }
+
+    output
+}
+
+impl XexResource {
+    fn read_all<R: Read>(mut reader: R) -> Result<Vec<XexResource>, Error> {
+        let size = reader.read_u32::<BE>()?;
+        (0..size.saturating_sub(4) / 16)
+            .map(|_| {
+                let mut name = [0_u8; 8];
+                reader.read_exact(&mut name)?;
+                let name_len = name.iter().position(|c| *c == 0).unwrap_or(8);
+
+                Ok(XexResource {
+                    name: String::from_utf8_lossy(&name[..name_len]).into_owned(),
+                    address: reader.read_u32::<BE>()?,
+                    size: reader.read_u32::<BE>()?,
+                })
+            })
+            .collect()
+    }
+}
+
+impl XexSecurityInfo {
+    fn read<R: Read + Seek>(mut reader: R) -> Result<XexSecurityInfo, Error> {
+        let header_size = reader.read_u32::<BE>()?;
+        let image_size = reader.read_u32::<BE>()?;
+
+        let mut rsa_signature = Box::new([0_u8; 0x100]);
+        reader.read_exact(rsa_signature.as_mut())?;
+
+        let image_info_size = reader.read_u32::<BE>()?;
+        let image_flags = reader.read_u32::<BE>()?;
+        let load_address = reader.read_u32::<BE>()?;
+
+        let mut image_hash = [0_u8; 20];
+        reader.read_exact(&mut image_hash)?;
+
+        let import_table_count = reader.read_u32::<BE>()?;
+        let mut import_table_hash = [0_u8; 20];
+        reader.read_exact(&mut import_table_hash)?;
+
+        let mut media_id = [0_u8; 16];
+        reader.read_exact(&mut media_id)?;
+
+        let mut file_key = [0_u8; 16];
+        reader.read_exact(&mut file_key)?;
+
+        let export_table = reader.read_u32::<BE>()?;
+
+        let mut header_hash = [0_u8; 20];
+        reader.read_exact(&mut header_hash)?;
+
+        let game_regions = XexGameRegions::from_bits_retain(reader.read_u32::<BE>()?);
+        let allowed_media_types = reader.read_u32::<BE>()?;
+
+        let page_descriptor_count = reader.read_u32::<BE>()?;
+        let page_descriptors = (0..page_descriptor_count)
+            .map(|_| XexPageDescriptor::read(&mut reader))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(XexSecurityInfo {
+            header_size,
+            image_size,
+            rsa_signature,
+            image_info_size,
+            image_flags,
+            load_address,
+            image_hash,
+            import_table_count,
+            import_table_hash,
+            media_id,
+            file_key,
+            export_table,
+            header_hash,
+            game_regions,
+            allowed_media_types,
+            page_descriptors,
+        })
+    }
+}
+
+impl XexPageDescriptor {
+    /// As stored in the security info, and hashed in the page hash chain
+    pub fn to_bytes(&self) -> [u8; 24] {
+        let mut bytes = [0_u8; 24];
+        BE::write_u32(&mut bytes, self.page_count << 4 | self.info as u32);
+        bytes[4..].copy_from_slice(&self.digest);
+        bytes
+    }
+
+    fn read<R: Read>(mut reader: R) -> Result<XexPageDescriptor, Error> {
+        let value = reader.read_u32::<BE>()?;
+
+        let mut digest = [0_u8; 20];
+        reader.read_exact(&mut digest)?;
+
+        Ok(XexPageDescriptor {
+            page_count: value >> 4,
+            info: (value & 0xf) as u8,
+            digest,
+        })
+    }
+}
+
+impl XexFileFormat {
+    fn read<R: Read>(mut reader: R) -> Result<XexFileFormat, Error> {
+        let info_size = reader.read_u32::<BE>()?;
+
+        let encryption = reader.read_u16::<BE>()?;
+        let Ok(encryption) = XexEncryption::try_from(encryption) else {
+            bail!("unknown XEX encryption type {encryption}");
+        };
+
+        let compression = match reader.read_u16::<BE>()? {
+            0 => XexCompression::None,
+            1 => {
+                let block_count = info_size.saturating_sub(8) / 8;
+                let blocks = (0..block_count)
+                    .map(|_| {
+                        Ok(XexBasicBlock {
+                            data_size: reader.read_u32::<BE>()?,
+                            zero_size: reader.read_u32::<BE>()?,
+                        })
+                    })
+                    .collect::<Result<Vec<_>, Error>>()?;
+                XexCompression::Basic(blocks)
+            }
+            2 => {
+                let window_size = reader.read_u32::<BE>()?;
+                let first_block_size = reader.read_u32::<BE>()?;
+                let mut first_block_hash = [0_u8; 20];
+                reader.read_exact(&mut first_block_hash)?;
+                XexCompression::Normal {
+                    window_size,
+                    first_block_size,
+                    first_block_hash,
+                }
+            }
+            3 => XexCompression::Delta,
+            compression => bail!("unknown XEX compression type {compression}"),
+        };
+
+        Ok(XexFileFormat {
+            encryption,
+            compression,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn header_with_image_size(image_size: u32) -> XexHeader {
+        XexHeader {
+            module_flags: XexModuleFlags::TITLE_MODULE,
+            code_offset: 0,
+            certificate_offset: 0,
+            security_info: XexSecurityInfo {
+                header_size: 0,
+                image_size,
+                rsa_signature: Box::new([0; 0x100]),
+                image_info_size: 0x174,
+                image_flags: 0,
+                load_address: 0,
+                image_hash: [0; 20],
+                import_table_count: 0,
+                import_table_hash: [0; 20],
+                media_id: [0; 16],
+                file_key: [0; 16],
+                export_table: 0,
+                header_hash: [0; 20],
+                game_regions: XexGameRegions::empty(),
+                allowed_media_types: 0,
+                page_descriptors: Vec::new(),
+            },
+            fields: Default::default(),
+        }
+    }
+
+    #[test]
+    fn unpacks_basic_blocks() {
+        let blocks = XexCompression::Basic(vec![
+            XexBasicBlock {
+                data_size: 2,
+                zero_size: 3,
+            },
+            XexBasicBlock {
+                data_size: 1,
+                zero_size: 0,
+            },
+        ]);
+
+        let image = header_with_image_size(8)
+            .decompress_image(&blocks, b"MZ!")
+            .unwrap();
+        assert_eq!(image, b"MZ\0\0\0!\0\0");
+    }
+
+    #[test]
+    fn refuses_oversized_images() {
+        let header = header_with_image_size(u32::MAX);
+        assert!(
+            header
+                .decompress_image(&XexCompression::None, b"MZ")
+                .is_err()
+        );
+
+        let zeros = XexCompression::Basic(vec![XexBasicBlock {
+            data_size: 2,
+            zero_size: u32::MAX,
+        }]);
+        assert!(
+            header_with_image_size(0x1000)
+                .decompress_image(&zeros, b"MZ")
+                .is_err()
+        );
+    }
+}
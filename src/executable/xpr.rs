@@ -0,0 +1,282 @@
+use anyhow::{Error, bail};
+use byteorder::{ByteOrder, LE};
+use image::RgbaImage;
+
+// based on https://xboxdevwiki.net/Xbe and the XDK D3D texture formats;
+// only the formats actually used by title images are supported
+
+const XPR_MAGIC: &[u8; 4] = b"XPR0";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TextureFormat {
+    A8R8G8B8,
+    X8R8G8B8,
+    Dxt1,
+    Dxt3,
+    Dxt5,
+    LinA8R8G8B8,
+    LinX8R8G8B8,
+}
+
+impl TextureFormat {
+    fn from_id(id: u32) -> Option<TextureFormat> {
+        match id {
+            0x06 => Some(TextureFormat::A8R8G8B8),
+            0x07 => Some(TextureFormat::X8R8G8B8),
+            0x0c => Some(TextureFormat::Dxt1),
+            0x0e => Some(TextureFormat::Dxt3),
+            0x0f => Some(TextureFormat::Dxt5),
+            0x12 => Some(TextureFormat::LinA8R8G8B8),
+            0x1e => Some(TextureFormat::LinX8R8G8B8),
+            _ => None,
+        }
+    }
+
+    fn data_size(self, width: u32, height: u32) -> usize {
+        let (width, height) = (width as usize, height as usize);
+        match self {
+            TextureFormat::Dxt1 => width.div_ceil(4) * height.div_ceil(4) * 8,
+            TextureFormat::Dxt3 | TextureFormat::Dxt5 => {
+                width.div_ceil(4) * height.div_ceil(4) * 16
+            }
+            _ => width * height * 4,
+        }
+    }
+}
+
+/// Decodes an XPR0 resource holding a single D3D texture, as found in the
+/// `$$XTIMAGE` section of an XBE.
+pub fn decode_xpr_texture(xpr: &[u8]) -> Result<RgbaImage, Error> {
+    if xpr.len() < 32 || &xpr[0..4] != XPR_MAGIC {
+        bail!("missing 'XPR0' magic bytes in texture resource");
+    }
+
+    let header_size = LE::read_u32(&xpr[8..]) as usize;
+
+    // D3DTexture resource: common, data, lock, format, size
+    let data_offset = LE::read_u32(&xpr[16..]) as usize;
+    let format = LE::read_u32(&xpr[24..]);
+    let size = LE::read_u32(&xpr[28..]);
+
+    let format_id = (format >> 8) & 0xff;
+    let Some(texture_format) = TextureFormat::from_id(format_id) else {
+        bail!("unsupported texture format {format_id:#04x}");
+    };
+
+    let (width, height) = if (format >> 20) & 0xf != 0 {
+        (1 << ((format >> 20) & 0xf), 1 << ((format >> 24) & 0xf))
+    } else {
+        ((size & 0xfff) + 1, ((size >> 12) & 0xfff) + 1)
+    };
+
+    let data_start = header_size + data_offset;
+    let data_end = data_start + texture_format.data_size(width, height);
+    if data_end > xpr.len() {
+        bail!("texture data is out of bounds of the resource");
+    }
+
+    Ok(decode_texture(
+        texture_format,
+        width,
+        height,
+        &xpr[data_start..data_end],
+    ))
+}
+
+fn decode_texture(format: TextureFormat, width: u32, height: u32, data: &[u8]) -> RgbaImage {
+    match format {
+        TextureFormat::Dxt1 | TextureFormat::Dxt3 | TextureFormat::Dxt5 => {
+            decode_dxt(format, width, height, data)
+        }
+
+        TextureFormat::A8R8G8B8 | TextureFormat::X8R8G8B8 => {
+            let opaque = format == TextureFormat::X8R8G8B8;
+            RgbaImage::from_fn(width, height, |x, y| {
+                let i = swizzled_index(x, y, width, height) * 4;
+                argb_pixel(LE::read_u32(&data[i..]), opaque)
+            })
+        }
+
+        TextureFormat::LinA8R8G8B8 | TextureFormat::LinX8R8G8B8 => {
+            let opaque = format == TextureFormat::LinX8R8G8B8;
+            RgbaImage::from_fn(width, height, |x, y| {
+                let i = ((y * width + x) * 4) as usize;
+                argb_pixel(LE::read_u32(&data[i..]), opaque)
+            })
+        }
+    }
+}
+
+fn argb_pixel(argb: u32, opaque: bool) -> image::Rgba<u8> {
+    let [b, g, r, a] = argb.to_le_bytes();
+    image::Rgba([r, g, b, if opaque { 0xff } else { a }])
+}
+
+/// Xbox swizzled textures store texels in Morton order, interleaving
+/// the bits of the coordinates for as long as both dimensions allow.
+fn swizzled_index(mut x: u32, mut y: u32, width: u32, height: u32) -> usize {
+    let (mut w, mut h) = (width, height);
+    let mut index = 0_usize;
+    let mut bit = 0;
+
+    while w > 1 || h > 1 {
+        if w > 1 {
+            index |= ((x & 1) as usize) << bit;
+            bit += 1;
+            x >>= 1;
+            w >>= 1;
+        }
+        if h > 1 {
+            index |= ((y & 1) as usize) << bit;
+            bit += 1;
+            y >>= 1;
+            h >>= 1;
+        }
+    }
+
+    index
+}
+
+fn decode_dxt(format: TextureFormat, width: u32, height: u32, data: &[u8]) -> RgbaImage {
+    let mut image = RgbaImage::new(width, height);
+    let block_size = if format == TextureFormat::Dxt1 { 8 } else { 16 };
+    let blocks_per_row = width.div_ceil(4);
+
+    for (block_index, block) in data.chunks_exact(block_size).enumerate() {
+        let bx = (block_index as u32 % blocks_per_row) * 4;
+        let by = (block_index as u32 / blocks_per_row) * 4;
+
+        let (alpha, color_block) = match format {
+            TextureFormat::Dxt3 => (Some(dxt3_alpha(&block[0..8])), &block[8..16]),
+            TextureFormat::Dxt5 => (Some(dxt5_alpha(&block[0..8])), &block[8..16]),
+            _ => (None, block),
+        };
+
+        let colors = dxt_colors(color_block, format == TextureFormat::Dxt1);
+        let indices = LE::read_u32(&color_block[4..]);
+
+        for i in 0..16 {
+            let (x, y) = (bx + i % 4, by + i / 4);
+            if x >= width || y >= height {
+                continue;
+            }
+
+            let mut pixel = colors[((indices >> (i * 2)) & 0b11) as usize];
+            if let Some(alpha) = &alpha {
+                pixel[3] = alpha[i as usize];
+            }
+            image.put_pixel(x, y, image::Rgba(pixel));
+        }
+    }
+
+    image
+}
+
+fn dxt_colors(block: &[u8], allow_transparency: bool) -> [[u8; 4]; 4] {
+    let c0 = LE::read_u16(&block[0..]);
+    let c1 = LE::read_u16(&block[2..]);
+
+    let rgb0 = rgb565(c0);
+    let rgb1 = rgb565(c1);
+
+    let mix = |a: u8, b: u8, wa: u16, wb: u16| ((a as u16 * wa + b as u16 * wb) / (wa + wb)) as u8;
+    let lerp = |wa, wb| -> [u8; 4] {
+        [
+            mix(rgb0[0], rgb1[0], wa, wb),
+            mix(rgb0[1], rgb1[1], wa, wb),
+            mix(rgb0[2], rgb1[2], wa, wb),
+            0xff,
+        ]
+    };
+
+    if c0 > c1 || !allow_transparency {
+        [rgb0, rgb1, lerp(2, 1), lerp(1, 2)]
+    } else {
+        [rgb0, rgb1, lerp(1, 1), [0, 0, 0, 0]]
+    }
+}
+
+fn rgb565(c: u16) -> [u8; 4] {
+    let r = ((c >> 11) & 0x1f) as u8;
+    let g = ((c >> 5) & 0x3f) as u8;
+    let b = (c & 0x1f) as u8;
+    [
+        (r << 3) | (r >> 2),
+        (g << 2) | (g >> 4),
+        (b << 3) | (b >> 2),
+        0xff,
+    ]
+}
+
+fn dxt3_alpha(block: &[u8]) -> [u8; 16] {
+    let bits = LE::read_u64(block);
+    std::array::from_fn(|i| (((bits >> (i * 4)) & 0xf) as u8) * 0x11)
+}
+
+fn dxt5_alpha(block: &[u8]) -> [u8; 16] {
+    let a0 = block[0] as u16;
+    let a1 = block[1] as u16;
+    let bits = LE::read_u48(&block[2..]);
+
+    let alpha = |code: u16| -> u8 {
+        match code {
+            0 => a0 as u8,
+            1 => a1 as u8,
+            _ if a0 > a1 => (((8 - code) * a0 + (code - 1) * a1) / 7) as u8,
+            6 => 0,
+            7 => 0xff,
+            _ => (((6 - code) * a0 + (code - 1) * a1) / 5) as u8,
+        }
+    };
+
+    std::array::from_fn(|i| alpha(((bits >> (i * 3)) & 0b111) as u16))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_xpr(format_id: u32, log2_size: u32, data: &[u8]) -> Vec<u8> {
+        let header_size = 0x800_u32;
+        let format = (format_id << 8) | (log2_size << 20) | (log2_size << 24);
+
+        let mut xpr = vec![0_u8; header_size as usize];
+        xpr[0..4].copy_from_slice(XPR_MAGIC);
+        LE::write_u32(&mut xpr[4..], header_size + data.len() as u32);
+        LE::write_u32(&mut xpr[8..], header_size);
+        LE::write_u32(&mut xpr[24..], format);
+        xpr.extend_from_slice(data);
+        xpr
+    }
+
+    #[test]
+    fn swizzle_interleaves_coordinate_bits() {
+        assert_eq!(swizzled_index(1, 0, 4, 4), 1);
+        assert_eq!(swizzled_index(0, 1, 4, 4), 2);
+        assert_eq!(swizzled_index(3, 3, 4, 4), 15);
+        // the remaining bits of the wider dimension go on top
+        assert_eq!(swizzled_index(2, 0, 4, 2), 4);
+    }
+
+    #[test]
+    fn decodes_dxt1_block() {
+        // c0 = pure red, c1 = pure blue, top row uses all four palette entries
+        let mut block = [0_u8; 8];
+        LE::write_u16(&mut block[0..], 0xf800);
+        LE::write_u16(&mut block[2..], 0x001f);
+        LE::write_u32(&mut block[4..], 0b11_10_01_00);
+
+        let image = decode_xpr_texture(&build_xpr(0x0c, 2, &block)).unwrap();
+
+        assert_eq!(image.dimensions(), (4, 4));
+        assert_eq!(image.get_pixel(0, 0).0, [0xff, 0, 0, 0xff]);
+        assert_eq!(image.get_pixel(1, 0).0, [0, 0, 0xff, 0xff]);
+        assert_eq!(image.get_pixel(2, 0).0, [0xaa, 0, 0x55, 0xff]);
+        assert_eq!(image.get_pixel(0, 1).0, [0xff, 0, 0, 0xff]);
+    }
+
+    #[test]
+    fn rejects_unsupported_format() {
+        assert!(decode_xpr_texture(&build_xpr(0x0b, 2, &[0; 16])).is_err());
+    }
+}
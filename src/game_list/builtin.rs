@@ -0,0 +1,78 @@
+//! The embedded title list, in the format written by `build.rs`: an index
+//! of fixed-size records sorted by title ID, and a string pool, deflated
+//! with the `compressed-db` feature. Both are decoded on first use.
+
+use std::borrow::Cow;
+use std::sync::OnceLock;
+
+use super::{GameListEntry, TitleType};
+
+#[cfg(feature = "builtin-db")]
+const TITLE_INDEX: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/titles.idx"));
+#[cfg(feature = "builtin-db")]
+const TITLE_POOL: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/titles.pool"));
+
+#[cfg(not(feature = "builtin-db"))]
+const TITLE_INDEX: &[u8] = &[];
+#[cfg(not(feature = "builtin-db"))]
+const TITLE_POOL: &[u8] = &[];
+
+const RECORD_SIZE: usize = 0x14;
+
+fn title_pool() -> &'static str {
+    static POOL: OnceLock<Cow<'static, str>> = OnceLock::new();
+
+    POOL.get_or_init(|| {
+        #[cfg(feature = "compressed-db")]
+        let pool = Cow::Owned(
+            miniz_oxide::inflate::decompress_to_vec(TITLE_POOL)
+                .ok()
+                .and_then(|pool| String::from_utf8(pool).ok())
+                .expect("embedded title database is corrupt"),
+        );
+        #[cfg(not(feature = "compressed-db"))]
+        let pool =
+            Cow::Borrowed(str::from_utf8(TITLE_POOL).expect("embedded title database is corrupt"));
+
+        pool
+    })
+}
+
+/// Sorted by title ID
+pub(super) fn builtin_titles() -> &'static [GameListEntry] {
+    static TITLES: OnceLock<Vec<GameListEntry>> = OnceLock::new();
+
+    TITLES.get_or_init(|| {
+        let pool = title_pool();
+        TITLE_INDEX
+            .chunks_exact(RECORD_SIZE)
+            .map(|record| decode_record(record, pool))
+            .collect()
+    })
+}
+
+fn decode_record(record: &[u8], pool: &'static str) -> GameListEntry {
+    let u32_at = |offset: usize| u32::from_le_bytes(record[offset..offset + 4].try_into().unwrap());
+
+    let name_start = u32_at(0x0c) as usize;
+    let name_end = name_start + u16::from_le_bytes([record[0x10], record[0x11]]) as usize;
+    let newest_content_end = name_end + record[0x12] as usize;
+
+    let title_type = match record[0x13] {
+        1 => Some(TitleType::Xbox360),
+        2 => Some(TitleType::Xbla),
+        3 => Some(TitleType::Xbox1),
+        4 => Some(TitleType::HomeBrew),
+        _ => None,
+    };
+
+    GameListEntry {
+        title_id: u32_at(0x00),
+        name: Cow::Borrowed(&pool[name_start..name_end]),
+        title_type,
+        hb_title_id: Some(u32_at(0x04)).filter(|id| *id != 0),
+        media_id_count: u32_at(0x08),
+        newest_content: Cow::Borrowed(&pool[name_end..newest_content_end]),
+        localized_names: Cow::Borrowed(&[]),
+    }
+}
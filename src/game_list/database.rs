@@ -0,0 +1,359 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Error, bail};
+use serde::Deserialize;
+
+use super::*;
+
+/// The built-in title list, with user-supplied entries layered on top, and
+/// user-supplied media lists; there is no built-in one.
+///
+/// Overlay entries replace built-in ones with the same title ID; metadata
+/// they do not specify is kept from the built-in entry.
+#[derive(Default)]
+pub struct TitleDatabase {
+    overlay: BTreeMap<u32, GameListEntry>,
+    media_overlay: BTreeMap<(u32, u32), MediaListEntry>,
+}
+
+/// A single user-supplied entry; only the title ID and the name are required
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct OverlayEntry {
+    #[serde(rename = "TitleID", deserialize_with = "deserialize_hex_id")]
+    pub title_id: u32,
+
+    pub name: String,
+
+    #[serde(default, deserialize_with = "deserialize_title_type")]
+    pub title_type: Option<TitleType>,
+
+    #[serde(
+        default,
+        rename = "HBTitleID",
+        deserialize_with = "deserialize_opt_hex_id"
+    )]
+    pub hb_title_id: Option<u32>,
+
+    #[serde(
+        default,
+        rename = "MediaIDCount",
+        deserialize_with = "deserialize_opt_count"
+    )]
+    pub media_id_count: Option<u32>,
+
+    #[serde(default)]
+    pub newest_content: Option<String>,
+
+    /// An object of locale codes to names, like `{"de": "...", "ja": "..."}`
+    #[serde(default, deserialize_with = "deserialize_localized_names")]
+    pub localized_names: Vec<(Locale, String)>,
+}
+
+impl TitleDatabase {
+    pub fn new() -> TitleDatabase {
+        Default::default()
+    }
+
+    /// Loads a JSONL file in the `titles.jsonl` format, or, if the file name
+    /// ends in `.csv`, a CSV file with `title_id,name[,title_type]` rows.
+    /// Returns the number of entries loaded.
+    pub fn load_overlay(&mut self, path: &Path) -> Result<usize, Error> {
+        let contents = fs::read_to_string(path).context("error reading title database")?;
+
+        let is_csv = path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("csv"));
+
+        let entries = if is_csv {
+            parse_csv(&contents)?
+        } else {
+            parse_jsonl(&contents)?
+        };
+
+        let count = entries.len();
+        for entry in entries {
+            self.insert(entry);
+        }
+
+        Ok(count)
+    }
+
+    /// Loads a JSONL file of media IDs, with `TitleID`, `MediaID`, `Region`, `Revision`,
+    /// `DiscNumber` and `DiscCount` string fields. Returns the number of entries loaded.
+    pub fn load_media_overlay(&mut self, path: &Path) -> Result<usize, Error> {
+        let contents = fs::read_to_string(path).context("error reading media ID database")?;
+
+        let entries = parse_media_jsonl(&contents)?;
+
+        let count = entries.len();
+        for entry in entries {
+            self.insert_media(entry);
+        }
+
+        Ok(count)
+    }
+
+    pub fn insert_media(&mut self, entry: MediaListEntry) {
+        self.media_overlay
+            .insert((entry.title_id, entry.media_id), entry);
+    }
+
+    /// Media IDs are only unique within a title, so both are needed
+    pub fn find_media_by_id(&self, title_id: u32, media_id: u32) -> Option<&MediaListEntry> {
+        self.media_overlay.get(&(title_id, media_id))
+    }
+
+    pub fn insert(&mut self, entry: OverlayEntry) {
+        let base = find_entry_by_id(entry.title_id);
+
+        let merged = GameListEntry {
+            title_id: entry.title_id,
+            name: Cow::Owned(entry.name),
+            title_type: entry.title_type.or(base.and_then(|b| b.title_type)),
+            hb_title_id: entry.hb_title_id.or(base.and_then(|b| b.hb_title_id)),
+            media_id_count: entry
+                .media_id_count
+                .or(base.map(|b| b.media_id_count))
+                .unwrap_or(0),
+            newest_content: entry
+                .newest_content
+                .map(Cow::Owned)
+                .or(base.map(|b| b.newest_content.clone()))
+                .unwrap_or_default(),
+            localized_names: if entry.localized_names.is_empty() {
+                base.map(|b| b.localized_names.clone()).unwrap_or_default()
+            } else {
+                entry
+                    .localized_names
+                    .into_iter()
+                    .map(|(locale, name)| (locale, Cow::Owned(name)))
+                    .collect()
+            },
+        };
+
+        self.overlay.insert(entry.title_id, merged);
+    }
+
+    pub fn find_title_by_id(&self, title_id: u32) -> Option<String> {
+        self.find_entry_by_id(title_id).map(|e| e.name.to_string())
+    }
+
+    pub fn find_entry_by_id(&self, title_id: u32) -> Option<&GameListEntry> {
+        self.overlay
+            .get(&title_id)
+            .or_else(|| find_entry_by_id(title_id))
+    }
+
+    /// Like `find_entry_by_id`, but ignores the built-in entries
+    pub fn find_overlay_entry_by_id(&self, title_id: u32) -> Option<&GameListEntry> {
+        self.overlay.get(&title_id)
+    }
+
+    pub fn entries(&self) -> impl Iterator<Item = &GameListEntry> {
+        let overridden = |e: &&GameListEntry| !self.overlay.contains_key(&e.title_id);
+        self.overlay
+            .values()
+            .chain(entries().iter().filter(overridden))
+    }
+
+    pub fn search_titles(&self, query: &str, limit: usize) -> Vec<TitleMatch<'_>> {
+        search_entries(self.entries(), query, limit)
+    }
+}
+
+fn parse_jsonl(contents: &str) -> Result<Vec<OverlayEntry>, Error> {
+    contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            serde_json::from_str(line).with_context(|| format!("error parsing line {}", i + 1))
+        })
+        .collect()
+}
+
+fn parse_csv(contents: &str) -> Result<Vec<OverlayEntry>, Error> {
+    let mut entries = Vec::new();
+
+    for (i, line) in contents.lines().enumerate() {
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let fields =
+            split_csv_line(line).with_context(|| format!("error parsing line {}", i + 1))?;
+
+        let title_id = match u32::from_str_radix(fields[0].trim(), 16) {
+            Ok(title_id) => title_id,
+            // a header row
+            Err(_) if i == 0 => continue,
+            Err(_) => bail!("invalid title ID {:?} on line {}", fields[0], i + 1),
+        };
+
+        let Some(name) = fields.get(1) else {
+            bail!("missing title name on line {}", i + 1);
+        };
+
+        let title_type = match fields.get(2).map(|s| s.trim()) {
+            Some("") | None => None,
+            Some(s) => Some(s.parse().with_context(|| format!("on line {}", i + 1))?),
+        };
+
+        entries.push(OverlayEntry {
+            title_id,
+            name: name.clone(),
+            title_type,
+            hb_title_id: None,
+            media_id_count: None,
+            newest_content: None,
+            localized_names: Vec::new(),
+        });
+    }
+
+    Ok(entries)
+}
+
+/// Splits a line on commas, honoring double-quoted fields with `""` escapes
+fn split_csv_line(line: &str) -> Result<Vec<String>, Error> {
+    let mut fields = vec![String::new()];
+    let mut in_quotes = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        let field = fields.last_mut().unwrap();
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => fields.push(String::new()),
+            c => field.push(c),
+        }
+    }
+
+    if in_quotes {
+        bail!("unterminated quoted field");
+    }
+
+    Ok(fields)
+}
+
+fn deserialize_hex_id<'de, D: serde::Deserializer<'de>>(d: D) -> Result<u32, D::Error> {
+    let s = String::deserialize(d)?;
+    u32::from_str_radix(&s, 16).map_err(serde::de::Error::custom)
+}
+
+fn deserialize_opt_hex_id<'de, D: serde::Deserializer<'de>>(d: D) -> Result<Option<u32>, D::Error> {
+    let id = deserialize_hex_id(d)?;
+    Ok(Some(id).filter(|id| *id != 0))
+}
+
+fn deserialize_opt_count<'de, D: serde::Deserializer<'de>>(d: D) -> Result<Option<u32>, D::Error> {
+    let s = String::deserialize(d)?;
+    s.parse().map(Some).map_err(serde::de::Error::custom)
+}
+
+fn deserialize_title_type<'de, D: serde::Deserializer<'de>>(
+    d: D,
+) -> Result<Option<TitleType>, D::Error> {
+    let s = String::deserialize(d)?;
+    if s.is_empty() {
+        return Ok(None);
+    }
+    s.parse().map(Some).map_err(serde::de::Error::custom)
+}
+
+fn deserialize_localized_names<'de, D: serde::Deserializer<'de>>(
+    d: D,
+) -> Result<Vec<(Locale, String)>, D::Error> {
+    let names = BTreeMap::<String, String>::deserialize(d)?;
+    names
+        .into_iter()
+        .map(|(locale, name)| Ok((locale.parse().map_err(serde::de::Error::custom)?, name)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csv_fields_can_be_quoted() {
+        assert_eq!(
+            split_csv_line(r#"4D5307E6,"Halo 3: ""ODST"", Campaign",360"#).unwrap(),
+            ["4D5307E6", r#"Halo 3: "ODST", Campaign"#, "360"]
+        );
+        assert!(split_csv_line(r#"4D5307E6,"Halo"#).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "builtin-db")]
+    fn overlay_overrides_and_extends_builtin_entries() {
+        let mut db = TitleDatabase::new();
+        let entries =
+            parse_csv("title_id,name\n00000002,Rent-A-Hero No. 1\nABCD0001,My Homebrew,HomeBrew\n")
+                .unwrap();
+        for entry in entries {
+            db.insert(entry);
+        }
+
+        let fixed = db.find_entry_by_id(0x00000002).unwrap();
+        assert_eq!(fixed.name, "Rent-A-Hero No. 1");
+        assert_eq!(fixed.title_type, Some(TitleType::Xbox1));
+
+        let added = db.find_entry_by_id(0xABCD0001).unwrap();
+        assert_eq!(added.title_type, Some(TitleType::HomeBrew));
+
+        assert_eq!(db.find_title_by_id(0x4D5307E6).as_deref(), Some("Halo 3"));
+        assert_eq!(
+            db.search_titles("my homebrew", 1)[0].entry.title_id,
+            0xABCD0001
+        );
+        assert_eq!(db.entries().filter(|e| e.title_id == 2).count(), 1);
+    }
+
+    #[test]
+    fn jsonl_overlay_uses_the_builtin_format() {
+        let entries = parse_jsonl(
+            r#"{"TitleID":"ABCD0002","Name":"Thing","TitleType":"XBLA","MediaIDCount":"3","LocalizedNames":{"de-DE":"Ding"}}"#,
+        )
+        .unwrap();
+        assert_eq!(entries[0].title_id, 0xABCD0002);
+        assert_eq!(entries[0].title_type, Some(TitleType::Xbla));
+        assert_eq!(entries[0].media_id_count, Some(3));
+
+        let mut db = TitleDatabase::new();
+        db.insert(entries[0].clone());
+        let entry = db.find_entry_by_id(0xABCD0002).unwrap();
+        assert_eq!(entry.localized_name(Locale::German), Some("Ding"));
+        assert_eq!(entry.localized_name(Locale::French), None);
+    }
+
+    #[test]
+    fn media_overlay_describes_discs() {
+        let mut db = TitleDatabase::new();
+        let entries = parse_media_jsonl(
+            r#"{"TitleID":"ABCD0002","MediaID":"12345678","Region":"PAL","Revision":"1","DiscNumber":"2","DiscCount":"2"}"#,
+        )
+        .unwrap();
+        for entry in entries {
+            db.insert_media(entry);
+        }
+
+        let media = db.find_media_by_id(0xABCD0002, 0x12345678).unwrap();
+        assert_eq!(media.to_string(), "PAL disc 2 of 2, revision 1");
+        assert!(db.find_media_by_id(0xABCD0003, 0x12345678).is_none());
+
+        let no_regions = parse_media_jsonl(
+            r#"{"TitleID":"ABCD0003","MediaID":"12345678","Region":"none","Revision":"2","DiscNumber":"1","DiscCount":"1"}"#,
+        )
+        .unwrap();
+        assert_eq!(no_regions[0].to_string(), "revision 2");
+
+        assert!(parse_media_jsonl(r#"{"TitleID":"ABCD0002","MediaID":"12345678","Region":"Mars","Revision":"1","DiscNumber":"1","DiscCount":"1"}"#).is_err());
+    }
+}
@@ -0,0 +1,83 @@
+use std::fmt;
+
+use anyhow::{Context, Error};
+use serde::Deserialize;
+
+use crate::executable::GameRegions;
+
+/// A single disc release of a title, as identified by the XEX media ID
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MediaListEntry {
+    pub title_id: u32,
+    pub media_id: u32,
+    pub regions: GameRegions,
+    pub revision: u32,
+    pub disc_number: u8,
+    pub disc_count: u8,
+}
+
+/// Formats like "PAL disc 2 of 2, revision 1"; regions are left out if there are none
+impl fmt::Display for MediaListEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts = Vec::new();
+        if !self.regions.is_empty() {
+            parts.push(self.regions.to_string());
+        }
+        if self.disc_count > 1 {
+            parts.push(format!("disc {} of {}", self.disc_number, self.disc_count));
+        }
+
+        match parts.is_empty() {
+            true => write!(f, "revision {}", self.revision),
+            false => write!(f, "{}, revision {}", parts.join(" "), self.revision),
+        }
+    }
+}
+
+/// A line of a media ID database
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct MediaRecord {
+    #[serde(rename = "TitleID")]
+    title_id: String,
+    #[serde(rename = "MediaID")]
+    media_id: String,
+    region: String,
+    revision: String,
+    disc_number: String,
+    disc_count: String,
+}
+
+impl TryFrom<MediaRecord> for MediaListEntry {
+    type Error = Error;
+
+    fn try_from(record: MediaRecord) -> Result<MediaListEntry, Error> {
+        let hex_id =
+            |s: &str| u32::from_str_radix(s, 16).with_context(|| format!("invalid ID {s:?}"));
+
+        Ok(MediaListEntry {
+            title_id: hex_id(&record.title_id)?,
+            media_id: hex_id(&record.media_id)?,
+            regions: record.region.parse()?,
+            revision: record.revision.parse().context("invalid revision")?,
+            disc_number: record.disc_number.parse().context("invalid disc number")?,
+            disc_count: record.disc_count.parse().context("invalid disc count")?,
+        })
+    }
+}
+
+pub(super) fn parse_media_jsonl(contents: &str) -> Result<Vec<MediaListEntry>, Error> {
+    contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            parse_media_line(line).with_context(|| format!("error parsing line {}", i + 1))
+        })
+        .collect()
+}
+
+fn parse_media_line(line: &str) -> Result<MediaListEntry, Error> {
+    let record: MediaRecord = serde_json::from_str(line)?;
+    MediaListEntry::try_from(record)
+}
@@ -1,4918 +1,120 @@
-// DO NOT EDIT; this file is auto-generated.
-// Run `./generate.bash titles.jsonl mod.rs` to re-generate.
+use std::borrow::Cow;
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::{Error, bail};
+
+use crate::god::{ContentType, Locale};
+
+mod builtin;
+use builtin::builtin_titles;
+
+mod database;
+pub use database::*;
+
+mod media;
+pub use media::*;
+
+mod search;
+pub use search::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TitleType {
+    Xbox360,
+    Xbla,
+    Xbox1,
+    HomeBrew,
+}
+
+impl fmt::Display for TitleType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Xbox360 => write!(f, "Xbox 360"),
+            Self::Xbla => write!(f, "Xbox Live Arcade"),
+            Self::Xbox1 => write!(f, "Original Xbox"),
+            Self::HomeBrew => write!(f, "Homebrew"),
+        }
+    }
+}
+
+impl TitleType {
+    /// `false` if an image with this content type can not be a genuine copy of such a title
+    pub fn matches_content_type(&self, content_type: ContentType) -> bool {
+        match self {
+            Self::Xbox360 | Self::Xbla => content_type != ContentType::XboxOriginal,
+            Self::Xbox1 => content_type == ContentType::XboxOriginal,
+            Self::HomeBrew => true,
+        }
+    }
+}
+
+/// Accepts both the `titles.jsonl` spelling and the display names
+impl FromStr for TitleType {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<TitleType, Error> {
+        match s.to_ascii_lowercase().as_str() {
+            "360" | "xbox360" | "xbox 360" => Ok(Self::Xbox360),
+            "xbla" | "xbox live arcade" => Ok(Self::Xbla),
+            "xbox1" | "original xbox" => Ok(Self::Xbox1),
+            "homebrew" => Ok(Self::HomeBrew),
+            _ => bail!("unknown title type {s:?}"),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct GameListEntry {
+    pub title_id: u32,
+    pub name: Cow<'static, str>,
+    pub title_type: Option<TitleType>,
+    /// Title ID of the homebrew port, if there is one
+    pub hb_title_id: Option<u32>,
+    pub media_id_count: u32,
+    /// Date of the newest content, as `YYYY-MM-DD`
+    pub newest_content: Cow<'static, str>,
+    /// Names in other languages, if they differ from `name`
+    pub localized_names: Cow<'static, [(Locale, Cow<'static, str>)]>,
+}
+
+impl GameListEntry {
+    pub fn localized_name(&self, locale: Locale) -> Option<&str> {
+        self.localized_names
+            .iter()
+            .find(|(l, _)| *l == locale)
+            .map(|(_, name)| name.as_ref())
+    }
+}
 
 pub fn find_title_by_id(title_id: u32) -> Option<String> {
-    GAMES_BY_TITLE_ID
-        .binary_search_by_key(&title_id, |x| x.0)
+    find_entry_by_id(title_id).map(|e| e.name.to_string())
+}
+
+/// Looks up the built-in title list; empty without the `builtin-db` feature
+pub fn find_entry_by_id(title_id: u32) -> Option<&'static GameListEntry> {
+    let titles = builtin_titles();
+    titles
+        .binary_search_by_key(&title_id, |e| e.title_id)
         .ok()
-        .map(|i| GAMES_BY_TITLE_ID[i].1.to_owned())
+        .map(|i| &titles[i])
+}
+
+pub fn entries() -> &'static [GameListEntry] {
+    builtin_titles()
 }
 
-#[rustfmt::skip]
-const GAMES_BY_TITLE_ID: &[(u32, &str)] = &[
-    (0x00000001, "FX Menu"),
-    (0x00000002, " Rent-A-Hero No. 1"),
-    (0x00000111, "Snes360"),
-    (0x00000113, "XellLaunch"),
-    (0x00000114, "GameBoyAdvance360"),
-    (0x00000115, "GenesisPlus360"),
-    (0x00000116, "PlayStation 1"),
-    (0x00000118, "Snes360 PAL simpel v1"),
-    (0x00000119, "ScummVM"),
-    (0x00000120, "FinalBurnAlpha"),
-    (0x00000121, "DashLaunch"),
-    (0x00000122, "Amiga360"),
-    (0x00000123, "Internet Explorer"),
-    (0x00000124, "3DO"),
-    (0x00000125, "Amstrad CPC"),
-    (0x00000126, "Atari 2600"),
-    (0x00000127, "Atari 5200"),
-    (0x00000128, "Atari 7800"),
-    (0x00000129, "Atari Lynx"),
-    (0x00000130, "Commodore 64"),
-    (0x00000131, "Intellivision"),
-    (0x00000132, "MSX"),
-    (0x00000133, "Neogeo pocket"),
-    (0x00000134, "PCEngine"),
-    (0x00000135, "Pokemon mini"),
-    (0x00000136, "Sega Megadrive"),
-    (0x00000137, "Supervision"),
-    (0x00000138, "Wonderswan"),
-    (0x00000139, "Vectoroids"),
-    (0x00000140, "Rise of the Triad"),
-    (0x00000141, "Road Fighter"),
-    (0x00000142, "Star Control 2"),
-    (0x00000143, "A Tale of Vengeance"),
-    (0x00000144, "Abuse"),
-    (0x00000145, "Biniax 2"),
-    (0x00000146, "Defendguin"),
-    (0x00000147, "Descent"),
-    (0x00000148, "Dink Smallwood"),
-    (0x00000149, "Doom Legacy"),
-    (0x00000150, "Mad Bomber"),
-    (0x00000151, "Maze of Galious"),
-    (0x00000152, "Quake II"),
-    (0x00000153, "Streets of Rage Remake"),
-    (0x00000154, "Stepmania"),
-    (0x00000155, "Pcsxr360xex"),
-    (0x00000156, "Super Transball 2"),
-    (0x00000159, "XellLaunch GOD by Pa0l0ne"),
-    (0x00000160, "XYZProject"),
-    (0x00000161, "COD Ghosts Trainer"),
-    (0x00000166, "Aurora"),
-    (0x00000167, "Freestyle 3"),
-    (0x00000169, "Capcom CPXIII"),
-    (0x00000170, "XexMenu 2.0"),
-    (0x00000171, "FCEUX"),
-    (0x00000172, "DSon360"),
-    (0x00000173, "PCEngine360"),
-    (0x00000174, "MAME360"),
-    (0x00000175, "SMS360"),
-    (0x00000176, "XM360"),
-    (0x00000177, "NXE2GOD"),
-    (0x00000178, "SuperMarioWar"),
-    (0x00000179, "FFPlay"),
-    (0x00000180, "DosBox"),
-    (0x00000181, "Capcom Play System 3 Emulator"),
-    (0x00000182, "Virtual Jaguar x360"),
-    (0x00000183, "Quake 3 360"),
-    (0x00000184, "OpenBOR360"),
-    (0x00000185, "OpenJazz360"),
-    (0x00000186, "MilkyTracker"),
-    (0x00000187, "xBermuda360"),
-    (0x00000188, "Flash360"),
-    (0x00000189, "Simple 360 NAND Flasher"),
-    (0x00000191, "Rick360"),
-    (0x00000192, "Reminiscence360"),
-    (0x00000193, "Raw360"),
-    (0x00000194, "EXULT360"),
-    (0x00000195, "XeBoy Advance"),
-    (0x00000197, "ppsspp"),
-    (0x00000198, "DemoN Nand Switcher"),
-    (0x00000199, "Shutshimi"),
-    (0x00000200, "Penny Arcade's Rain-slick 3"),
-    (0x00000201, "Xbox Invaders"),
-    (0x00000202, "Snes"),
-    (0x00000203, "Nes"),
-    (0x00034D57, "NHL Hitz 2002"),
-    (0x04292006, "StepManiaX"),
-    (0x04541000, "NFS  underground 2"),
-    (0x04555300, "Lego Star Wars"),
-    (0x05040302, "Need for Speed Underground"),
-    (0x05060708, "Need for Speed Underground 2"),
-    (0x05345008, "The House of the Dead 3"),
-    (0x05443003, "Ninja Gaiden"),
-    (0x315A07D1, "NBA 2K16"),
-    (0x315A07D2, "WWE 2K16"),
-    (0x324707D1, "Love Tore: Sweet Version (ラブトレSweet)"),
-    (0x324707D2, " Love Tore: Mint Version (ラブトレMint)"),
-    (0x324707D3, "Love Tore: Bitter Version (ラブトレBitter)"),
-    (0x334807D1, "Ga-sen Love Plus Pengo! (ゲーセンラブ。)"),
-    (0x334807D2, "Shooting Love. 10 Shuunen: XXIZeal & DeltaZeal (シューティングラブ。１０周年)"),
-    (0x354807D1, "Painkiller Hell & Damnation"),
-    (0x354807D5, "Deadfall Adventures"),
-    (0x354807DC, "MX VS ATV Supercross"),
-    (0x354807DD, "Legend of Kay Anniversary"),
-    (0x354807DE, "The Book of Unwritten Tales 2"),
-    (0x373307D2, "Dance Central 2"),
-    (0x373307D9, "Dance Central 3"),
-    (0x373407D1, "Young Justice"),
-    (0x373407D3, "Falling Skies"),
-    (0x373407D4, "Dragons 2"),
-    (0x373407D6, "AT:TSotNK"),
-    (0x373407D7, "Kung Fu Panda"),
-    (0x373407D8, "Adventure Time: FJI"),
-    (0x373407DA, "Monster High: NGIS"),
-    (0x373407DB, "Barbie Puppy"),
-    (0x394707D1, "Netflix"),
-    (0x394F07D1, "Diablo III"),
-    (0x394F07D4, "Diablo III Reaper of Souls"),
-    (0x413307D3, "Air Conflicts: Secret Wars"),
-    (0x413307D6, "Air Conflicts: Pacific Carriers"),
-    (0x413307D9, "Air Conflicts: Vietnam"),
-    (0x41430001, "Dave MIrras Freestyle BMX 2"),
-    (0x41430003, "Legends of Wrestling"),
-    (0x41430004, "Turok Evolution"),
-    (0x41430005, "vexx"),
-    (0x41430006, "Burnout"),
-    (0x41430007, "Aggressive Inline"),
-    (0x4143000A, "ATV Quad Power Racing 2"),
-    (0x4143000B, "Speed Kings"),
-    (0x4143000E, "BMX XXX"),
-    (0x41430014, "Xgra"),
-    (0x41430016, "Alias"),
-    (0x41430019, "Burnout 2"),
-    (0x4143001C, "Allstar Baseball 05"),
-    (0x41430022, "THE Red Star"),
-    (0x41480002, "Playboy the Mansion"),
-    (0x414A07D2, "Gal Gun (ぎゃる☆がん)"),
-    (0x414A07D3, "Ougon Musou Kyoku X (黄金夢想曲Ｘ)"),
-    (0x414B07D1, "A-Train HX"),
-    (0x41500001, "Tenerezza"),
-    (0x415407D1, "Zoids Assault"),
-    (0x415407D2, "Operation Darkness(NA)"),
-    (0x415407D3, "Spectral Force 3"),
-    (0x415407D5, "Catherine (キャサリン)"),
-    (0x415407D6, "The Cursed Crusade"),
-    (0x415407D7, "Catherine"),
-    (0x415407D8, "Divinity II: TDKS"),
-    (0x415407DE, "Game of Thrones"),
-    (0x415407E0, "Testament of Sherlock Holmes"),
-    (0x415407E2, "P4AU"),
-    (0x41560001, "Tony Hawks Pro Skater 2x"),
-    (0x41560002, "Kelly Slaters Pro Surfer"),
-    (0x41560004, "Tony Hawk pro skater 3"),
-    (0x41560005, "Blade II"),
-    (0x41560006, "Spider-Man"),
-    (0x41560007, "X-Men: Next Dimension"),
-    (0x41560008, "X2 Wolverines Revenge"),
-    (0x41560009, "Rally Fusion"),
-    (0x4156000A, "Wreckless The Yakuza Missions"),
-    (0x4156000B, "Matt Hoffmans Pro BMX 2"),
-    (0x4156000D, "Wakeboarding Unleashed"),
-    (0x4156000E, "TrueCrimeStreetsOfLa"),
-    (0x41560010, "Return to Castle Wolfenstein"),
-    (0x41560015, "jedi knight II"),
-    (0x41560017, "Tony Hawks Pro Skater 4"),
-    (0x41560018, "Pitfall The Lost Expedition"),
-    (0x4156001A, "Fantastic 4"),
-    (0x4156001B, "Soldier of Fortune II"),
-    (0x4156001E, "xmen legends"),
-    (0x41560020, "DOOM 3"),
-    (0x41560022, "MTX Mototrax"),
-    (0x41560026, "Tenchu Return From Darkness"),
-    (0x4156002A, "Call Of Duty Finest Hour"),
-    (0x4156002B, "SpiderMan 2"),
-    (0x4156002D, "Tony Hawks Underground"),
-    (0x41560030, "Cabelas Deer Hunt 2004 Season"),
-    (0x41560035, "Ultimate SpiderMan"),
-    (0x41560036, "Tenchu Return from Darkness"),
-    (0x41560037, "Shrek2"),
-    (0x41560038, "Shark Tale"),
-    (0x4156003A, "Rapala Pro Fishing"),
-    (0x4156003B, "MADAGASCAR"),
-    (0x4156003C, "Tony Hawks Underground 1"),
-    (0x4156003D, "American Chopper"),
-    (0x4156003E, "Cabelas Big Game Hunter 2005 Adventures"),
-    (0x4156003F, "Cabelas Deer Hunt 2005 Season"),
-    (0x41560041, "Monster Garage"),
-    (0x41560042, "Greg Hastings Paintball"),
-    (0x41560045, "True Crime New York City"),
-    (0x41560047, "xmen legends 2"),
-    (0x41560048, "Shrek Super Slam"),
-    (0x41560049, "American Wasteland"),
-    (0x4156004B, "gun"),
-    (0x4156004D, " Cabela's Outdoor Adventure"),
-    (0x4156004E, "Doom 3 Resurrection Of Evil"),
-    (0x4156004F, "American Chopper 2 Full Trottle"),
-    (0x41560051, "Call of Duty 2 Big Red One"),
-    (0x41560052, "Cabelas Dangerous Hunts 2"),
-    (0x41560055, "TY the Tasmanian Tiger 3"),
-    (0x41560058, "Over the Hedge"),
-    (0x4156005D, "Call of Duty 3"),
-    (0x4156005E, "Tony Hawks Project 8"),
-    (0x415607D1, "Call of Duty 2"),
-    (0x415607D2, "QUAKE 4"),
-    (0x415607D3, "Gun"),
-    (0x415607D4, "American Wasteland"),
-    (0x415607D6, "Quake 2"),
-    (0x415607D7, "QUAKE 4"),
-    (0x415607D9, "WSOP: TOC"),
-    (0x415607DA, "Marvel Ult. Alliance"),
-    (0x415607DB, "X-Men:TheOfficialGame"),
-    (0x415607DC, "Alaskan Adventures"),
-    (0x415607DD, "Tony Hawk's Project 8"),
-    (0x415607DE, "Wolfenstein"),
-    (0x415607DF, "ET: QUAKE Wars"),
-    (0x415607E0, "Pimp My Ride"),
-    (0x415607E1, "Call of Duty 3"),
-    (0x415607E2, "Spider-Man 3"),
-    (0x415607E4, "Civil War"),
-    (0x415607E5, "Cabela's Safari"),
-    (0x415607E6, "Modern Warfare"),
-    (0x415607E7, "Guitar Hero II"),
-    (0x415607E8, "SHReK the THiRD"),
-    (0x415607EB, "Rapala Tournament"),
-    (0x415607EF, "Singularity"),
-    (0x415607F0, "TH Proving Ground"),
-    (0x415607F1, "Transformers: The Game"),
-    (0x415607F2, "Kung Fu Panda"),
-    (0x415607F5, "Bee Movie Game"),
-    (0x415607F7, "Guitar Hero III"),
-    (0x415607FA, "Spider-Man: FoF"),
-    (0x415607FF, "Quantum of Solace"),
-    (0x41560800, "Cabela's Big Game Hunter"),
-    (0x41560801, "Hot Wheels: Beat That!"),
-    (0x41560802, "SOF Payback"),
-    (0x41560803, "Monster Jam"),
-    (0x41560804, "Cabela's Trophy Bucks"),
-    (0x41560805, "Battle for the Pacific"),
-    (0x41560806, "WSOP: 2008"),
-    (0x41560808, "Rapala Fishing Frenzy"),
-    (0x41560811, "Hot Wheels: Beat That!"),
-    (0x41560815, "Spidey: Web of Shadows"),
-    (0x41560816, "XMen Origins Wolverine"),
-    (0x41560817, "Modern Warfare 2"),
-    (0x41560819, "Guitar Hero: Aerosmith"),
-    (0x4156081A, "Guitar Hero World Tour"),
-    (0x4156081C, "Call of Duty: WaW"),
-    (0x4156081D, "Madagascar 2"),
-    (0x4156081F, "James Bond 007: Blood Stone"),
-    (0x41560820, "Tony Hawk: RIDE"),
-    (0x41560823, "Baja 1000"),
-    (0x41560824, "Secret Service"),
-    (0x41560825, "CW: Secret Missions"),
-    (0x41560826, "Dangerous Hunts 2009"),
-    (0x41560827, "Paintball 2009"),
-    (0x4156082A, "Monsters vs. Aliens"),
-    (0x4156082F, "Marvel Ult. Alliance 2"),
-    (0x41560830, "Guitar Hero Metallica"),
-    (0x41560834, "Transformers 2"),
-    (0x4156083D, "Guitar Hero Van Halen"),
-    (0x4156083E, "Guitar Hero Hits"),
-    (0x4156083F, "Ice Age 3"),
-    (0x41560840, "Guitar Hero 5"),
-    (0x41560844, "DJ Hero"),
-    (0x41560845, "Blur"),
-    (0x4156084C, "Transformers: WFC"),
-    (0x4156084E, "[PROTOTYPE]"),
-    (0x41560855, "Call of Duty: Black Ops"),
-    (0x41560857, "Bakugan"),
-    (0x41560858, "Madagascar Kartz"),
-    (0x4156085A, "Chaotic: Shadow Warriors"),
-    (0x4156085B, "Outdoor Adventures"),
-    (0x4156085C, "Band Hero"),
-    (0x4156085F, "Cabela's BGH 2010"),
-    (0x41560870, "Jurassic: The Hunted"),
-    (0x41560876, "Shrek Forever After"),
-    (0x41560877, "HowToTrainYourDragon"),
-    (0x4156087F, "DJ Hero 2"),
-    (0x41560881, "Spider-Man:Dimensions"),
-    (0x41560883, "GH: Warriors of Rock"),
-    (0x41560886, "LLWS2010"),
-    (0x41560888, "TONY HAWK: SHRED"),
-    (0x41560889, "Rapala Pro Bass"),
-    (0x4156088B, "Apache: Air Assault"),
-    (0x4156088C, "MJ Path of Destruction"),
-    (0x4156088D, "Blood Drive"),
-    (0x4156088F, "Cabela's NAA"),
-    (0x41560890, "Cabela's DH 2011"),
-    (0x41560891, "Bakugan™: DOTC"),
-    (0x41560893, "NASCAR The Game 2011"),
-    (0x4156089C, "TRANSFORMERS: FoC"),
-    (0x415608A4, "TRANSFORMERS: Dark of the Moon"),
-    (0x415608A7, "PROTOTYPE 2"),
-    (0x415608AA, "X-Men: Destiny"),
-    (0x415608AB, "Skylanders Spyro's Adventure"),
-    (0x415608AD, "Generator Rex"),
-    (0x415608AE, "Wipeout: In The Zone"),
-    (0x415608AF, "GoldenEye Reloaded"),
-    (0x415608B2, "Spider-Man: EoT"),
-    (0x415608B4, "DreamWorks Kartz"),
-    (0x415608B5, "The Amazing Spider-Man"),
-    (0x415608BB, "NASCAR Unleashed"),
-    (0x415608BD, "Cabela's Big Game Hunter 2012"),
-    (0x415608BE, "Cabela's Survival: SoK"),
-    (0x415608C2, "Cabela's Hunting Party"),
-    (0x415608C3, "COD: Black Ops II"),
-    (0x415608C5, "Family Guy: BTTM"),
-    (0x415608C6, "Rapala for Kinect"),
-    (0x415608C7, "Adventure Camp"),
-    (0x415608CB, "Modern Warfare 3"),
-    (0x415608CC, "Wipeout 2"),
-    (0x415608CE, "Big League Sports"),
-    (0x415608D1, "Battleship"),
-    (0x415608D2, "NASCAR Inside Line"),
-    (0x415608D3, "MIB: Alien Crisis"),
-    (0x415608D7, "Ice Age 4"),
-    (0x415608D8, "007 Legends"),
-    (0x415608DA, "Skylanders Giants"),
-    (0x415608DB, "Cabela's Dangerous Hunts 2013"),
-    (0x415608DC, "Cabela's Hunting Expeditions"),
-    (0x415608DD, "Wipeout 3"),
-    (0x415608E1, "TWD: Survival Instinct"),
-    (0x415608E4, "Angry Birds Trilogy"),
-    (0x415608E9, "Skylanders SWAP Force"),
-    (0x415608EC, "Deadpool"),
-    (0x415608ED, "F&F: Showdown"),
-    (0x415608F8, "Destiny"),
-    (0x415608FA, "Cabela's African Adventure"),
-    (0x415608FC, "Call of Duty : Ghosts"),
-    (0x415608FD, "TMNT"),
-    (0x415608FE, "Cabela's BGH Pro Hunts"),
-    (0x415608FF, "Wipeout C&C"),
-    (0x41560900, "SB: Robotic Revenge"),
-    (0x41560901, "Angry Birds Star Wars"),
-    (0x41560904, "TRANSFORMERS: RotDS"),
-    (0x41560905, "The Amazing Spider-Man 2"),
-    (0x4156090C, "TMNT: DotO"),
-    (0x4156090D, "Duck Dynasty"),
-    (0x41560911, "Skylanders Trap Team(TM)"),
-    (0x41560912, "The Voice"),
-    (0x41560914, "Call of Duty: Advanced Warfare"),
-    (0x41560919, "SBHP"),
-    (0x4156091B, "Pro Skater™ 5"),
-    (0x4156091C, "Destiny Demo"),
-    (0x4156091D, "COD: Black Ops III"),
-    (0x4156091E, "TMNT: MiM"),
-    (0x4156091F, "Skylanders SuperChargers"),
-    (0x41560920, "Guitar Hero Live"),
-    (0x41560922, "Peanuts® Movie"),
-    (0x41560923, "TRANSFORMERS: Dev"),
-    (0x41560924, "King’s Quest – Ch. 1"),
-    (0x41560926, "King's Quest"),
-    (0x41560929, "Black Ops III Bundle"),
-    (0x41560930, "Skylanders Imaginators"),
-    (0x41560931, "COD: MW Bundle"),
-    (0x415707D1, "GUILTY GEAR 2"),
-    (0x415707D2, "SHIKIGAMI NO SHIRO 3"),
-    (0x415707D4, "Battle Fantasia"),
-    (0x415707DA, "BLAZBLUE CS"),
-    (0x415707DC, "Arcana Heart 3"),
-    (0x415707DD, "BLAZBLUE CONTINUUM SHIFT EXTEND"),
-    (0x415807D5, "BlazBlue"),
-    (0x415807D7, "Record of Agarest War"),
-    (0x415807D8, "DEATHSMILES NA"),
-    (0x415807D9, "Record of Agarest War Zero"),
-    (0x41590002, "Stubbs The Zombie in Rebel Without A Pulse"),
-    (0x41590004, "Dreamfall"),
-    (0x415907D1, "Supreme Commander"),
-    (0x415907D3, "Dreamfall The Longest Journey"),
-    (0x415907D4, "Stubbs the Zombie in Rebel Without a Pulse"),
-    (0x415907D5, "Supreme Commander"),
-    (0x415907D7, "BCFx-Doug Williams Ed."),
-    (0x42410001, "Digimon Rumble Arena 2"),
-    (0x42410002, "Digimon World 4"),
-    (0x424107D2, "Culdcept SAGA"),
-    (0x424107D6, "GUNDAM OPERATION TROY"),
-    (0x424107DC, "DBZ: BURST LIMIT"),
-    (0x424107DD, "Culdcept SAGA"),
-    (0x424507D1, "KUF: Circle of Doom"),
-    (0x424907D1, "WSC2009"),
-    (0x424907D4, "WSC Real 2011"),
-    (0x424C07D1, "Sega Soccer Slam"),
-    (0x424D0001, "Chase Hollywood Stunt Driver"),
-    (0x424D0002, "Reign Of  Fire"),
-    (0x424D0003, "WallaceAndGromitInProjectZoo"),
-    (0x425007D1, "スーパーロボット大戦ＸＯ"),
-    (0x42530001, "Morrowind"),
-    (0x42530004, "PiratesOfTheCaribbean"),
-    (0x42530005, "Morrowind Game of the Year"),
-    (0x42530007, "call of cthulhu"),
-    (0x4253000B, "Powerdrome"),
-    (0x4253000C, "IHRA Professional Drag Racing 2005"),
-    (0x42530011, "IHRA Sportsman Edition"),
-    (0x42530014, "AMF Xtreme bowling 2006"),
-    (0x425307D1, "Oblivion"),
-    (0x425307D2, "Star Trek Legacy"),
-    (0x425307D3, "Rogue Warrior"),
-    (0x425307D5, "Fallout 3"),
-    (0x425307D7, "Hunted Demon's Forge"),
-    (0x425307D9, "Brink"),
-    (0x425307DB, "WET"),
-    (0x425307E0, "Fallout: New Vegas"),
-    (0x425307E3, "Dishonored"),
-    (0x425307E6, "Skyrim"),
-    (0x425307EC, "RAGE"),
-    (0x425307F1, "DOOM 3 BFG Edition"),
-    (0x425307F3, "Dishonored Game of the Year Edition"),
-    (0x425307F4, "The Evil Within"),
-    (0x425307F5, "Wolfenstein: The New Order"),
-    (0x425407D1, "Jumper"),
-    (0x425407D4, "Space Chimps"),
-    (0x42560001, "Tron 20 Killer App"),
-    (0x42560003, "The Chronicles of Narnia: The Lion, the Witch, and the Wardrobe"),
-    (0x425607D1, "At Worlds End"),
-    (0x425607D3, "Turok"),
-    (0x425607D4, "Meet the Robinsons"),
-    (0x425607D9, "Pure"),
-    (0x425607DB, "Narnia: Prince Caspian"),
-    (0x425607DE, "Disney Bolt"),
-    (0x425607DF, "HSM3 Senior Year DANCE"),
-    (0x425607E0, "Disney Sing It"),
-    (0x425607E5, "Toy Story 3"),
-    (0x425607E6, "Disney Sing It HSM3"),
-    (0x425607E7, "Split/Second"),
-    (0x425607E8, "G-Force"),
-    (0x425607E9, "Hannah The Movie"),
-    (0x425607ED, "Tron: Evolution"),
-    (0x425607FE, "Cars 2: The Video Game"),
-    (0x42560802, "LEGO Pirates of the Caribbean: The Video Game"),
-    (0x42560803, "Disney Universe"),
-    (0x42560816, "Disney Epic Mickey 2: The Power of Two"),
-    (0x42560817, "Brave: The Video Game"),
-    (0x42560819, "Toy Story Mania!"),
-    (0x4256081E, "Disney Infinity"),
-    (0x42560824, "Fantasia: Music Evolved"),
-    (0x4256082C, "Disney Infinity (2.0 Edition)"),
-    (0x42560832, "Disney Infinity 3.0"),
-    (0x42570001, "Robot Wars"),
-    (0x425A07D1, "Zapper"),
-    (0x42725270, "Land of the Dead Road to Fiddlers Green"),
-    (0x434107D1, "BulletWitch"),
-    (0x434107D2, "TGM ACE"),
-    (0x434107D3, "Vampire Rain"),
-    (0x434107D5, "通信対戦麻雀 闘龍門"),
-    (0x43430001, "Genma Onimusha"),
-    (0x43430003, "DINO CRISIS 3"),
-    (0x43430007, "MarvelVsCapcom2"),
-    (0x43430008, "Capcom vs SNK 2 EO"),
-    (0x4343000C, "Robin Hood Defender of the Crown"),
-    (0x4343000E, "Auto Modellista"),
-    (0x4343000F, "StreetFighterAnniversaryCollection"),
-    (0x43430011, "Megaman Anniversary Collection"),
-    (0x43430013, "Final Fight  Streetwise"),
-    (0x43430014, "Without Warning"),
-    (0x43430015, "BeatDown  Fists of Vengeance"),
-    (0x43430016, "Darkwatch"),
-    (0x43430018, "Capcom Classics Volume 1"),
-    (0x434307D2, "DEAD RISING"),
-    (0x434307D3, "LOST PLANET"),
-    (0x434307D4, "RESIDENT EVIL 5"),
-    (0x434307DD, "Bionic Commando"),
-    (0x434307DE, "LOST PLANET COLONIES"),
-    (0x434307DF, "Devil May Cry 4"),
-    (0x434307E0, "Dark Void"),
-    (0x434307E1, "STREET FIGHTER IV"),
-    (0x434307E6, "MotoGP 08"),
-    (0x434307EC, "Dead Rising 2"),
-    (0x434307ED, "LOST PLANET 2"),
-    (0x434307F0, "MotoGP 09/10"),
-    (0x434307FC, "SUPER STREETFIGHTER IV ARCADE EDITION"),
-    (0x43430806, "MARVEL VS. CAPCOM 3"),
-    (0x4343080B, "Moto GP 10/11"),
-    (0x4343080F, "STREET FIGHTER X TEKKEN"),
-    (0x43430814, "DD: Dark Arisen"),
-    (0x43430816, "STEEL BATTALION HEAVY ARMOR"),
-    (0x43430817, "ASURA'S WRATH"),
-    (0x43430819, "RESIDENT EVIL 6"),
-    (0x4343081C, "Resident Evil 4"),
-    (0x4343081D, "Resident Evil Operation Raccoon City"),
-    (0x4343081E, "DMC HD Collection"),
-    (0x4343081F, "DEAD RISING 2 OFF THE RECORD"),
-    (0x43430821, "U. MARVEL VS. CAPCOM 3"),
-    (0x43430822, "RESIDENT EVIL CODE: Veronica X"),
-    (0x43430824, "DmC"),
-    (0x4343082B, "Capcom DC"),
-    (0x4343082F, "Remember Me"),
-    (0x43430830, "Lost Planet 3"),
-    (0x43430833, "RESIDENT EVIL REV."),
-    (0x4343083F, "Ducktales Remastered"),
-    (0x43430840, "Resident Evil: Revelations 2"),
-    (0x43430841, "Resident Evil"),
-    (0x43430842, "Resident Evil 0"),
-    (0x434407D6, "Divinity II"),
-    (0x434607D3, "Strike Witches: Wings of Silver (ストライクウィッチーズ 白銀の翼)"),
-    (0x434D0002, "Race Driver"),
-    (0x434D0010, "ColinMcraeRally04"),
-    (0x434D0011, "Toca Race Driver 2"),
-    (0x434D0024, "IndyCar Series 2005"),
-    (0x434D002B, "Colin McRae Rally 2005"),
-    (0x434D0046, "LMA Manager 2005"),
-    (0x434D0047, "Second Sight"),
-    (0x434D0050, "TOCA Race Driver 3"),
-    (0x434D0052, "Worms 4 Mayhem"),
-    (0x434D07D2, "LMA Manager 2007"),
-    (0x434D07D5, "Overlord"),
-    (0x434D07D8, "ICC Cricket 2007"),
-    (0x434D07DB, "DiRT"),
-    (0x434D07DE, "Rise of the Argonauts"),
-    (0x434D07E0, "Turning Point"),
-    (0x434D07E4, "Brian Lara International Cricket 2007"),
-    (0x434D07E9, "Clive Barker's Jericho"),
-    (0x434D07FF, "GRID"),
-    (0x434D0801, "Turning Point [FR/DE]"),
-    (0x434D0806, "Damnation"),
-    (0x434D0808, "You're in the Movies"),
-    (0x434D0809, "Overlord II"),
-    (0x434D080D, "FUEL"),
-    (0x434D0811, "OF: Dragon Rising"),
-    (0x434D0819, "DiRT 2"),
-    (0x434D0822, "Leisure Suit Larry"),
-    (0x434D082B, "Ashes Cricket 2009"),
-    (0x434D082D, "F1 2010"),
-    (0x434D0836, "Int'l Cricket 2010"),
-    (0x434D0838, "Operation Flashpoint: Red River"),
-    (0x434D0839, "Bodycount"),
-    (0x434D083D, "DiRT 3"),
-    (0x434D0842, "F1 2011"),
-    (0x434D0844, "GRID 2"),
-    (0x434D0845, "DiRT Showdown"),
-    (0x434D0847, "F1 2012"),
-    (0x434D0849, "F1 RACE STARS"),
-    (0x434D084F, "F1 2013"),
-    (0x434D0851, "GRID Autosport"),
-    (0x434D0852, "F1 2014"),
-    (0x434E07D2, "Winter Sports 2"),
-    (0x434E07D3, "Autobahn Polizei"),
-    (0x434E07D5, "SBK (NA)"),
-    (0x43560002, "ufc tapout"),
-    (0x43560009, "Trigger Man"),
-    (0x43560011, "TheBibleGame"),
-    (0x435607D1, "WCP All In"),
-    (0x435607D5, "Crash Time"),
-    (0x435607D6, "Intellivision Lives"),
-    (0x435607D7, "Pinball Hall of Fame"),
-    (0x435607D8, "Deadliest Catch: Sea of Chaos"),
-    (0x435607D9, "Man vs. Wild"),
-    (0x435607DA, "Brunswick Pro Bowling"),
-    (0x435607DB, "CNPTE: XL"),
-    (0x435907D1, "Sniper: Ghost Warrior"),
-    (0x435907D4, "Sniper Ghost Warrior 2"),
-    (0x435907D5, "Enemy Front"),
-    (0x435A07D5, "DEATHSMILES"),
-    (0x435A07D7, "虫姫さまふたり"),
-    (0x435A07D8, "ESPGALUDA2"),
-    (0x435A07D9, "DeathSmiles2 X"),
-    (0x435A07DC, "怒首領蜂 大復活 ver 1.5"),
-    (0x435A07DD, "赤い刀 真 (Akai Katana Shin)"),
-    (0x435A07DE, "Muchi Muchi Pork! & Pink Sweets (むちむちポーク！＆ピンクスゥイーツ)"),
-    (0x435A07DF, "怒首領蜂 大復活 Black Label"),
-    (0x435A07E0, "Instant Brain"),
-    (0x435A07E3, "Mushihimesama Disc Xbox 360"),
-    (0x435A07E7, "Dodonpachi saidaioujyou"),
-    (0x443607D0, "Jurassic Park: The Game"),
-    (0x443607D2, "The Walking Dead"),
-    (0x443607D4, "The Walking Dead: Season 2"),
-    (0x443607D5, "The Wolf Among Us"),
-    (0x443607D6, "Back to the Future: The Game"),
-    (0x443607D7, "The Jackbox Party Pack"),
-    (0x443607D8, "Game of Thrones "),
-    (0x443607D9, "Minecraft: Story Mode"),
-    (0x443607DB, "Batman: The Telltale Series"),
-    (0x443607DC, "Minecraft: Story Mode - Complete"),
-    (0x443607DF, "Minecraft: Story Mode - Season Two"),
-    (0x44430002, "Arx Fatalis"),
-    (0x44430003, "Painkiller"),
-    (0x444507D2, "Indy 500 Evolution"),
-    (0x444507D3, "Stoked"),
-    (0x444507D5, "Stoked: Big Air Ed."),
-    (0x444707D3, "Phantom of Inferno (ファントム　ファントム　オブ　インフェルノ)"),
-    (0x445007D2, "お姉チャンバラvorteX"),
-    (0x445007D3, "EarthDefenseForce 2017"),
-    (0x445007D4, "Dark Sector"),
-    (0x445007EA, "Onechanbara"),
-    (0x445007EB, "Eat Lead"),
-    (0x445007F0, "ドリームクラブ"),
-    (0x445007F1, "BEN 10: VILGAX ATTACKS"),
-    (0x445007F4, "Onechambara Z Kagura(お姉チャンバラZ ～カグラ～)"),
-    (0x445007F7, "BEN 10 ULTIMATE ALIEN"),
-    (0x445007F9, "Earth Defense Force: IA"),
-    (0x445007FA, "Victorious: Time to Shine"),
-    (0x445007FC, "Ben 10 Galactic Racing"),
-    (0x445007FF, "Mahjong Dream Club (マージャン★ドリームクラブ)"),
-    (0x44500801, "Madagascar 3: The Video Game"),
-    (0x44500802, "Ben 10 Omniverse"),
-    (0x44500803, "RISE OF THE GUARDIANS"),
-    (0x44500804, "Top Hand Rodeo Tour"),
-    (0x44500805, "Earth Defense Force 2025"),
-    (0x44500806, "Turbo: Super Stunt Squad"),
-    (0x44500807, "Adventure Time: Explore"),
-    (0x44500808, "Ben 10 Omniverse"),
-    (0x445407D1, "Venetica"),
-    (0x445407D2, "Summer Athletics"),
-    (0x445407D3, "Divinity II"),
-    (0x445407D4, "Gray Matter"),
-    (0x445407D6, "Summer Athletics 2009"),
-    (0x445407D9, "Crash Time 4 - The Syndicate"),
-    (0x445407DA, "Winter Sports 2011"),
-    (0x445407DE, "The Cursed Crusade"),
-    (0x445407E1, "Crash Time 5"),
-    (0x453907D2, "P4A"),
-    (0x45410004, "SSX Tricky"),
-    (0x4541000A, "Knockout Kings 2002"),
-    (0x4541000B, "World Cup 2002"),
-    (0x4541000D, "007 Agent Under Fire"),
-    (0x4541000E, "Harry Potter Chamber of Secrets"),
-    (0x45410010, "LordOfTheRingsTheTwoTowers"),
-    (0x45410012, "Buffy The Vampire Slayer"),
-    (0x45410013, "The Simpsons Road Rage"),
-    (0x45410015, "Pirates The Legend of Black Kat"),
-    (0x4541001A, "Medal of Honor Frontline"),
-    (0x45410021, "Tiger Woods PGA Tour 2003"),
-    (0x45410026, "007 Nightfire"),
-    (0x45410027, "NBA Street Vol. 2"),
-    (0x45410028, "NFS Hot Pursuit 2"),
-    (0x45410029, "TY the Tasmanian Tiger"),
-    (0x4541002A, "Freedom Fighters"),
-    (0x4541002C, "Dead To Rights"),
-    (0x4541002E, "Freedom Fighters"),
-    (0x4541002F, "The Sims"),
-    (0x45410030, "NHL 2004"),
-    (0x45410031, "Nascar Thunder 2004"),
-    (0x45410036, "Madden NFL 2004"),
-    (0x45410037, "Harry Potter and the Sorcerers Stone"),
-    (0x4541003B, "Fifa 2004"),
-    (0x4541003C, "ssx3"),
-    (0x4541003D, "Harry Potter Quidditch World Cup"),
-    (0x4541003E, "LordOfRingsTheReturnOfKing"),
-    (0x4541003F, "Medal Of Honor Rising Sun"),
-    (0x45410042, "007 everything or nothing"),
-    (0x45410043, "The Sims Bustin Out"),
-    (0x45410047, "Need For Speed Underground"),
-    (0x45410048, "NFL Street"),
-    (0x45410049, "Def Jam Fight For New York"),
-    (0x4541004A, "Fifa Euro 2004"),
-    (0x4541004B, "FightNight2004"),
-    (0x4541004C, "Harry Potter Prisoner of Azkaban"),
-    (0x4541004E, "FIFA Soccer 2005"),
-    (0x4541004F, "NHL 2005"),
-    (0x45410050, "NbaLive2005"),
-    (0x45410053, "NASCAR 2005 Chase for the Cup"),
-    (0x45410055, "Fight Night Round 2"),
-    (0x45410058, "NBA Street V3"),
-    (0x4541005A, "need for speed underground 2"),
-    (0x4541005B, "Burnout 3 Takedown"),
-    (0x4541005C, "Catwoman"),
-    (0x4541005D, "GoldenEye Rogue Agent"),
-    (0x4541005E, "MOH European Assault"),
-    (0x4541005F, "Der Herr der Ringe  Das dritte Zeitalter"),
-    (0x45410060, "The Urbz Sims In The City"),
-    (0x45410063, "TY the Tasmanian Tiger 2"),
-    (0x45410066, "TimeSplitters3FuturePerfect"),
-    (0x4541006D, "Strangers Wrath"),
-    (0x4541006F, "Need for Speed Underground 2"),
-    (0x45410072, "FIFA Street NTSC"),
-    (0x45410076, "Burnout Revenge"),
-    (0x45410077, "Harry Potter And The Goblet Of Fire"),
-    (0x45410078, "Family Guy"),
-    (0x45410079, "From Russia With Love"),
-    (0x4541007B, "Need For Speed Most Wanted"),
-    (0x4541007D, "The Godfather"),
-    (0x45410080, "Ncaa 06 Football"),
-    (0x45410082, "SSX on Tour"),
-    (0x45410083, "Black"),
-    (0x45410085, "FIFA STREET 2"),
-    (0x45410086, "Fight Night Round 3"),
-    (0x45410087, "EA Sports Rugby 06"),
-    (0x45410091, "Half Life 2"),
-    (0x45410094, "Arena Football"),
-    (0x4541009C, "2006 FIFA World Cup"),
-    (0x454100A0, "superman returns"),
-    (0x454100A3, "NASCAR 07"),
-    (0x454100A4, "Tiger Woods PGA Tour 07"),
-    (0x454100A6, "FIFA 07"),
-    (0x4541023B, "Madden NFL 09"),
-    (0x45410389, "Batman Begins"),
-    (0x4541038A, "Marvel Nemesis  Rise Of The Imperfects"),
-    (0x4541038B, "The Sims 2"),
-    (0x454107D2, "Madden NFL 06"),
-    (0x454107D6, "Tiger Woods PGA TOUR06"),
-    (0x454107D7, "FIFA 06 RTFWC"),
-    (0x454107D8, "NBA LIVE 06"),
-    (0x454107D9, "NFS Most Wanted"),
-    (0x454107DB, "Battlefield 2: MC"),
-    (0x454107DC, "Burnout Revenge"),
-    (0x454107DE, "Fight Night Round 3"),
-    (0x454107DF, "LOTR, BFME II"),
-    (0x454107E6, "2006 FIFA World Cup"),
-    (0x454107E8, "NCAA® Football 07"),
-    (0x454107E9, "The Godfather"),
-    (0x454107EB, "Madden NFL 07"),
-    (0x454107EC, "Need for Speed Carbon"),
-    (0x454107ED, "Superman Returns"),
-    (0x454107EE, "DEF JAM: ICON"),
-    (0x454107EF, "FIFA 07"),
-    (0x454107F1, "NBA LIVE 07"),
-    (0x454107F2, "NCAA March Madness07"),
-    (0x454107F3, "NHL 07"),
-    (0x454107F4, "TigerWoodsPGATOUR07"),
-    (0x454107F6, "MOH Airborne"),
-    (0x454107F7, "NBA STREET Homecourt"),
-    (0x454107F8, "Army of Two"),
-    (0x454107F9, "Battlefield: Bad Co."),
-    (0x454107FA, "Harry Potter HBP"),
-    (0x45410806, "Burnout Paradise"),
-    (0x45410809, "The Simpsons Game"),
-    (0x4541080E, "Command and Conquer 3"),
-    (0x4541080F, "The Orange Box"),
-    (0x45410811, "UEFA CL 2006-2007"),
-    (0x45410813, "skate."),
-    (0x45410814, "NASCAR 08"),
-    (0x45410819, "Harry Potter OOTP"),
-    (0x4541081A, "NCAA Football 08"),
-    (0x4541081D, "Madden NFL 08"),
-    (0x4541081F, "FIFA 08"),
-    (0x45410820, "NHL 08"),
-    (0x45410821, "TigerWoodsPGATOUR 08"),
-    (0x45410822, "NFS ProStreet"),
-    (0x45410828, "Mercenaries 2"),
-    (0x45410829, "Rock Band"),
-    (0x4541082B, "NBA LIVE 08"),
-    (0x45410830, "Left 4 Dead"),
-    (0x45410835, "NFL Tour"),
-    (0x45410837, "NCAA March Madness08"),
-    (0x4541083A, "C&C3: Kane's Wrath"),
-    (0x4541083B, "FIFA Street 3"),
-    (0x4541083F, "UEFA EURO 2008™"),
-    (0x45410841, "NFL Head Coach 09"),
-    (0x45410845, "NASCAR 09"),
-    (0x4541084C, "Army of Two (EU)"),
-    (0x45410850, "Mirror's Edge"),
-    (0x45410852, "LOTR: Conquest"),
-    (0x45410857, "Dead Space"),
-    (0x4541085B, "FIFA 09"),
-    (0x4541085D, "NCAA Football 09"),
-    (0x45410860, "C&C Red Alert 3"),
-    (0x45410861, "FaceBreaker"),
-    (0x45410864, "Madden NFL 09"),
-    (0x45410866, "The Godfather II"),
-    (0x45410868, "BLACK"),
-    (0x45410869, "Rock Band 2"),
-    (0x4541086F, "MONOPOLY"),
-    (0x45410870, "TigerWoodsPGATOUR 09"),
-    (0x45410872, "NHL 09"),
-    (0x45410876, "NFS Undercover"),
-    (0x45410879, "NASCAR 09 EU"),
-    (0x4541087A, "NBA LIVE 09"),
-    (0x4541087F, "Skate 2"),
-    (0x45410881, "Rock Band Song Pack 2"),
-    (0x45410889, "AC/DC LIVE: Rock Band"),
-    (0x4541088A, "NCAA Basketball 09"),
-    (0x4541088F, "The Saboteur"),
-    (0x45410891, "EA SPORTS Grand Slam Tennis 2"),
-    (0x45410894, "Fight Night Round 4"),
-    (0x45410898, "Trivial Pursuit"),
-    (0x4541089B, "G.I. JOE"),
-    (0x454108A4, "NCAA Football 10"),
-    (0x454108A8, "Battlefield: Bad Co. 2"),
-    (0x454108AD, "TigerWoodsPGATOUR 10"),
-    (0x454108B0, "Rock Band Classic Rock"),
-    (0x454108B1, "The Beatles: Rock Band"),
-    (0x454108B4, "Need for Speed SHIFT"),
-    (0x454108BA, "NHL 10"),
-    (0x454108BC, "FIFA 10"),
-    (0x454108C0, "Dragon Age: Origins"),
-    (0x454108C1, "NBA LIVE 10"),
-    (0x454108C3, "NCAA Basketball 10"),
-    (0x454108C5, "Brutal Legend"),
-    (0x454108C6, "Madden NFL 10"),
-    (0x454108CA, "Rock Band Country Pack"),
-    (0x454108CD, "Rock Band Metal Pack"),
-    (0x454108CE, "Mass Effect 2"),
-    (0x454108CF, "Dante's Inferno"),
-    (0x454108D4, "Left 4 Dead 2"),
-    (0x454108D8, "Army of TWO: TFD"),
-    (0x454108D9, "G.I. JOE (PAL)"),
-    (0x454108DF, "Dead Space 2"),
-    (0x454108E2, "The Sims 3"),
-    (0x454108E3, "Crysis 2"),
-    (0x454108E4, "2010 FIFA World Cup"),
-    (0x454108E5, "MySims SkyHeroes"),
-    (0x454108E6, "Skate 3"),
-    (0x454108E7, "Green Day: Rock Band"),
-    (0x454108EE, "TigerWoodsPGATOUR 11"),
-    (0x454108EF, "Bulletstorm"),
-    (0x454108F3, "FIFA Soccer 11"),
-    (0x454108F5, "MONOPOLY Streets"),
-    (0x454108F7, "Medal of Honor"),
-    (0x454108F9, "Harry Potter and the Deathly Hallows - Part 1"),
-    (0x454108FC, "EA SPORTS MMA"),
-    (0x454108FD, "NHL 11"),
-    (0x454108FE, "Madden NFL 11"),
-    (0x45410900, "NCAA Football 11"),
-    (0x45410903, "Need for Speed Hot Pursuit"),
-    (0x4541090B, "Dragon Age 2"),
-    (0x4541090C, "Hasbro Family Game Night 3"),
-    (0x45410911, "EA SPORTS Active 2"),
-    (0x45410912, "Portal 2"),
-    (0x45410914, "Rock Band 3"),
-    (0x45410915, "FIGHT NIGHT CHAMPION"),
-    (0x45410916, "Alice: Madness Returns"),
-    (0x45410923, "Syndicate"),
-    (0x45410929, "Create"),
-    (0x4541092A, "Shadows of the Damned"),
-    (0x4541092C, "Rock Band Country TP 2"),
-    (0x45410934, "SHIFT 2 UNLEASHED"),
-    (0x45410937, "Madden NFL 12"),
-    (0x4541093A, "Rango"),
-    (0x4541093E, "Kingdoms of Amalur: Reckoning"),
-    (0x4541094A, "Need for Speed The Run"),
-    (0x4541094C, "NBA JAM"),
-    (0x4541094D, "Tiger Woods PGA TOUR 12: The Masters"),
-    (0x4541094E, "NCAA Football 12"),
-    (0x45410950, "Battlefield 3"),
-    (0x45410955, "Harry Potter and the Deathly Hallows - Part 2"),
-    (0x4541095D, "Mass Effect 3"),
-    (0x45410961, "Need for Speed Most Wanted"),
-    (0x45410962, "The Sims 3 Pets"),
-    (0x45410964, "NHL12"),
-    (0x45410966, "Family Game Night 4: The Game Show"),
-    (0x45410967, "FIFA 12"),
-    (0x45410968, "Crysis"),
-    (0x4541096D, "SSX"),
-    (0x4541096E, "Fuse"),
-    (0x45410972, "Tiger Woods PGA TOUR 13"),
-    (0x4541097B, "EA SPORTS FIFA Street"),
-    (0x4541097C, "Family Game Night 4: The Game Show"),
-    (0x4541098A, "NCAA Football 13"),
-    (0x4541098B, "Madden NFL 13"),
-    (0x4541098E, "Crysis 3"),
-    (0x45410993, "Medal of Honor Warfighter"),
-    (0x45410997, "Dragon Age™: Inquisition"),
-    (0x45410998, "FIFA Soccer 13"),
-    (0x4541099B, "NHL13"),
-    (0x4541099D, "Dead Space 3"),
-    (0x4541099F, "Zuma's Revenge! Collection"),
-    (0x454109A9, "Tiger Woods PGA TOUR 14"),
-    (0x454109AB, "Army of TWO The Devils Cartel"),
-    (0x454109B6, "NCAA Football 14"),
-    (0x454109BA, "Battlefield 4"),
-    (0x454109BE, "NHL14"),
-    (0x454109C1, "Madden NFL 25"),
-    (0x454109C3, "FIFA 14"),
-    (0x454109C6, "Need for Speed Rivals"),
-    (0x454109C9, "Plants vs Zombies Garden Warfare"),
-    (0x454109D0, "Titanfall"),
-    (0x454109D4, "EA SPORTS 2014 FIFA World Cup Brazil"),
-    (0x454109D9, "Madden NFL 15"),
-    (0x454109DB, "FIFA 15"),
-    (0x454109DC, "NHL15"),
-    (0x454109DE, "FIFA 15"),
-    (0x454109E0, "Battlefield™ Hardline"),
-    (0x454109EB, "Madden NFL 16"),
-    (0x454109EC, "EA SPORTS™ NHL® Legacy Edition"),
-    (0x454109EE, "EA SPORTS™ FIFA 16"),
-    (0x454109F2, "Madden NFL 17"),
-    (0x454109F4, "EA SPORTS™ FIFA 17"),
-    (0x454109F8, "FIFA 18"),
-    (0x454109F9, "EA SPORTS FIFA 19 Legacy Edition"),
-    (0x45430002, "grooverider slot car thunder"),
-    (0x454C0001, "Scrapland"),
-    (0x454D0002, "Big Mutha Truckers"),
-    (0x454D0005, "Starsky and Hutch"),
-    (0x454D0008, "Bad Boys 2"),
-    (0x454D0009, "FlatOut"),
-    (0x454D000A, "Mashed Drive to Survive"),
-    (0x454D000D, "Ford Racing 3"),
-    (0x454D0017, "Big Mutha Truckers 2"),
-    (0x454D001B, "Mashed Fully Loaded"),
-    (0x454D001C, "Taito Legends"),
-    (0x454D001D, "Ultimate Pro Pinball"),
-    (0x454D001E, "Jacked"),
-    (0x454D07D2, "FlatOut UC"),
-    (0x45530001, "Mad Dash Racing"),
-    (0x45530004, "Thief Deadly Shadows"),
-    (0x45530005, "Deus Ex Invisible War"),
-    (0x45530006, "Blood Omen 2"),
-    (0x45530008, "Commandos 2"),
-    (0x45530009, "Hitman2 Silent Assassin"),
-    (0x4553000A, "TimeSplitters 2"),
-    (0x4553000D, "Backyard Wrestling Dont Try This At Home"),
-    (0x4553000F, "Legacy of Kain Defiance  Cover 2"),
-    (0x45530012, "Hitman Contracts"),
-    (0x45530013, "Shellshock NAM67"),
-    (0x45530018, "25 To Life"),
-    (0x45530019, "Project Snowblind"),
-    (0x4553001D, "Lego Star Wars The Video Game"),
-    (0x455607D1, "Brave- Warrior's Tale"),
-    (0x455607D3, "Raven Squad"),
-    (0x455607D5, "Terminator Salvation"),
-    (0x455607D6, "JASF"),
-    (0x455607D7, "Venetica"),
-    (0x455607D8, "Mayhem"),
-    (0x455607DB, "Test Drive: Ferrari"),
-    (0x464507D1, "Blood Bowl"),
-    (0x464507D3, "Sherlock Holmes"),
-    (0x464507D5, "The Testament of Sherlock Holmes"),
-    (0x464507D6, "Divinity II - DKS"),
-    (0x464507D8, "Tour de France"),
-    (0x464507D9, "Of Orcs and Men"),
-    (0x464507DA, "Tour de France 2012"),
-    (0x464507DB, "Game of Thrones"),
-    (0x464507DC, "Farming Simulator"),
-    (0x464507DD, "Bound by Flame"),
-    (0x464507DE, "Tour de France 2013"),
-    (0x464507DF, "Sherlock Holmes"),
-    (0x464507E0, "Tour de France 2014"),
-    (0x464507E1, "Farming Simulator 15"),
-    (0x46490002, "Futurama"),
-    (0x464A07D1, "The Escapists"),
-    (0x464F07D1, "IL-2: Birds of Prey"),
-    (0x464F07D7, "MorphX"),
-    (0x464F07D8, "Naughty Bear"),
-    (0x464F07D9, "Naval Assault"),
-    (0x464F07DA, "BACKBREAKER"),
-    (0x464F07DB, "Field & Stream T.O.C."),
-    (0x464F07E1, "Michael Phelps: Push the Limit"),
-    (0x464F07E4, "Supremacy MMA"),
-    (0x464F07E7, "Grease Dance"),
-    (0x464F07E8, "Sniper Elite V2"),
-    (0x464F07E9, "Brunswick Pro Bowling"),
-    (0x464F07EB, "Greg Hastings Paintball 2"),
-    (0x464F07EC, "Top Gun: Hard Lock"),
-    (0x464F07ED, "Rugby World Cup 2011"),
-    (0x464F07F2, "Blackwater Kinect"),
-    (0x464F07F6, "Jillian Michael's Fitness Adventure"),
-    (0x464F07FE, "miCoach"),
-    (0x464F0800, "PAYDAY 2"),
-    (0x464F0802, "Terraria – Xbox 360 Edition"),
-    (0x464F0803, "Sniper Elite 3"),
-    (0x46530002, "OTOGI"),
-    (0x46530004, "Otogi 2 Immortal Warriors"),
-    (0x46530006, "Metal Wolf Chaos"),
-    (0x465307D2, "Enchanted Arms"),
-    (0x465307D3, "Enchanted Arms"),
-    (0x465307D6, "ARMORED CORE4"),
-    (0x465307D7, "Tenchu Z"),
-    (0x465307D9, "NINJA BLADE"),
-    (0x465307DA, "AC for Answer"),
-    (0x465307E4, "DARK SOULS II"),
-    (0x465607D1, "ケツイ ～絆地獄たち～ EXTRA"),
-    (0x465607D2, "怒首領蜂大往生ブラックレーベル EXTRA"),
-    (0x465607D3, "CHAOS;HEAD NOAH"),
-    (0x465607D8, "STEINS;GATE(オリジナル版)"),
-    (0x465607DE, "Phantom Breaker"),
-    (0x465607DF, "Bullet Soul"),
-    (0x465607F1, "PHANTOM BREAKER:EXTRA"),
-    (0x465607F6, "bullet soul infinite burst"),
-    (0x474507D1, "首都高バトルＸ"),
-    (0x474507D2, "Kengo Legend of the 9"),
-    (0x474907D1, "The Strike"),
-    (0x474907D2, "BPS®: The Hunt"),
-    (0x475007D2, "Hail to the Chimp"),
-    (0x475007D3, "Legendary"),
-    (0x475007D4, "Section 8"),
-    (0x475007D5, "Velvet Assassin"),
-    (0x475207D1, "WAR TECH:SenkoNoRonde"),
-    (0x475207F6, "Mamoru-Kun (まもるクンは呪われてしまった！)"),
-    (0x475207F9, "旋光の輪舞 DUO"),
-    (0x475207FC, "Under Defeat HD"),
-    (0x47560001, "Pariah"),
-    (0x47560004, "Land Of The Dead NTSC"),
-    (0x47560009, "world war 2"),
-    (0x475707D1, "Alaskan Storm"),
-    (0x475807D1, "Lucha Libre AAA"),
-    (0x475807D7, "El Chavo Kart"),
-    (0x48410057, "NFL Street 2"),
-    (0x48450005, "Rugby League 2"),
-    (0x484507D1, "Rugby League Live"),
-    (0x484507D2, "AFL Live"),
-    (0x484507D4, "Jonah Lomu Rugby Challenge"),
-    (0x484507D5, "Rugby League Live 2"),
-    (0x484507D6, "AFL Live 2"),
-    (0x484507D7, "Rugby Challenge 2 - The Lions Tour Edition"),
-    (0x484507D8, "Don Bradman Cricket 14"),
-    (0x484507D9, "Rugby League Live 2 - World Cup Edition"),
-    (0x484507DA, "Rugby League Live 3"),
-    (0x484507DB, "Rugby Challenge 3"),
-    (0x484E0027, "Winning eleven 8"),
-    (0x48500002, "Counter Terror Special Forces"),
-    (0x48550001, "Bloody Roar Extreme"),
-    (0x485507D1, "BOMBERMAN Act:Zero"),
-    (0x485507D4, "Fuzion Frenzy 2"),
-    (0x485507DF, "DECA SPORTS FREEDOM"),
-    (0x494607D6, "Agarest Senki - Rapier Lance"),
-    (0x494607D7, "Cross Edge Dash (クロスエッジ ダッシュ)"),
-    (0x49470001, "Test Drive Off Road"),
-    (0x49470006, "Loons The Fight For Fame"),
-    (0x49470007, "Test Drive"),
-    (0x4947000B, "Furious Carting"),
-    (0x4947000D, "Taz Wanted"),
-    (0x4947000E, "Magic The Gathering Battlegrounds"),
-    (0x4947000F, "Monopoly Party"),
-    (0x49470012, "zapper"),
-    (0x49470013, "Dungeons and Dragons Heroes"),
-    (0x49470015, "Racing Evoluzione (Apex)"),
-    (0x49470018, "Jet Set Radio Future"),
-    (0x4947001C, "Micro Machines"),
-    (0x4947001F, "MI Operation Surma"),
-    (0x49470022, "Enter The Matrix"),
-    (0x49470023, "Crazy Taxi 3"),
-    (0x49470024, "Unreal Championship"),
-    (0x49470025, "Shadow Ops Red Mercury"),
-    (0x49470026, "The Terminator Dawn of Fate"),
-    (0x49470028, "Nickelodeon Party Blast"),
-    (0x4947002B, "Panzer Dragoon ORTA"),
-    (0x4947002C, "segaGT 2002"),
-    (0x4947002E, "RollercoasterTycoon"),
-    (0x49470034, "VRally 3"),
-    (0x49470038, "DRIV3R"),
-    (0x49470039, "Godzilla Melee"),
-    (0x4947003C, "Unreal 2"),
-    (0x4947003E, "Terminator 3 The Redemption"),
-    (0x49470073, "Godzilla Save the Earth"),
-    (0x49470074, "DemonStone"),
-    (0x49470075, "Test Drive Eve Of Destruction"),
-    (0x49470079, "Atari Anthology"),
-    (0x4947007A, "DragonBallZSagas"),
-    (0x4947007B, "Fahrenheit"),
-    (0x4947007C, "The Matrix PON"),
-    (0x4947007D, "Getting Up Contents Under Pressure"),
-    (0x4947007F, "Driver Parallel Lines"),
-    (0x494707D1, "Alone In The Dark"),
-    (0x494707D4, "Test Drive Unlimited"),
-    (0x494707DB, "BulletWitch"),
-    (0x494707DF, "Indigo Prophecy"),
-    (0x494707E0, "Fahrenheit"),
-    (0x494707E1, "Asterix Olympic Games"),
-    (0x494707E2, "My Horse And Me 2"),
-    (0x494707E4, "Race Pro"),
-    (0x494707EE, "Assault on Dark Athena"),
-    (0x494707F4, "Ghostbusters"),
-    (0x49470803, "Backyard Football '10"),
-    (0x49470804, "Test Drive Unlimited 2"),
-    (0x49470806, "Sandlot Sluggers"),
-    (0x4947080B, "Backyard Sports: Rookie Rush"),
-    (0x494787FE, "Qubed"),
-    (0x494F07D1, "El Shaddai"),
-    (0x494F07D4, "DEADLY PREMONITION"),
-    (0x494F07E8, "National Geographic Challenge!"),
-    (0x49500006, "Run Like Hell"),
-    (0x49500008, "Fallout Brotherhood of Steel"),
-    (0x4950000A, "Baldurs Gate Dark Alliance 2"),
-    (0x49580001, "a bards tale"),
-    (0x4A3007D1, "PES 2017"),
-    (0x4A3007D3, "PRO EVOLUTION SOCCER 2018"),
-    (0x4A410002, "NightCaster2"),
-    (0x4A570002, "Neighbours from hell"),
-    (0x4A570003, "Kao The Kangaroo Round 2"),
-    (0x4A570005, "Ski Racing 2005"),
-    (0x4A57000A, "Ski Racing 2006"),
-    (0x4A5707D2, "ArcaniA"),
-    (0x4A5707D3, "Sherlock Holmes"),
-    (0x4B420004, "Rogue Ops"),
-    (0x4B4B0002, "Triangle Again 2"),
-    (0x4B4D07D1, "Sacred 2 Fallen Angel"),
-    (0x4B4D07D8, "Risen"),
-    (0x4B4D07DE, "Prison Break"),
-    (0x4B4D07DF, "Dead Island"),
-    (0x4B4D07E0, "nail'd"),
-    (0x4B4D07E2, "The X Factor"),
-    (0x4B4D07E5, "Winter Stars"),
-    (0x4B4D07E6, "Risen 2: Dark Waters"),
-    (0x4B4D07E7, "Sacred 3"),
-    (0x4B4D07E9, "Ride to Hell: Retribution"),
-    (0x4B4D07EA, "Catherine"),
-    (0x4B4D07EB, "Summer Stars 2012"),
-    (0x4B4D07F2, "Dead Island Riptide"),
-    (0x4B4D07F5, "Metro: Last Light"),
-    (0x4B4D07F6, "Saints Row IV"),
-    (0x4B4D07FB, "NASCAR '14"),
-    (0x4B4D07FD, "Risen 3 Titan Lords"),
-    (0x4B4D07FE, "Escape Dead Island"),
-    (0x4B4D0800, "Saints Row: Gat Out of Hell   "),
-    (0x4B4E0002, "Airforce Delta Storm"),
-    (0x4B4E0004, "Silent Hill 2"),
-    (0x4B4E0007, "Metal Gear Solid 2 Substance"),
-    (0x4B4E000E, "Shadow of Memories"),
-    (0x4B4E0013, "Frogger Beyond"),
-    (0x4B4E001B, "TMNT"),
-    (0x4B4E001E, "YuGiOh The Dawn Of Destiny"),
-    (0x4B4E001F, "Silent Hill 4 The Room"),
-    (0x4B4E0020, "TMNT Mutant Melee"),
-    (0x4B4E0023, "Teenage Mutant Ninja Turtles 2 Battlenexus"),
-    (0x4B4E002A, "TMNT 3"),
-    (0x4B4E002D, "Castlevania: Curse Of Darkness"),
-    (0x4B4E002F, "World Soccer Winning Eleven 9"),
-    (0x4B4E07D1, "Rumble Roses XX"),
-    (0x4B4E07D3, "Hellboy: TSoE"),
-    (0x4B4E07D4, "プロ野球スピリッツ３"),
-    (0x4B4E07DA, "Pro Evolution Soccer 6"),
-    (0x4B4E07DC, "DDR/DS Universe"),
-    (0x4B4E07DE, "Winning Eleven X"),
-    (0x4B4E07DF, "Winning Eleven 2007"),
-    (0x4B4E07E0, "Rock Revolution"),
-    (0x4B4E07E2, "KR: AI Encore"),
-    (0x4B4E07E5, "Silent Hill Homecoming"),
-    (0x4B4E07E6, "PES2008"),
-    (0x4B4E07E7, "DDR/DS Universe 2"),
-    (0x4B4E07E9, "PES2008 (US)"),
-    (0x4B4E07EE, "Otomedius Gorgeous! (オトメディウスG)"),
-    (0x4B4E07EF, "DDR Universe 3"),
-    (0x4B4E07F0, "PES 2009"),
-    (0x4B4E07F3, "KR: AI Encore 2"),
-    (0x4B4E07F6, "Rock Revolution (EU)"),
-    (0x4B4E07FA, "PES 2009"),
-    (0x4B4E0801, "PES 2010"),
-    (0x4B4E0802, "Saw"),
-    (0x4B4E0803, "Karaoke Revolution"),
-    (0x4B4E0805, "PES 2010"),
-    (0x4B4E0806, "Tornado Outbreak"),
-    (0x4B4E0807, "DanceDanceRevolution"),
-    (0x4B4E0809, "Castlevania: Lords of Shadow"),
-    (0x4B4E080A, "METAL GEAR RISING: REVENGEANCE"),
-    (0x4B4E080D, "Winning Eleven 2010"),
-    (0x4B4E0810, "Ninety-Nine Nights II"),
-    (0x4B4E0816, "Ninety-Nine Nights II"),
-    (0x4B4E0819, "ADRENALIN MISFITS"),
-    (0x4B4E081A, "OTOMEDIUS EXCELLENT"),
-    (0x4B4E081D, "PES 2011"),
-    (0x4B4E081E, "DanceMasters"),
-    (0x4B4E081F, "NeverDead"),
-    (0x4B4E0820, "DEF JAM RAPSTAR"),
-    (0x4B4E0822, "SAW II"),
-    (0x4B4E0823, "Silent Hill:  Downpour"),
-    (0x4B4E0826, "ぬらりひょんの孫　百鬼繚乱大戦"),
-    (0x4B4E082C, "CROSSBOARD 7"),
-    (0x4B4E0837, "PES 2012"),
-    (0x4B4E0838, "Blades of Time"),
-    (0x4B4E0839, "Birds of Steel"),
-    (0x4B4E083A, "Otomedius Excellent"),
-    (0x4B4E083C, "MGS PW HD"),
-    (0x4B4E083F, "Karaoke Revolution Glee:  Volume 3"),
-    (0x4B4E0840, "MGS 2 AND 3"),
-    (0x4B4E0841, "Jimmie Johnson's Anything With An Engine"),
-    (0x4B4E0842, "ZOE HD"),
-    (0x4B4E0845, "Silent Hill: HD Collection"),
-    (0x4B4E084C, "PES 2013"),
-    (0x4B4E084D, "Castlevania: Lords of Shadow 2"),
-    (0x4B4E0856, "PES 2014"),
-    (0x4B4E085C, "METAL GEAR SOLID V: GROUND ZEROES"),
-    (0x4B4E085E, "METAL GEAR SOLID V: THE PHANTOM PAIN"),
-    (0x4B4E085F, "PES 2015"),
-    (0x4B4E0861, "PES 2016"),
-    (0x4B4F0002, "Crimson Sea"),
-    (0x4B4F0004, "Dynasty Warriors 4"),
-    (0x4B4F0006, "Samurai Warriors"),
-    (0x4B4F0007, "Dynasty Warriors 5"),
-    (0x4B4F0008, "Winback 2"),
-    (0x4B4F07D1, "真・三國無双４ Special"),
-    (0x4B4F07D3, "DW5 Empires"),
-    (0x4B4F07D5, "SAMURAI WARRIORS 2"),
-    (0x4B4F07D6, "Samurai Warriors 2 Empires"),
-    (0x4B4F07D7, "Fatal Inertia"),
-    (0x4B4F07D8, "BLADESTORM"),
-    (0x4B4F07D9, "WARRIORS OROCHI"),
-    (0x4B4F07E0, "DW6"),
-    (0x4B4F07E1, "DYNASTY WARRIORS 6"),
-    (0x4B4F07E3, "WARRIORS OROCHI 2"),
-    (0x4B4F07E7, "DW6 Empires"),
-    (0x4B4F07F0, "Warriors: Legends of Troy"),
-    (0x4B4F07F1, "DW Strikeforce(Tc)"),
-    (0x4B4F07FA, "DW: Strikeforce"),
-    (0x4B4F07FE, "Fist of the North Star"),
-    (0x4B4F07FF, "Fist of the North Star: Ken's rage 2"),
-    (0x4B5107D3, "Radirgy Noa Massive"),
-    (0x4B53000F, "RallisportChallenge 1 xbox 1"),
-    (0x4B550001, "Innocent Tears"),
-    (0x4B5607D2, "Dynasty Warriors 7"),
-    (0x4B5607D4, "NINJA GAIDEN 3"),
-    (0x4B5607D5, "Champion Jockey G1 & GR"),
-    (0x4B5607D9, "WARRIORS OROCHI 3"),
-    (0x4B5607DA, "Dead or Alive 5"),
-    (0x4B5607DF, "WARRIORS OROCHI 3"),
-    (0x4B5607E2, "Yaiba: Ninja Gaiden Z"),
-    (0x4B5607E3, "Fist of the North Star: Ken's rage 2"),
-    (0x4B5607E4, "NINJA GAIDEN 3: RE"),
-    (0x4B5607E5, "Dynasty Warriors 8"),
-    (0x4B5607E6, "Dynasty Warriors 8"),
-    (0x4B5607E8, "DOA5 Ultimate"),
-    (0x4B5907D6, "Tropico 3"),
-    (0x4B5907D8, "The First Templar"),
-    (0x4B5907DA, "DarkStar One"),
-    (0x4B5907DC, "Tropico 4"),
-    (0x4B5907DF, "Port Royale 3"),
-    (0x4B5907E0, "Air Conflicts: Secret Wars"),
-    (0x4B5907E5, "Omerta - City of Gangsters"),
-    (0x4B5907E6, "DARK"),
-    (0x4B5907EA, "Tropico 5"),
-    (0x4C410001, "Star Wars Obi Wan"),
-    (0x4C410002, "Starfighter SE"),
-    (0x4C410003, "Star Wars Knights of The Old Republic"),
-    (0x4C410004, "Star Wars The Clone Wars"),
-    (0x4C410005, "Star Wars Jedi Starfighter"),
-    (0x4C410006, "Secret Weapons Over Normandy"),
-    (0x4C410007, "Indiana Jones"),
-    (0x4C410008, "Gladius"),
-    (0x4C410009, "StarWarsJediKnight2JediOutcast"),
-    (0x4C41000B, "Jedi Knight Jedi Academy"),
-    (0x4C41000D, "Armed and Dangerous"),
-    (0x4C410011, "Star Wars Battlefront"),
-    (0x4C410013, "Star Wars Republic Commando"),
-    (0x4C410014, "Star Wars KotOR II"),
-    (0x4C410015, "Mercenaries"),
-    (0x4C410017, "Star Wars Episode 3"),
-    (0x4C41001A, "Star Wars Battlefront 2"),
-    (0x4C41001F, "LEGO Star Wars II"),
-    (0x4C410022, "Thrillville"),
-    (0x4C4107D1, "LEGO Star Wars II"),
-    (0x4C4107D2, "Star Wars: The Force Unleashed"),
-    (0x4C4107D4, "Fracture"),
-    (0x4C4107D6, "Thrillville: OTR"),
-    (0x4C4107D7, "LEGO Star Wars: TCS"),
-    (0x4C4107DF, "LEGO Indiana Jones"),
-    (0x4C4107E2, "SWTCW: Republic Heroes"),
-    (0x4C4107ED, "LEGO Indiana Jones 2"),
-    (0x4C4107F2, "Star Wars: The Force Unleashed II"),
-    (0x4C4107F3, "LEGO Star Wars III"),
-    (0x4C5307D1, "SBK®08"),
-    (0x4C5307D3, "Superstars V8 Racing"),
-    (0x4C5307D4, "SBK09"),
-    (0x4C5307D7, "SBKX"),
-    (0x4C5307D8, "Superstars V8 NC"),
-    (0x4C5307DB, "NGQ! Wild Life"),
-    (0x4C5307DD, "WRC 2010"),
-    (0x4C5307DE, "Get Fit With Mel B"),
-    (0x4C5307E1, "Nat Geo Challenge!"),
-    (0x4C5307E5, "SBK"),
-    (0x4C5307E7, "Let's Dance with Mel B"),
-    (0x4C5307EB, "WRC 2"),
-    (0x4C5307EC, "MUD"),
-    (0x4C5307ED, "Kung-Fu"),
-    (0x4C5307F1, "SBK 2012"),
-    (0x4C5307F3, "WRC 3"),
-    (0x4D4607D3, "BandFuse: Rock Legends"),
-    (0x4D4607D5, "Heavy Fire: Shattered Spear"),
-    (0x4D490006, "Syberia"),
-    (0x4D490008, "Syberia 2"),
-    (0x4D490009, "Obscure"),
-    (0x4D49000B, "Still Life"),
-    (0x4D49000D, "Sniper Elite"),
-    (0x4D4A0001, "BloodRayne"),
-    (0x4D4A0008, "Blowout"),
-    (0x4D4A0009, "advent rising"),
-    (0x4D4A000B, "Drake of the 99 Dragons"),
-    (0x4D4A000C, "Ultra Bust A Move"),
-    (0x4D4A000D, "BloodRayne 2"),
-    (0x4D4A000F, "JawsUnleashed"),
-    (0x4D4A0012, "psychonauts"),
-    (0x4D4A0014, "Aeon Flux"),
-    (0x4D4A07D2, "Psychonauts"),
-    (0x4D4A07D3, "Razes Hell"),
-    (0x4D4A07D4, "BlowOut"),
-    (0x4D4A07D5, "Night at the Museum 2"),
-    (0x4D4A07D8, "GHP2"),
-    (0x4D4A07D9, "Attack Of The Movies 3D"),
-    (0x4D4A07DA, "Zumba Fitness"),
-    (0x4D4A07DD, "Hulk Hogan's Main Event"),
-    (0x4D4A07E2, "Twister Mania"),
-    (0x4D4A07E3, "Harley Pasternak's Hollywood Workout"),
-    (0x4D4A07E4, "Alvin and The Chipmunks: Chipwrecked"),
-    (0x4D4A07E5, "Jillian Michaels Fitness Adventure"),
-    (0x4D4A07E6, "Motion Explosion"),
-    (0x4D4A07E7, "Zumba Fitness: Rush"),
-    (0x4D4A07E8, "NBA Baller Beats"),
-    (0x4D4A07F0, "Zumba Fitness Core"),
-    (0x4D4A07F4, "Zumba Fitness World Party"),
-    (0x4D4A07F5, "Phineas and Ferb"),
-    (0x4D4A07F6, "Zumba Kids"),
-    (0x4D4B07D0, "PopCap Hits"),
-    (0x4D4B07D2, "Alien Breed Trilogy"),
-    (0x4D4B07D3, "PopCap Hits Vol. 2"),
-    (0x4D4B07D4, "Serious Sam HD: The First and Second Encounters"),
-    (0x4D4B07D6, "Carrier Command"),
-    (0x4D4B07D7, "Worms Collection"),
-    (0x4D4B07D9, "Worms The Revolution Collection"),
-    (0x4D4B07DA, "The Serious Sam Collection"),
-    (0x4D4F0002, "Crimson Sea"),
-    (0x4D4F07D1, "Raiden IV"),
-    (0x4D5107D1, "Dance Paradise"),
-    (0x4D530001, "Oddworld Munchs Oddysee"),
-    (0x4D530002, "Fuzion Frenzy"),
-    (0x4D530003, "ProjectGothamRacing"),
-    (0x4D530004, "Halo"),
-    (0x4D530005, "Amped Freestyle Snowboarding Mini"),
-    (0x4D530008, "Nightcaster"),
-    (0x4D53000A, "Sudeki"),
-    (0x4D53000C, "Kakuto Chojin Back Alley Brutal"),
-    (0x4D53000D, "Fable"),
-    (0x4D53000F, "Rallisport Challenge"),
-    (0x4D530010, "Blood Wake"),
-    (0x4D530013, "BLiNX The Time Sweeper"),
-    (0x4D530014, "Tao Feng Fist of the Lotus"),
-    (0x4D530015, "Quantum Redshift"),
-    (0x4D530017, "MechAssault"),
-    (0x4D53001E, "Brute Force"),
-    (0x4D530021, "Crimson Skies High Road to Revenge"),
-    (0x4D530027, "Whacked"),
-    (0x4D53002A, "Midtown Madness 3"),
-    (0x4D53002D, "DEAD OR ALIVE 3"),
-    (0x4D530031, "Kung Fu Chaos"),
-    (0x4D530032, "Shenmue II"),
-    (0x4D530035, "Top Spin"),
-    (0x4D530036, "Counter Strike"),
-    (0x4D530039, "RalliSport Challenge 2"),
-    (0x4D53003D, "Sega GT 2002  Jet Set Radio Future"),
-    (0x4D530041, "Amped 2"),
-    (0x4D530046, "PhantomDust"),
-    (0x4D53004A, "Phantasy Star Online"),
-    (0x4D53004B, "Project Gotham Racing 2"),
-    (0x4D530051, "Conker Live and Reload"),
-    (0x4D530053, "Grabbed by the Ghoulies"),
-    (0x4D530057, "Halo Combat Evolved"),
-    (0x4D530059, "Fatal Frame"),
-    (0x4D53005D, "Links 2004"),
-    (0x4D53005F, "Voodoo Vince"),
-    (0x4D530064, "Halo 2"),
-    (0x4D530065, "BLiNX 2"),
-    (0x4D53006B, "MechAssault 2"),
-    (0x4D53006D, "jade empire"),
-    (0x4D53006E, "forza motorsport"),
-    (0x4D530082, "Halo 2 Beta"),
-    (0x4D530085, "The Urbz Sims In The City"),
-    (0x4D5300D1, "Fable The Lost Chapters"),
-    (0x4D5307D1, "PGR 3"),
-    (0x4D5307D2, "Kameo"),
-    (0x4D5307D3, "Perfect Dark Zero"),
-    (0x4D5307D4, "エブリパーティ"),
-    (0x4D5307D5, "Gears of War"),
-    (0x4D5307D6, "Shadowrun"),
-    (0x4D5307DB, "Ninety-Nine Nights"),
-    (0x4D5307DC, "Crackdown"),
-    (0x4D5307DE, "Too Human"),
-    (0x4D5307DF, "Blue Dragon"),
-    (0x4D5307E6, "Halo 3"),
-    (0x4D5307E8, "Mass Effect"),
-    (0x4D5307EA, "Forza Motorsport 2"),
-    (0x4D5307ED, "Banjo Kazooie: N n B"),
-    (0x4D5307F0, "Everyparty"),
-    (0x4D5307F1, "Fable II"),
-    (0x4D5307F2, "Viva Piñata"),
-    (0x4D5307F9, "PGR 4"),
-    (0x4D5307FA, "Lost Odyssey"),
-    (0x4D530805, "Alan Wake"),
-    (0x4D530808, "Halo Wars"),
-    (0x4D530819, "Party Animals"),
-    (0x4D53082D, "Gears of War 2"),
-    (0x4D530832, "Scene It? LCA"),
-    (0x4D53084D, "Forza Motorsport 3"),
-    (0x4D530851, "Crimson Skies High Road to Revenge"),
-    (0x4D530852, "Jade Empire"),
-    (0x4D530854, "Fable"),
-    (0x4D530856, "Fuzion Frenzy"),
-    (0x4D53085B, "Halo: Reach"),
-    (0x4D53085F, "Viva Piñata: TIP"),
-    (0x4D530860, "Scene It? BOS!"),
-    (0x4D530877, "Halo 3: ODST Campaign Edition"),
-    (0x4D53087B, "Gears of War ( Bonus Disc)"),
-    (0x4D530888, "Lips"),
-    (0x4D5308AB, "Gears of War 3"),
-    (0x4D5308AF, "Lips™: Number One Hits"),
-    (0x4D5308B3, "Kinectimals"),
-    (0x4D5308BC, "Crackdown 2"),
-    (0x4D5308BE, "Grabbed by the Ghoulies"),
-    (0x4D5308C8, "Gears of War 2"),
-    (0x4D5308C9, "Kinect Sports"),
-    (0x4D5308D6, "Fable III"),
-    (0x4D5308ED, "Kinect Adventures!"),
-    (0x4D530901, "Game Room"),
-    (0x4D53090E, "Kinect Star Wars"),
-    (0x4D53090F, "Nike+ Kinect Training"),
-    (0x4D530910, "Forza Motorsport 4"),
-    (0x4D530919, "Halo 4"),
-    (0x4D53093B, "Kinect Joy Ride"),
-    (0x4D5309B1, "Halo: Combat Evolved Anniversary"),
-    (0x4D5309B6, "KINECT RUSH: A Disney • PIXAR Adventure"),
-    (0x4D5309C1, "Disneyland Adventures"),
-    (0x4D5309C5, "Triple Pack"),
-    (0x4D5309C9, "Forza Horizon"),
-    (0x4D5309CD, "Kinect Nat Geo TV DVD"),
-    (0x4D5309D6, "Kinect Sports: Season Two"),
-    (0x4D5309DA, "Kinect Sesame Street TV"),
-    (0x4D5309DF, "The Gunstringer"),
-    (0x4D530A0C, "Game Demos"),
-    (0x4D530A1A, "Fable: The Journey"),
-    (0x4D530A26, "Gears of War: Judgment"),
-    (0x4D530A55, "KS Ultimate "),
-    (0x4D530A81, "Minecraft: Xbox 360 Edition"),
-    (0x4D530A87, "Fable Anniversary"),
-    (0x4D530A8C, "World of Tanks: Xbox 360 Edition"),
-    (0x4D530A95, "Zoo Tycoon"),
-    (0x4D530AA4, "Forza Horizon 2"),
-    (0x4D530AA5, "Warface"),
-    (0x4D530AA6, "ScreamRide"),
-    (0x4D530AB5, "Forza Horizon 2 Presents Fast & Furious"),
-    (0x4D5380CD, "Halo 2 Multiplayer Map Pack"),
-    (0x4D538801, "Big Bumpin"),
-    (0x4D538802, "Sneak King"),
-    (0x4D538803, "Pocketbike Racer"),
-    (0x4D53880C, "Halo 3 Beta"),
-    (0x4D53885C, "Halo Reach Beta"),
-    (0x4D5388BB, "Mass Effect Bonus Disc"),
-    (0x4D5388DE, "Alan Wake"),
-    (0x4D5607D1, "Deadly Premonition"),
-    (0x4D5607D2, "NO MORE HEROES 英雄たちの楽園"),
-    (0x4D570002, "Arctic Thunder"),
-    (0x4D570005, "Area51"),
-    (0x4D570006, "Defender"),
-    (0x4D57000A, "nfl blitz 2003"),
-    (0x4D57000B, "NHL Hitz 2003"),
-    (0x4D57000C, "Mortal Kombat Deadly Aliancee"),
-    (0x4D57000E, "Gauntlet Dark Legacy"),
-    (0x4D57000F, "Red card soccer"),
-    (0x4D570015, "The Suffering"),
-    (0x4D570016, "PsiOps The Mindgate Conspiracy"),
-    (0x4D570018, "RoadKill"),
-    (0x4D570019, "Mortal Kombat Deception"),
-    (0x4D57001A, "NHL Hitz Pro"),
-    (0x4D57001B, "spy hunter 2"),
-    (0x4D57001C, "Midway Arcade Treasures"),
-    (0x4D57001D, "MLB Slugfest Loaded"),
-    (0x4D570020, "Midway Arcade Treasures 2"),
-    (0x4D570021, "Unreal Championship 2"),
-    (0x4D570022, "Gauntlet Seven Sorrows"),
-    (0x4D570023, " MORTAL KOMBAT:DECEPTION"),
-    (0x4D570025, "LA Rush"),
-    (0x4D570029, "MK Shaolin Monks"),
-    (0x4D57002C, "The Suffering Ties That Bind"),
-    (0x4D570033, "Gauntlet Seven Sorrows"),
-    (0x4D570034, "Mortal Kombat Armageddon"),
-    (0x4D570037, "Spy Hunter: Nowhere To Run"),
-    (0x4D5707D2, "Stranglehold"),
-    (0x4D5707D4, "Blitz: The League"),
-    (0x4D5707D6, "Wheelman"),
-    (0x4D5707D7, "Blacksite"),
-    (0x4D5707D9, "Hour of Victory"),
-    (0x4D5707DA, "NBA Ballers:Chosen One"),
-    (0x4D5707DB, "Unreal Tournament 3"),
-    (0x4D5707DF, "TNA iMPACT!"),
-    (0x4D5707E8, "Blitz: The League II"),
-    (0x4D5707E9, "Mortal Kombat vs. DCU"),
-    (0x4D580001, "Dreamfall The Longest Journey"),
-    (0x4D5907D3, "Damage Inc. - Pacific Squadron WWII"),
-    (0x4E4B0001, "Tom and Jerry war of the whiskers"),
-    (0x4E4D0001, "Smashing Drive"),
-    (0x4E4D0003, "soul calibur II"),
-    (0x4E4D0005, "Dead To Rights"),
-    (0x4E4D0009, "breakdown"),
-    (0x4E4D000B, "Kill Switch"),
-    (0x4E4D000C, "Spawn: Armageddon"),
-    (0x4E4D000F, "INinja"),
-    (0x4E4D0013, "Street Racing Syndicate"),
-    (0x4E4D0015, "Dead To Rights 2"),
-    (0x4E4D0016, "Namco Museum 50th Anniversary"),
-    (0x4E4D0017, "Pac Man World 3"),
-    (0x4E4D0019, "Curious George"),
-    (0x4E4D07D1, "ACE COMBAT 6"),
-    (0x4E4D07D3, "Ridge Racer 6"),
-    (0x4E4D07D5, "Eternal Sonata (トラスティベル)"),
-    (0x4E4D07D8, "AFRO SAMURAI"),
-    (0x4E4D07DB, "THE IDOLM@STER"),
-    (0x4E4D07DC, "Beautiful Katamari"),
-    (0x4E4D07DF, "Warhammer:BattleMarch"),
-    (0x4E4D07E0, "Soulcalibur IV"),
-    (0x4E4D07E2, "Eternal Sonata"),
-    (0x4E4D07E4, "Eternal Sonata"),
-    (0x4E4D07E5, "DW:GUNDAM"),
-    (0x4E4D07E9, "Tales of Vesperia"),
-    (0x4E4D07EA, "アイドルマスター ライブフォーユー！"),
-    (0x4E4D07ED, "SMASH COURT TENNIS 3"),
-    (0x4E4D07F0, "SPLATTERHOUSE"),
-    (0x4E4D07F1, "ENSLAVED"),
-    (0x4E4D07F5, "DTR: Retribution"),
-    (0x4E4D07FB, "Gundam Musou 2 (ガンダム無双２)"),
-    (0x4E4D07FC, "TEKKEN 6"),
-    (0x4E4D0800, "DW: GUNDAM 2"),
-    (0x4E4D0801, "Inversion"),
-    (0x4E4D0803, "DB: Raging Blast"),
-    (0x4E4D0805, "Tales of Vesperia"),
-    (0x4E4D080A, "Magnacarta2 (マグナカルタ2)"),
-    (0x4E4D080B, "Magnacarta2"),
-    (0x4E4D080C, "Magnacarta2"),
-    (0x4E4D0811, "Magna Carta II (마그나카르타2)"),
-    (0x4E4D0816, "Majin and the Forsaken Kingdom"),
-    (0x4E4D0817, "THE IDOLM@STER 2"),
-    (0x4E4D0819, "NINJA STORM 2"),
-    (0x4E4D081B, "CLASH OF THE TITANS"),
-    (0x4E4D081C, "ACE COMBAT: AH"),
-    (0x4E4D0821, "Knights Contract"),
-    (0x4E4D0826, "DB: Raging Blast 2"),
-    (0x4E4D0827, "Body and Brain Connection"),
-    (0x4E4D0834, "DW: Gundam 3"),
-    (0x4E4D083A, "Dark Souls"),
-    (0x4E4D083D, "SOULCALIBUR Ⅴ"),
-    (0x4E4D083E, "Ridge Racer Unbounded"),
-    (0x4E4D0842, "STORM Generations"),
-    (0x4E4D0846, "Dragon Ball Z UT"),
-    (0x4E4D0848, "Power Rangers Super Samurai"),
-    (0x4E4D084E, "DragonBallZ Kinect"),
-    (0x4E4D084F, "Armored Core V"),
-    (0x4E4D0853, "Armored Core V"),
-    (0x4E4D0854, "ARMORED CORE V(ASIA)"),
-    (0x4E4D0855, "The Witcher 2"),
-    (0x4E4D0856, "DBZ Budokai HD Collection"),
-    (0x4E4D0859, "TEKKEN TAG TOURNAMENT 2"),
-    (0x4E4D085B, "Star Trek"),
-    (0x4E4D085C, "NARUTO STORM 3"),
-    (0x4E4D0860, "DBZ: Battle of Z"),
-    (0x4E4D0862, "DBX"),
-    (0x4E4D0864, "ARMORED CORE: VERDICT DAY"),
-    (0x4E4D0865, "PAC-MAN GHOSTLY ADV"),
-    (0x4E4D0868, "NARUTO STORM R"),
-    (0x4E4D0869, "Digimon: ASR"),
-    (0x4E4D086A, "PAC-MAN GHOSTLY ADV 2"),
-    (0x4E4D87F3, "Namco Museum: Virtual Arcade"),
-    (0x4E5107D2, "Sakura Flamingo Archives"),
-    (0x4F4707D1, "PDC World Championship Darts: Pro Tour"),
-    (0x4F4707D4, "Everyone Sing"),
-    (0x4F4707D7, "Cartoon Network"),
-    (0x4F4807D1, "NASCAR '15"),
-    (0x4F580004, "ProStroke Golf"),
-    (0x4F5807D1, "PDC World Champ Darts"),
-    (0x50430001, "Xyanide"),
-    (0x504307D1, "Fairytale Fights"),
-    (0x504307D4, "Infernal"),
-    (0x504707D8, "Bejeweled 3"),
-    (0x504C0001, "Phantom Crash"),
-    (0x504C0002, "Kingdom Under Fire The Crusaders"),
-    (0x504C0004, "Kingdome under Fire  The Crusaders"),
-    (0x504C0006, "Kingdom Under Fire Heroes"),
-    (0x513107D1, "MotoGP13"),
-    (0x513107D4, "MXGP"),
-    (0x513107D5, "MotoGP 14"),
-    (0x513107D8, "RIDE"),
-    (0x513107D9, "MotoGP15"),
-    (0x515007D1, "Kick-Ass 2"),
-    (0x515007D3, "Bloodbath"),
-    (0x515507D1, "ESCHATOS"),
-    (0x515507D2, "GINGA FORCE"),
-    (0x523207D7, "Deadliest Warrior: Ancient Combat"),
-    (0x52410007, "Rocky"),
-    (0x52440001, "Yourself!Fitness"),
-    (0x524C07D1, "Crash Time"),
-    (0x524C07D2, "RTL Winter Sports 2009"),
-    (0x524C07D6, "Crash Time II"),
-    (0x524C07D8, "Winter Sports 2010"),
-    (0x524C07D9, "Crash Time 3"),
-    (0x524C07DE, "Summer Challenge"),
-    (0x533007D0, "Caladrius"),
-    (0x53410002, "GUILTYGEARX2#RELOAD"),
-    (0x53410006, "Guilty Gear X2 Reload"),
-    (0x53430003, "The Great Escape"),
-    (0x53430005, "Richard Burns Rally"),
-    (0x53430006, "Conflict Desert Storm II"),
-    (0x5343000E, "Rogue Trooper"),
-    (0x5343000F, "Constantine"),
-    (0x53430010, "Total Overdose"),
-    (0x534300F9, "CommandosStrikeForce"),
-    (0x534300FA, "Hitman Blood Money"),
-    (0x534300FB, "Just Cause"),
-    (0x534300FF, "Reservoir Dogs"),
-    (0x534307D3, "Conflict: Denied Ops"),
-    (0x534307D4, "Tomb Raider:Legend"),
-    (0x534307D5, "Just Cause"),
-    (0x534307D7, "CM 2007"),
-    (0x534307D9, "Battlestations: Midway"),
-    (0x534307DB, "Hitman: Blood Money"),
-    (0x534307E0, "Kane and Lynch:DeadMen"),
-    (0x534307E2, "Bionicle Heroes"),
-    (0x534307E4, "MINI NINJAS"),
-    (0x534307E5, "Shellshock 2"),
-    (0x534307E7, "Just Cause 2"),
-    (0x534307E9, "Battlestations Pacific"),
-    (0x534307EA, "Tomb Raider: Anniv."),
-    (0x534307EC, "Tomb Raider Underworld"),
-    (0x534307EF, "Conflict:DeniedOps(DE)"),
-    (0x534307F6, "Batman: Arkham Asylum"),
-    (0x534307F8, "Kane & Lynch 2"),
-    (0x5343080B, "Batman: AA GOTY"),
-    (0x534407F5, "Tomb Raider: Anniv."),
-    (0x534407FC, "Way of the Samurai 3"),
-    (0x53440803, "Dead Island"),
-    (0x53450003, "Sega GT 2002"),
-    (0x53450004, "CrazyTaxi3HighRoller"),
-    (0x53450007, "Panzer Dragoon ORTA"),
-    (0x53450008, "House of the Dead 3"),
-    (0x5345000A, "Jet Set Radio Future"),
-    (0x5345000B, "Gun Valkyrie"),
-    (0x5345000F, "ToeJamAndEarl3"),
-    (0x53450014, "Sega Soccer Slam"),
-    (0x53450020, "NHL 2K3"),
-    (0x53450021, "Sega GT Online"),
-    (0x53450025, "Headhunter Redemption"),
-    (0x53450027, "Puyo Pop Fever"),
-    (0x53450029, "Spikeout Battle Street"),
-    (0x5345002A, "Worms3d"),
-    (0x5345002B, "Sonic Heros"),
-    (0x53450030, "ESPN NFL 2K5"),
-    (0x53450031, "NBA 2K5"),
-    (0x53450032, "ESPN NHL 2K5"),
-    (0x53450033, "ESPN COLLEGE HOOPS 2K5"),
-    (0x53450035, "Worms Forts Under Siege"),
-    (0x53450036, "Outrun 2"),
-    (0x53450037, "MLB 2K5"),
-    (0x53450038, "Super Monkey Ball DX"),
-    (0x5345003C, "Sonic Mega Collection Plus"),
-    (0x53450084, "Spartan Total Warrior"),
-    (0x53450086, "Shadow The Hedgehog"),
-    (0x53450087, "Sonic Riders"),
-    (0x53450088, "Outrun 2006 Coast 2 Coast"),
-    (0x534507D1, "Football Manager 2006"),
-    (0x534507D2, "Condemned"),
-    (0x534507D4, "Chromehounds"),
-    (0x534507D5, "Full Auto"),
-    (0x534507D6, "SONIC THE HEDGEHOG"),
-    (0x534507DD, "The Club"),
-    (0x534507DF, "WSC2007"),
-    (0x534507E0, "Viking"),
-    (0x534507E1, "Virtua Tennis 3"),
-    (0x534507E3, "Phantasy Star Universe"),
-    (0x534507E5, "Golden Axe:Beast Rider"),
-    (0x534507E6, "SEGA Rally"),
-    (0x534507E7, "Football Manager 2007"),
-    (0x534507ED, "The Golden Compass"),
-    (0x534507EE, "Virtua Fighter 5"),
-    (0x534507EF, "Condemned 2: Bloodshot"),
-    (0x534507F0, "Universe at War"),
-    (0x534507F1, "The Incredible Hulk"),
-    (0x534507F2, "Iron Man"),
-    (0x534507F4, "Beijing 2008"),
-    (0x534507F5, "SEGA Superstars Tennis"),
-    (0x534507F7, "Football Manager 2008"),
-    (0x53450806, "Stormrise"),
-    (0x5345080C, "Aliens: Colonial Marines"),
-    (0x5345080E, "Alpha Protocol"),
-    (0x53450810, "Sonic's UGC"),
-    (0x53450812, "SONIC UNLEASHED"),
-    (0x53450813, "BAYONETTA"),
-    (0x53450814, "Virtua Tennis 2009"),
-    (0x53450815, "Sonic & SEGA Racing"),
-    (0x5345081A, "Vancouver 2010"),
-    (0x53450825, "SEGA MEGA DRIVE UC"),
-    (0x5345082A, "Aliens vs Predator"),
-    (0x5345082B, "Planet 51"),
-    (0x53450832, "End of Eternity"),
-    (0x53450836, "Iron Man 2"),
-    (0x53450839, "Resonance of Fate"),
-    (0x5345083F, "ANARCHY REIGNS"),
-    (0x53450841, "VANQUISH"),
-    (0x53450843, "London 2012"),
-    (0x53450845, "Thor: God of Thunder"),
-    (0x53450846, "Rise of Nightmares"),
-    (0x53450848, "Sonic Generations"),
-    (0x5345084C, "Virtual-On Force"),
-    (0x5345084D, "Sonic Free Riders"),
-    (0x53450857, "Virtua Tennis 4"),
-    (0x53450858, "Captain America: Super Soldier"),
-    (0x5345085A, "Dreamcast Collection"),
-    (0x5345085C, "BINARY DOMAIN"),
-    (0x5345085D, "Sonic & All-Stars Racing Transformed"),
-    (0x5345085E, "Alien: Isolation"),
-    (0x534A07D1, "Williams Pinball Classics"),
-    (0x534E0005, "Metal Slug 4"),
-    (0x534E0006, "The King of Fighters 2002"),
-    (0x534E0007, "KOF Maximum Impact Maniax"),
-    (0x534E0008, "Metal Slug 5"),
-    (0x534E000A, "The king of Fighters NeoWave"),
-    (0x534E000B, "The King of Fighters '94 Re-bout"),
-    (0x534E07D2, "KOF XII"),
-    (0x534E07D5, "SAMURAI SHOWDOWN SEN"),
-    (0x534E07DC, "THE KING OF FIGHTERS XIII"),
-    (0x535007D1, "Monster Madness"),
-    (0x535007D4, "Two Worlds"),
-    (0x535007DF, "X-Blades"),
-    (0x535107D4, "PROJECT SYLPHEED"),
-    (0x535107D5, "Seekers of Adoulin"),
-    (0x535107DA, "The Last Remnant"),
-    (0x535107DB, "Infinite Undiscovery"),
-    (0x535107DD, "Star Ocean: TLH"),
-    (0x535107E3, "Supreme Commander 2"),
-    (0x535107E4, "FINAL FANTASY XIII"),
-    (0x535107E8, "NIER"),
-    (0x535107E9, "Front Mission Evolved"),
-    (0x535107F1, "Mindjack"),
-    (0x535107F3, "Just Cause 2"),
-    (0x535107F5, "Dungeon Siege III"),
-    (0x535107FA, "DEUS EX: HUMAN REVOLUTION"),
-    (0x53510802, "Tomb Raider"),
-    (0x53510803, "Thief"),
-    (0x53510804, "Hitman: Absolution"),
-    (0x53510806, "FINAL FANTASY XIII-2"),
-    (0x53510811, "Sleeping Dogs"),
-    (0x53510818, "Sleeping Dogs"),
-    (0x5351081B, "LIGHTNING RETURNS FFXIII"),
-    (0x5351081C, "Hitman HD Pack"),
-    (0x5351081E, "MURDERED: SOUL SUSPECT"),
-    (0x53510823, "Rise of the Tomb Raider"),
-    (0x53518813, "Hitman: Absolution - Sniper Challenge"),
-    (0x53530003, "Outlaw Volleyball"),
-    (0x53538003, "Outlaw Volleyball"),
-    (0x53550001, "Bistro Cupid"),
-    (0x53550009, "Psyvariar2 Extend Edition"),
-    (0x535507D4, "RAIDEN FIGHTERS ACES"),
-    (0x535607D1, "Great Battles Medieval"),
-    (0x535607D7, "HISTORY: Legends of War"),
-    (0x54430001, "Dead or Alive 3"),
-    (0x54430003, "Ninja Gaiden"),
-    (0x54430004, "Fatal Frame"),
-    (0x54430006, "DEAD OR ALIVE 1 ULTIMATE"),
-    (0x54430007, "Dead Or Alive Extreme"),
-    (0x5443000A, "fatal frame 2"),
-    (0x5443000D, "Ninja Gaiden Black"),
-    (0x5443000E, "Tecmo Classic Arcade"),
-    (0x544307D1, "DEAD OR ALIVE 4"),
-    (0x544307D2, "DEAD OR ALIVE Xtreme 2"),
-    (0x544307D5, "NINJA GAIDEN 2"),
-    (0x544307D8, "Ninja Gaiden Black"),
-    (0x544307DC, "QUANTUM THEORY"),
-    (0x544607EC, "Indiana Jones"),
-    (0x544707D1, "シューティング ラブ。２００Ｘ"),
-    (0x544907D1, "Over-G"),
-    (0x544D0001, "Shrek"),
-    (0x544D0002, "Robotech Battlecry"),
-    (0x544D0003, "Aquaman Battle for Atlantis"),
-    (0x544D0004, "Shrek Superparty"),
-    (0x544D0005, "Mercedes Benz World Racing"),
-    (0x544D0007, "Knights of the Temple"),
-    (0x544D000A, "UFC Tapout 2"),
-    (0x544D0010, "Star Trek: Shattered Universe"),
-    (0x544D0012, "World Racing 2"),
-    (0x544D0013, "Knights of the temple 2"),
-    (0x544F07D1, "Zoids Infinity EX Neo (ゾイドインフィニティEX NEO)"),
-    (0x54510005, "Red Faction II"),
-    (0x54510006, "Yager"),
-    (0x5451000A, "Tetris Worlds"),
-    (0x5451000B, "Evil Dead A Fistful Of Boomstick"),
-    (0x5451000D, "WWE Raw 2"),
-    (0x54510016, "MotoGP 2"),
-    (0x54510018, "The Punisher"),
-    (0x5451001A, "Spongebob squarepants"),
-    (0x5451001C, "TetrisWorldsLive"),
-    (0x5451001D, "BrokenSwordTheSleepingDragon"),
-    (0x5451001E, "FindingNemo"),
-    (0x5451001F, "Scooby Doo Night Of 100 Frights"),
-    (0x54510020, "Fairly Odd Parents"),
-    (0x54510021, "MX Unleashed"),
-    (0x54510022, "Full Spectrum Warrior"),
-    (0x54510023, "Sphinx and the Cursed Mummy"),
-    (0x54510024, "WWE Wrestlemania  21"),
-    (0x54510025, "Evil Dead  Regeneration"),
-    (0x54510026, "DestroyAllHumans"),
-    (0x54510027, "The Spongebob Squarepants Movie"),
-    (0x54510028, "The Incredibles"),
-    (0x54510089, "HotWheelsStuntTrackChallenge"),
-    (0x545100ED, "MX vs ATV Unleashed"),
-    (0x545100EF, "Juiced"),
-    (0x545100F2, "Cars"),
-    (0x545100F6, "The Incredibles Rise Of The Underminer"),
-    (0x545100F7, "SccobyDoo Unmasked"),
-    (0x545100F8, "Full Spectrum Warrior Ten Hammers"),
-    (0x545100F9, "WWE Wrestlemania 21"),
-    (0x545100FA, "SpongeBob SquarePants: Lights, Camera, Pants!"),
-    (0x54510105, "Avatar The last Airbender"),
-    (0x54510106, "DestroyAllHumans2"),
-    (0x545107D1, "Saints Row"),
-    (0x545107D2, "The Outfit"),
-    (0x545107D4, "MotoGP 06"),
-    (0x545107D5, "Juiced 2: HIN"),
-    (0x545107D7, "Tetris Evolution"),
-    (0x545107D8, "Frontlines:Fuel of War"),
-    (0x545107DA, "Conan"),
-    (0x545107DE, "MX vs. ATV: Untamed"),
-    (0x545107E0, "Smackdown vs RAW 2007"),
-    (0x545107E1, "Avatar: TLA: TBE"),
-    (0x545107E6, "Darksiders"),
-    (0x545107EA, "Cars"),
-    (0x545107EF, "BAJA: Edge of Control"),
-    (0x545107F1, "Ratatouille"),
-    (0x545107F4, "Stuntman: Ignition"),
-    (0x545107F6, "MotoGP 07"),
-    (0x545107FC, "Saints Row 2"),
-    (0x545107FE, "DAH! Path of the Furon"),
-    (0x54510801, "Cars: Mater-National"),
-    (0x5451080B, "SmackDown vs RAW 2008"),
-    (0x5451080D, "Red Faction: Guerrilla"),
-    (0x54510824, "WALL€E"),
-    (0x54510826, "SmackDown vs. RAW 2009"),
-    (0x54510829, "MX vs ATV Reflex"),
-    (0x5451082A, "UFC 2009 Undisputed"),
-    (0x5451082C, "Warhammer 40,000: Space Marine"),
-    (0x54510835, "WoO: Sprint Cars"),
-    (0x54510836, "Saints Row 2 BRD"),
-    (0x54510838, "WWE Legends"),
-    (0x54510839, "Disney Pixar UP"),
-    (0x5451083B, "50 Cent: BotS"),
-    (0x54510840, "5th Grader: Game Time"),
-    (0x54510842, "Metro 2033"),
-    (0x54510844, "SmackDown vs. RAW 2010"),
-    (0x54510846, "Homefront"),
-    (0x54510848, "Cars: Race-O-Rama"),
-    (0x54510850, "SpongeBob: Truth-Sq."),
-    (0x54510851, "UFC Undisputed 2010"),
-    (0x54510859, "The Biggest Loser: Ultimate Workout"),
-    (0x5451085B, "UFC Personal Trainer"),
-    (0x5451085C, "MegaMind: Ultimate Showdown"),
-    (0x5451085D, "WWE Smackdown vs. Raw 2011"),
-    (0x5451085E, "Fantastic Pets"),
-    (0x5451085F, "Red Faction: Armageddon"),
-    (0x54510860, "Super Hero Squad: TIG"),
-    (0x54510864, "Truth or Lies"),
-    (0x54510865, "de Blob 2"),
-    (0x54510866, "WWE All Stars"),
-    (0x54510869, "YOU DON'T KNOW JACK"),
-    (0x5451086B, "Deepak Chopra’s Leela™"),
-    (0x5451086D, "Saints Row: The Third"),
-    (0x54510873, "The Penguins of Madagascar"),
-    (0x54510874, "Kung Fu Panda 2"),
-    (0x54510875, "Rio"),
-    (0x5451087B, "MX vs. ATV Alive"),
-    (0x5451087D, "UFC Undisputed 3"),
-    (0x5451088B, "Puss in Boots"),
-    (0x5451088C, "Dance Paradise"),
-    (0x54510890, "WWE '12"),
-    (0x54510896, "Darksiders II"),
-    (0x54510899, "uDraw Studio: Instant Artist"),
-    (0x5451089B, "Marvel Super Hero Squad: Comic Combat"),
-    (0x5451089C, "Pictionary™: Ultimate Edition"),
-    (0x5451089D, "SpongeBob's Surf & Skate Roadtrip"),
-    (0x545108AE, "Jeopardy!"),
-    (0x545108AF, "Wheel of Fortune"),
-    (0x545108B4, "WWE '13"),
-    (0x54530002, "Barbarian"),
-    (0x54530005, "RoboCop"),
-    (0x54540001, "4X4 EVO 2"),
-    (0x54540003, "Max Payne"),
-    (0x54540004, "Serious Sam"),
-    (0x54540005, "Conflict Desert Storm"),
-    (0x54540006, "State Of Emergency"),
-    (0x54540008, "Midnight Club 2"),
-    (0x54540009, "Mafia"),
-    (0x5454000A, "Vietcong Purple Haze"),
-    (0x5454000B, "CelebrityDeathmatch"),
-    (0x5454000C, "Max Payne 2"),
-    (0x5454000E, "Grand Theft Auto 3"),
-    (0x5454000F, "Grand Theft Auto Vice City"),
-    (0x54540011, "Pure Pinball"),
-    (0x54540014, "Army Men Sarges War"),
-    (0x54540015, "Motocross Mania 3"),
-    (0x54540017, "Outlaw Golf 2"),
-    (0x54540019, "Manhunt"),
-    (0x5454001A, "Red Dead Revolver"),
-    (0x54540073, "Grand Theft Auto Vice City"),
-    (0x54540076, "Outlaw Tennis"),
-    (0x54540077, "Ford Mustang"),
-    (0x54540079, "Midnight Club 3 Dub Edition"),
-    (0x5454007A, "Scaler"),
-    (0x5454007B, "The Guy Game"),
-    (0x5454007C, "SpyVsSpy"),
-    (0x5454007E, "Gotcha"),
-    (0x54540081, "CharlieAndTheChocolateFactory"),
-    (0x54540082, "GTA San Andreas (Xbox Classic)"),
-    (0x54540086, "Grand Theft Auto: San Andreas"),
-    (0x54540089, "FordVsChevy"),
-    (0x5454008A, "Codename Kids Next Door  Operation VIDEOGAME"),
-    (0x5454008F, "Sid Meiers Pirates"),
-    (0x54540093, "Renegade Paintball"),
-    (0x54540095, "GTA San Andreas (Xbox Classic MOD)"),
-    (0x5454009B, "The Warriors"),
-    (0x545400A3, "GTA San Andreas (Xbox Classic)"),
-    (0x545400A4, "GTA San Andreas (Xbox Classic)"),
-    (0x545400A7, "Zathura"),
-    (0x545400AB, "The Da Vinci Code"),
-    (0x545400B0, "Family Guy - The Video Game"),
-    (0x545407D2, "College Hoops 2K6"),
-    (0x545407D3, "MLB 2K6"),
-    (0x545407D4, "Amped 3"),
-    (0x545407D5, "Top Spin 2"),
-    (0x545407D8, "BioShock"),
-    (0x545407DC, "Prizefighter"),
-    (0x545407DD, "The Bureau"),
-    (0x545407DF, "Rockstar Table Tennis"),
-    (0x545407E0, "Prey"),
-    (0x545407E2, "NBA 2K6"),
-    (0x545407E3, "NHL 2K6"),
-    (0x545407E4, "The BIGS"),
-    (0x545407E5, "Civilization Revolution"),
-    (0x545407E6, "Mafia II"),
-    (0x545407E7, "Borderlands"),
-    (0x545407EB, "NHL 2K7"),
-    (0x545407EE, "The Darkness"),
-    (0x545407EF, "NBA 2K7"),
-    (0x545407F1, "College Hoops 2K7"),
-    (0x545407F2, "GTA IV"),
-    (0x545407F8, "Midnight Club: LA"),
-    (0x545407FA, "MLB 2K7"),
-    (0x545407FD, "F4: ROTSS"),
-    (0x545407FF, "Top Spin 3"),
-    (0x54540804, "The Darkness [German]"),
-    (0x54540807, "All Pro Football 2K8"),
-    (0x54540809, "NHL 2K8"),
-    (0x5454080C, "NBA 2K8"),
-    (0x5454080E, "BioShock"),
-    (0x54540817, "MLB 2K8"),
-    (0x54540818, "College Hoops 2K8"),
-    (0x5454081A, "Bully Scholarship Ed."),
-    (0x54540822, "BioShock"),
-    (0x54540828, "Sid Meiers Pirates"),
-    (0x54540829, "NBA 2K9"),
-    (0x5454082B, "Red Dead Redemption"),
-    (0x5454082D, "BioShock 2"),
-    (0x5454082E, "NHL 2K9"),
-    (0x5454082F, "MLB® Front Office Mgr"),
-    (0x54540830, "The BIGS 2"),
-    (0x54540832, "Bully"),
-    (0x54540841, "GTA San Andreas (Xbox 360)"),
-    (0x54540842, "MLB 2K9"),
-    (0x54540843, "Spec Ops: The Line"),
-    (0x5454084B, "Max Payne 2 The Fall of Max Payne"),
-    (0x5454084C, "Max Payne"),
-    (0x5454084E, "Duke Nukem Forever"),
-    (0x54540852, "NBA 2K10"),
-    (0x54540853, "NHL 2K10"),
-    (0x54540855, "MLB 2K10"),
-    (0x54540859, "Top Spin 4"),
-    (0x5454085D, "BioShock Infinite"),
-    (0x54540861, "BioShock 2"),
-    (0x54540865, "BioShock 2 (Kor)"),
-    (0x5454086B, "Max Payne 3"),
-    (0x5454086C, "L.A. Noire"),
-    (0x5454087A, "NBA 2K11"),
-    (0x5454087C, "Borderlands 2"),
-    (0x5454087D, "Mafia II"),
-    (0x54540882, "XCOM: Enemy Unknown"),
-    (0x54540883, "Nicktoons MLB"),
-    (0x5454088D, "The Darkness II"),
-    (0x5454088E, "MLB 2K11"),
-    (0x54540890, "Carnival Games: MSMD"),
-    (0x54540894, "NBA 2K12"),
-    (0x54540895, "Nickelodeon Dance"),
-    (0x54540899, "Let's Cheer!"),
-    (0x5454089E, "MLB 2K12"),
-    (0x545408A2, "NBA 2K13"),
-    (0x545408A5, "Nickelodeon Dance 2"),
-    (0x545408A7, "GTA V"),
-    (0x545408AB, "MLB 2K13"),
-    (0x545408AE, "XCOM: Enemy Within"),
-    (0x545408B0, "NBA 2K14"),
-    (0x545408B2, "WWE 2K14"),
-    (0x545408B4, "Borderlands: The Pre-Sequel"),
-    (0x545408B5, "NBA 2K15"),
-    (0x545408B6, "WWE 2K15"),
-    (0x545408B8, "GTA San Andreas (Xbox 360)"),
-    (0x545408B9, "Tales from the Borderlands"),
-    (0x545408BA, "WWE 2K17"),
-    (0x545408BD, "NBA 2K17"),
-    (0x545408C0, "NBA 2K18"),
-    (0x545607D3, "Dance Central"),
-    (0x545907D1, "Defiance"),
-    (0x545A07D1, "Deathsmiles"),
-    (0x545A07D2, "DoDonPachi Resurrection"),
-    (0x545A07D3, "Akai Katana"),
-    (0x545A07D4, "Under Defeat HD: Deluxe Edition"),
-    (0x545A07D5, "Carrier Command: Gaea Mission"),
-    (0x554F07D3, "Way of the Samurai 3"),
-    (0x554F07D4, "Rock of the Dead"),
-    (0x55530001, "Batman Vengeance"),
-    (0x55530002, "Rayman 3 Hoodlum Havoc"),
-    (0x55530003, "Rayman Arena"),
-    (0x55530004, "Deathrow"),
-    (0x55530005, "Ghost Recon 2"),
-    (0x55530006, "Ghost Recon"),
-    (0x55530007, "Ghost Recon Island Thunder"),
-    (0x55530008, "FarCry Instincts"),
-    (0x55530009, "XIII"),
-    (0x5553000C, "Splinter Cell"),
-    (0x55530011, "Myst III Revelation"),
-    (0x55530012, "Dragons Lair 3D"),
-    (0x55530013, "Rainbow Six 3"),
-    (0x55530014, "Rocky"),
-    (0x55530016, "Murakumo: Renegade Mech Pursuit"),
-    (0x55530018, "Beyond Good and Evil"),
-    (0x55530019, "Splinter Cell Pandora Tomorrow"),
-    (0x5553001C, "Batman Rise of Sin Tzu"),
-    (0x5553001D, "Prince of Persia The Sands of Time"),
-    (0x55530020, "Crouching Tiger"),
-    (0x55530034, "Rocky Legends"),
-    (0x55530035, "CsiCrimeSceneInvestigation"),
-    (0x55530036, "187 Ride Or Die"),
-    (0x55530037, "RainbowSix3BlackArrowntsc"),
-    (0x55530038, "Tom Clancys Splinter Cell Chaos Theory"),
-    (0x55530039, "Chessmaster"),
-    (0x5553003A, "Dukes of Hazzard: Return of the General Lee"),
-    (0x5553003B, "Prince of Persia Warrior Within"),
-    (0x5553003C, "Brothers In Arms Road To Hill 30"),
-    (0x5553003F, "CSI"),
-    (0x55530041, "Splinter Cell Chaos Theory"),
-    (0x55530048, "RainbowSix Lockdown"),
-    (0x55530049, "Monster 4x4 World Circuit"),
-    (0x5553004A, "Myst IV"),
-    (0x5553004B, "Cold Fear"),
-    (0x5553004C, "PeterJacksonsKingKong"),
-    (0x5553004D, "Ghost Recon 2 Summit Strike"),
-    (0x55530054, "Ghost Recon Advanced Warfighter"),
-    (0x55530056, "And1 Streetball"),
-    (0x55530057, "Blazing Angels: SOWWII"),
-    (0x55530058, "Prince of Persia The Two Thrones"),
-    (0x5553005A, "Brothers In Arms Earned In Blood"),
-    (0x5553005C, "Darkwatch"),
-    (0x5553005E, "splinter cell double agent"),
-    (0x5553005F, "Rainbow Six  Critical Hour"),
-    (0x55530060, "FarCry Instincts Evolution"),
-    (0x55530061, "Open Season"),
-    (0x555307D3, "King Kong"),
-    (0x555307D4, "Assassin's Creed"),
-    (0x555307D5, "TC's GRAW"),
-    (0x555307D6, "TC's RainbowSix Vegas"),
-    (0x555307D7, "TC's SC Double Agent"),
-    (0x555307DB, "Blazing Angels"),
-    (0x555307DC, "FC Instincts Predator"),
-    (0x555307E0, "TC's EndWar"),
-    (0x555307E5, "Naruto Rise Of A Ninja"),
-    (0x555307E6, "Enchanted Arms"),
-    (0x555307E8, "Open Season"),
-    (0x555307EA, "Brothers in Arms: HH"),
-    (0x555307EE, "IMPORT TUNER CHALLENGE"),
-    (0x555307F0, "TC's GRAW2"),
-    (0x555307F3, "Rayman Raving Rabbids"),
-    (0x555307F6, "R.U.S.E."),
-    (0x555307F8, "TMNT"),
-    (0x555307F9, "Surf's Up"),
-    (0x555307FA, "Call of Juarez"),
-    (0x555307FB, "Pure Futbol"),
-    (0x555307FE, "Blazing Angels 2"),
-    (0x55530801, "Beowulf"),
-    (0x55530804, "DMMM:Elements"),
-    (0x55530807, "TC's RainbowSix Vegas2"),
-    (0x55530808, "AA: True Soldiers"),
-    (0x55530809, "CSI-Hard Evidence"),
-    (0x5553080B, "SplinterCellConviction"),
-    (0x5553080C, "Prince of Persia"),
-    (0x5553080F, "Lost Via Domus"),
-    (0x55530810, "Far Cry 2"),
-    (0x55530816, "TC's H.A.W.X"),
-    (0x5553081C, "Call of Juarez 2"),
-    (0x5553081E, "SW Snowboarding"),
-    (0x55530825, "Naruto The Broken Bond"),
-    (0x5553082F, "Shaun White Skateboarding"),
-    (0x55530833, "Ghost Recon: Future Soldier"),
-    (0x55530836, "Cloudy with a..."),
-    (0x5553083B, "Assassin's Creed II"),
-    (0x5553083C, "Cameron's Avatar"),
-    (0x5553083E, "Prince of Persia The Forgotten Sands"),
-    (0x5553083F, "CSI: Deadly Intent"),
-    (0x55530841, "Heroes Over Europe"),
-    (0x55530848, "Tom Clancy€s H.A.W.X. 2"),
-    (0x55530849, "CSI: Fatal Conspiracy"),
-    (0x5553084C, "Child of Eden"),
-    (0x5553084E, "Fighters Uncaged"),
-    (0x5553084F, "Your Shape : Fitness Evolved"),
-    (0x55530850, "Motionsports"),
-    (0x55530851, "Michael Jackson The Experience"),
-    (0x5553085D, "Assassin's Creed Brotherhood"),
-    (0x5553086B, "PowerUp Heroes"),
-    (0x55530871, "The Adventures of Tintin"),
-    (0x55530873, "Rocksmith"),
-    (0x55530874, "Call Of Juarez : The Cartel"),
-    (0x55530877, "Just Dance Kids 2"),
-    (0x55530879, "Assassin's Creed Revelations"),
-    (0x5553087E, "Driver San Francisco"),
-    (0x5553087F, "Family Feud"),
-    (0x55530880, "The Price Is Right"),
-    (0x55530881, "NCIS Game"),
-    (0x55530882, "Raving Rabbids: A&K"),
-    (0x55530883, "Who Wants To Be A Millionaire™"),
-    (0x55530884, "Rayman Origins"),
-    (0x55530885, "Hole In The Wall"),
-    (0x55530886, "Your Shape Fitness Evolved 2012"),
-    (0x55530887, "MotionSports: Adrenaline"),
-    (0x55530888, "Just Dance 3"),
-    (0x55530889, "Self-Defense Training Camp"),
-    (0x5553088C, "Far Cry 3"),
-    (0x5553088D, "Black Eyed Peas Experience"),
-    (0x55530890, "Just Dance Kids"),
-    (0x555308AD, "Marvel Avengers: Battle for Earth"),
-    (0x555308AE, "Assassin's Creed III"),
-    (0x555308B2, "Just Dance Greatest Hits"),
-    (0x555308B3, "JD Disney Party"),
-    (0x555308B4, "Hip-Hop Dance Experience"),
-    (0x555308B5, "Just Dance 4"),
-    (0x555308B6, "Tom Clancy's Splinter Cell Blacklist"),
-    (0x555308B7, "Watch Dogs"),
-    (0x555308BD, "The Smurfs 2"),
-    (0x555308C0, "Rocksmith 2014 Edition"),
-    (0x555308C1, "Just Dance 2014"),
-    (0x555308C2, "Assassin's Creed IV Black Flag"),
-    (0x555308C3, "Rayman Legends"),
-    (0x555308C5, "The Stick of Truth"),
-    (0x555308C7, "Just Dance Kids 2014"),
-    (0x555308CA, "Far Cry® 4"),
-    (0x555308CB, "The Crew"),
-    (0x555308CC, "Just Dance Disney Party 2"),
-    (0x555308CD, " Just Dance 2015"),
-    (0x555308CE, "Assassin's Creed® Rogue"),
-    (0x555308CF, "Far Cry® 3 Blood Dragon"),
-    (0x555308D0, "Rabbids Invasion: The Interactive TV Show"),
-    (0x555308D3, "Just Dance 2016"),
-    (0x555308D5, "Just Dance 2017"),
-    (0x555308D7, "Just Dance 2018"),
-    (0x555308D9, "Just Dance  2019"),
-    (0x563307D1, "Country Dance All Stars"),
-    (0x564707D1, "Ski-Doo Challenge"),
-    (0x564707D3, "RAIDEN FIGHTERS ACES"),
-    (0x564707D4, "Darkest Of Days"),
-    (0x564E0006, "Baldurs Gate Dark Alliance"),
-    (0x565107D6, "Let's Dance"),
-    (0x565107D7, "Legends of War"),
-    (0x56550003, "Crash Bandicoot TWOC"),
-    (0x56550004, "LotR The Fellowship of the Ring"),
-    (0x56550005, "Buffy Chaos Bleeds"),
-    (0x56550007, "Hunter the Reckoning"),
-    (0x56550008, "Futurama"),
-    (0x5655000A, "metal arms"),
-    (0x5655000D, "The Thing"),
-    (0x5655000E, "Enclave"),
-    (0x5655000F, "Battlestar Galactica"),
-    (0x56550011, "The Hulk"),
-    (0x56550014, "Dark Angel"),
-    (0x56550015, "The Simpsons Hit and Run"),
-    (0x5655001A, "Baldurs Gate Dark Alliance"),
-    (0x5655001F, "Crash Nitro Kart"),
-    (0x56550023, "The Hobbit"),
-    (0x56550026, "Chronicles of Riddick"),
-    (0x56550027, "Red Ninja End of Honor"),
-    (0x56550029, "Van Helsing"),
-    (0x5655002A, "BarbieHorseAdventures"),
-    (0x5655002D, "Hunter: The Reckoning Redeemer"),
-    (0x5655002F, "Fight Club"),
-    (0x56550030, "Predator  Concrete Jungle"),
-    (0x56550031, "Leisure Suit Larry MCL"),
-    (0x56550034, "Spyro A Heros Tail"),
-    (0x56550036, "Crash Twinsanity"),
-    (0x56550037, "Robots"),
-    (0x56550039, "the incredible hulk ultimate destruction"),
-    (0x5655003F, "Scarface"),
-    (0x56550041, "CRASH Tag Team Racing"),
-    (0x56550042, "50 Cent Bulletproof"),
-    (0x5655004A, "TLoS A New Beginning"),
-    (0x5655004B, "Ice Age 2 The Meltdown"),
-    (0x565507D4, "The Bourne Conspiracy"),
-    (0x565507D8, "Eragon"),
-    (0x565507D9, "F.E.A.R."),
-    (0x565507DA, "TimeShift"),
-    (0x565507E0, "Eragon"),
-    (0x565507E4, "Crash Of The Titans"),
-    (0x565507E6, "Spiderwick Chronicles"),
-    (0x565507E8, "F.E.A.R. Files"),
-    (0x565507F2, "Crash Bandicoot: Wrath of Cortex"),
-    (0x565507F5, "Metal Arms Glitch in the System"),
-    (0x565507F9, "Legend of Spyro"),
-    (0x565507FA, "Crash Mind Over Mutant"),
-    (0x565507FF, "The Legend of Spyro A New Beginning"),
-    (0x56560001, "Crash Bandicoot The Wrath of Cortex"),
-    (0x56560002, "Bruce Lee Quest Of The Dragon"),
-    (0x56560003, "the thing"),
-    (0x56560005, "TLOR The Fellowship of the Ring"),
-    (0x5656000A, "Jurassic Park Operation Genesis"),
-    (0x56560011, "Mace Griffin Bounty Hunter"),
-    (0x56560021, "BloodRayne"),
-    (0x565707D0, "Lollipop Chainsaw"),
-    (0x565707D4, "Meikyuu X Blood: Reloaded"),
-    (0x565707D6, "KILLER IS DEAD"),
-    (0x573607D2, "Snipers"),
-    (0x573607D5, "Hunter's Trophy 2 - Europa"),
-    (0x573607D7, "My Body Coach 3"),
-    (0x573607D8, "Obut Pétanque 2"),
-    (0x573607D9, "Handball Challenge 14"),
-    (0x573607DB, "Truck Racer"),
-    (0x573607DD, "WRC 4"),
-    (0x573607DF, "Rugby 15"),
-    (0x573607E0, "Motorcycle Club"),
-    (0x573607E2, "WRC 5"),
-    (0x573607E3, "Rugby World Cup 2015"),
-    (0x573607E4, "Handball 16"),
-    (0x57450001, "Pro Beach Soccer"),
-    (0x57450005, "Curse  The Eye of Isis"),
-    (0x57520001, "Justice League Heroes"),
-    (0x575207D2, "Looney Tunes: AA"),
-    (0x575207D3, "F.E.A.R. 2"),
-    (0x575207D5, "Looney Tunes: AA EUR"),
-    (0x575207D7, "LEGO Batman"),
-    (0x575207DA, "WANTED:Weapons of Fate"),
-    (0x575207E6, "LEGO Harry Potter"),
-    (0x575207E9, "Green Lantern"),
-    (0x575207EF, "The Lord of the Rings: War in the North"),
-    (0x575207F0, "LEGO Rock Band"),
-    (0x575207F1, "LOTG"),
-    (0x575207F2, "WtWTA"),
-    (0x575207F5, "Scene It?"),
-    (0x575207F7, "Scene It?"),
-    (0x575207F8, "Scene It?"),
-    (0x575207F9, "Scene It?"),
-    (0x575207FA, "Scene It?"),
-    (0x575207FD, "Mortal Kombat"),
-    (0x57520800, "F.E.A.R. 3"),
-    (0x57520801, "Game Party In Motion"),
-    (0x57520802, "Batman: Arkham City"),
-    (0x5752080C, "Sesame Street: Once Upon a Monster"),
-    (0x5752080D, "Happy Feet Two: The Videogame"),
-    (0x57520812, "LEGO Harry Potter: Years 5-7"),
-    (0x57520817, "LEGO Batman 2"),
-    (0x5752081D, "LEGO Lord of the Rings"),
-    (0x57520824, "The Witcher 2: Assassins of Kings"),
-    (0x57520826, "Harry Potter for Kinect"),
-    (0x57520828, "Batman: Arkham Origins"),
-    (0x57520829, "Injustice: Gods Among Us"),
-    (0x5752082C, "Shadow of Mordor"),
-    (0x5752082F, "Midway Arcade Origins"),
-    (0x57520835, "LEGO Marvel"),
-    (0x57520838, "World's Best Driver"),
-    (0x57520841, "LEGO Movie Videogame"),
-    (0x57520844, "LEGO The Hobbit"),
-    (0x57520845, "BEYOND GOTHAM"),
-    (0x5752084B, "LEGO® Dimensions™"),
-    (0x5752084C, "Mortal Kombat X"),
-    (0x5752084D, "LEGO® Jurassic World™ "),
-    (0x5752084F, "LEGO® Marvel's Avengers"),
-    (0x57520851, "LEGO® Star Wars™: The Force Awakens"),
-    (0x57520855, "Cars 3: Driven to Win"),
-    (0x57BD267A, "dakar 2"),
-    (0x584107D1, "Hexic HD"),
-    (0x584107D2, "Bejeweled 2"),
-    (0x584107D3, "Hardwood Hearts"),
-    (0x584107D5, "Small Arms"),
-    (0x584107D7, "Marble Blast Ultra"),
-    (0x584107D8, "Joust"),
-    (0x584107DA, "Mutant Storm Reloaded"),
-    (0x584107DB, "Outpost Kaloki X"),
-    (0x584107DD, "Wik: Fable of Souls"),
-    (0x584107DE, "Worms"),
-    (0x584107DF, "Gauntlet"),
-    (0x584107E0, "Robotron: 2084"),
-    (0x584107E1, "Smash TV"),
-    (0x584107E2, "Hardwood Backgammon"),
-    (0x584107E4, "Hardwood Spades"),
-    (0x584107E5, "RoboBlitz"),
-    (0x584107E7, "Cloning Clyde"),
-    (0x584107E9, "Novadrome"),
-    (0x584107EA, "Jewel Quest"),
-    (0x584107EC, "Bankshot Billiards 2"),
-    (0x584107ED, "Geometry Wars Evolved"),
-    (0x584107EE, "Crystal Quest"),
-    (0x584107EF, "Zuma"),
-    (0x584107F0, "Street Trace:NYC"),
-    (0x584107F1, "Feeding Frenzy"),
-    (0x584107F2, "Astropop"),
-    (0x584107F3, "UNO"),
-    (0x584107F4, "Street Fighter II' HF"),
-    (0x584107F5, "Spyglass Board Games"),
-    (0x584107F6, "Texas Hold'em"),
-    (0x584107F7, "Mad Tracks"),
-    (0x584107F8, "LUMINES LIVE!"),
-    (0x584107FB, "Jetpac Refuelled"),
-    (0x584107FE, "Assault Heroes"),
-    (0x58410807, "Arkadian Warriors"),
-    (0x5841080C, "Space Giraffe"),
-    (0x5841080D, "Luxor 2"),
-    (0x58410810, "Heavy Weapon"),
-    (0x58410811, "PAC-MAN"),
-    (0x58410812, "GALAGA"),
-    (0x58410813, "Frogger"),
-    (0x58410814, "Scramble"),
-    (0x58410815, "Time Pilot"),
-    (0x58410816, "Yie Ar Kung-Fu"),
-    (0x58410817, "Gyruss"),
-    (0x58410818, "Contra"),
-    (0x58410819, "Rush'n Attack"),
-    (0x5841081A, "Super Contra"),
-    (0x5841081B, "Track and Field"),
-    (0x5841081E, "Mutant Storm Empire"),
-    (0x58410820, "Buku Sudoku"),
-    (0x58410821, "Commanders: Attack"),
-    (0x58410822, "Darwinia+"),
-    (0x58410823, "Defender"),
-    (0x58410824, "DOOM"),
-    (0x58410825, "Eets: Chowdown"),
-    (0x58410826, "Hexic 2"),
-    (0x58410829, "Paperboy"),
-    (0x5841082D, "Root Beer Tapper"),
-    (0x5841082F, "Band of Bugs"),
-    (0x58410830, "Switchball"),
-    (0x58410831, "TotemBall"),
-    (0x58410833, "Ultimate Mortal Kombat 3"),
-    (0x58410834, "Pinball FX"),
-    (0x58410835, "NEW RALLY-X"),
-    (0x58410836, "DIG DUG"),
-    (0x58410837, "MS.PAC-MAN"),
-    (0x5841083A, "Fatal Fury Special"),
-    (0x5841083B, "Tetris Splash"),
-    (0x5841083C, "Aegis Wing"),
-    (0x5841083D, "Soltrio Solitaire"),
-    (0x5841083E, "Catan"),
-    (0x5841083F, "Bomberman LIVE"),
-    (0x58410840, "Carcassonne"),
-    (0x58410841, "3D Ultra Minigolf"),
-    (0x58410842, "Cyberball 2072"),
-    (0x58410844, "Geon"),
-    (0x58410846, "Alien Hominid HD"),
-    (0x58410847, "Castlevania: Symphony of the Night"),
-    (0x5841084C, "Centipede & Millipede"),
-    (0x5841084D, "Missile Command"),
-    (0x5841084E, "Asteroids & Deluxe"),
-    (0x5841084F, "Tempest"),
-    (0x58410850, "Battlezone"),
-    (0x58410851, "Warlords"),
-    (0x58410857, "War World"),
-    (0x5841085B, "Lode Runner"),
-    (0x5841085C, "N+"),
-    (0x5841085E, "Marathon: Durandal"),
-    (0x5841085F, "Double Dragon"),
-    (0x58410860, "Magic: The Gathering"),
-    (0x58410861, "Battlestar Galactica"),
-    (0x58410862, "Golden Axe"),
-    (0x58410863, "Sonic The Hedgehog 2"),
-    (0x58410864, "Sonic The Hedgehog"),
-    (0x58410865, "Prince of Persia"),
-    (0x58410866, "Chessmaster Live"),
-    (0x58410867, "Elements of Destruction"),
-    (0x58410868, "Wing Commander Arena"),
-    (0x58410869, "Sensible World of Soccer"),
-    (0x5841086A, "Boom Boom Rocket"),
-    (0x5841086C, "Bliss Island"),
-    (0x5841086D, "Streets of Rage 2"),
-    (0x5841086E, "Puzzle Fighter HD"),
-    (0x5841086F, "Rocketmen:Axis of Evil"),
-    (0x58410870, "TMNT 1989 Arcade"),
-    (0x58410872, "E4"),
-    (0x58410873, "Undertow"),
-    (0x58410874, "Word Puzzle"),
-    (0x58410875, "RocketBowl"),
-    (0x58410876, "Ecco the Dolphin"),
-    (0x58410877, "Pac-Man C.E."),
-    (0x58410878, "Ikaruga"),
-    (0x58410879, "Shred Nebula"),
-    (0x5841087B, "Shotest Shogi"),
-    (0x5841087C, "Frogger 2"),
-    (0x5841087D, "Coffeetime Crosswords"),
-    (0x5841087E, "Beat'n Groovy"),
-    (0x5841087F, "Yaris"),
-    (0x58410886, "Aces of the Galaxy"),
-    (0x58410888, "Triggerheart Exelica"),
-    (0x58410889, "Peggle"),
-    (0x5841088C, "Feeding Frenzy 2"),
-    (0x5841088D, "Speedball 2"),
-    (0x5841088E, "TRON"),
-    (0x5841088F, "Discs Of Tron"),
-    (0x58410890, "Rocky and Bullwinkle"),
-    (0x58410894, "XEVIOUS"),
-    (0x58410895, "Screwjumper!"),
-    (0x58410896, "Puzzle Quest"),
-    (0x58410898, "Military Madness"),
-    (0x58410899, "Domino Master"),
-    (0x5841089A, "UNO RUSH"),
-    (0x5841089B, "CrazyMouse"),
-    (0x5841089C, "Poker Smash"),
-    (0x5841089D, "Castlevania: Symphony of the Night (悪魔城ドラキュラX 月下の夜想曲)"),
-    (0x5841089E, "GripShift"),
-    (0x5841089F, "SpongeBob UnderPants!"),
-    (0x584108A0, "Puzzle Arcade"),
-    (0x584108A1, "Golf: Tee It Up!"),
-    (0x584108A4, "Super Street Fighter 2 Turbo HD Remix"),
-    (0x584108A5, "Omega Five"),
-    (0x584108A6, "Wits & Wagers"),
-    (0x584108A7, "EXIT"),
-    (0x584108A8, "Vigilante 8 Arcade"),
-    (0x584108A9, "GoldenEye 007 Remastered"),
-    (0x584108AA, "1942: Joint Strike"),
-    (0x584108AB, "WOTB: Commando 3"),
-    (0x584108AC, "Happy Tree Friends"),
-    (0x584108AD, "R-Type Dimensions"),
-    (0x584108AE, "Brain Challenge"),
-    (0x584108B3, "Rez HD"),
-    (0x584108B4, "MLB Stickball"),
-    (0x584108B5, "Diner Dash"),
-    (0x584108B7, "Castle Crashers"),
-    (0x584108B8, "Go! Go! Break Steady"),
-    (0x584108BB, "Galaga Legions"),
-    (0x584108BC, "Mr. DRILLER Online"),
-    (0x584108BE, "Schizoid"),
-    (0x584108C2, "The Maw"),
-    (0x584108C3, "Assault Heroes 2"),
-    (0x584108C7, "Shrek-n-Roll"),
-    (0x584108C8, "Quake Arena Arcade"),
-    (0x584108CB, "Defense Grid"),
-    (0x584108CC, "Rocket Riot"),
-    (0x584108CE, "Braid"),
-    (0x584108CF, "Metal Slug 3"),
-    (0x584108D0, "Samurai Shodown II"),
-    (0x584108D1, "KOF98UM"),
-    (0x584108D3, "Boogie Bunnies"),
-    (0x584108D8, "Zombie Wranglers"),
-    (0x584108D9, "TiQal"),
-    (0x584108DB, "A Kingdom for Keflings"),
-    (0x584108DC, "Minesweeper Flags"),
-    (0x584108DD, "Pirates vs Ninjas Dball"),
-    (0x584108EC, "Ticket to Ride"),
-    (0x584108EF, "Double D Dodgeball"),
-    (0x584108F0, "Age of Booty"),
-    (0x584108F1, "BionicCommando:Rearmed"),
-    (0x584108F3, "Roogoo"),
-    (0x584108F6, "Shadow Complex"),
-    (0x584108F9, "Sealife Safari"),
-    (0x584108FB, "Lost Cities"),
-    (0x584108FF, "Geometry Wars Evolved²"),
-    (0x58410900, "5th Grader"),
-    (0x58410901, "Duke Nukem 3D"),
-    (0x58410902, "The Dishwasher"),
-    (0x58410904, "SOULCALIBUR"),
-    (0x58410907, "Encleverment Experiment"),
-    (0x58410908, "Gel"),
-    (0x58410909, "Blazing Birds"),
-    (0x5841090B, "Dash of Destruction"),
-    (0x5841090D, "Penny Arcade Episode 1"),
-    (0x5841090F, "Gin Rummy"),
-    (0x58410910, "Death Tank"),
-    (0x58410912, "Worms 2: Armageddon"),
-    (0x5841091B, "Puzzle Quest Galactrix"),
-    (0x5841091C, "METEOS WARS"),
-    (0x5841091D, "Fable II Pub Games"),
-    (0x5841091E, "Buku数字パズル"),
-    (0x58410920, "Raskulls"),
-    (0x58410921, "CellFactor: PW"),
-    (0x58410922, "Shadow Assault/Tenchu"),
-    (0x58410927, "Penny Arcade Episode 2"),
-    (0x5841092A, "MEGA MAN 9"),
-    (0x5841092B, "Merv Griffin's Xwords"),
-    (0x5841092D, "Axel & Pixel"),
-    (0x5841092E, "Yo-Ho Kablammo"),
-    (0x5841092F, "Tour de France 2009"),
-    (0x58410930, "The Warriors: SB"),
-    (0x58410931, "South Park"),
-    (0x58410932, "FunTown Mahjong"),
-    (0x58410934, "PowerUp Forever"),
-    (0x58410939, "FLOCK!"),
-    (0x5841093A, "Live Draft Tracker"),
-    (0x5841093B, "Live Score Tracker"),
-    (0x5841093C, "Wallace & Gromit #1"),
-    (0x58410952, "Puzzle Chronicles"),
-    (0x58410954, "Banjo-Kazooie"),
-    (0x58410955, "Banjo-Tooie"),
-    (0x58410956, "Tower Bloxx Deluxe"),
-    (0x58410957, "Call of Duty Classic"),
-    (0x5841095A, "Trials HD"),
-    (0x5841095E, "Ancients of Ooga"),
-    (0x5841095F, "Lazy Raiders"),
-    (0x58410960, "Portal: Still Alive"),
-    (0x58410961, "ZOMBIE APOCALYPSE"),
-    (0x58410964, "Alien Breed Episode 1"),
-    (0x58410968, "OutRun Online Arcade"),
-    (0x5841096A, "Hydro Thunder"),
-    (0x5841096B, "Ion Assault"),
-    (0x5841096D, "Texas Cheat'em"),
-    (0x5841096E, "WATCHMEN"),
-    (0x5841096F, "Space Ark"),
-    (0x58410971, "GAROU Mark of the Wolves"),
-    (0x58410972, "Metal Slug XX"),
-    (0x58410974, "Vandal Hearts: FoJ"),
-    (0x58410975, "3 on 3 NHL  Arcade"),
-    (0x58410976, "Marvel vs. Capcom 2"),
-    (0x58410977, "Interpol"),
-    (0x58410979, "Wallace & Gromit #2"),
-    (0x5841097A, "Wallace & Gromit #3"),
-    (0x5841097B, "Wallace & Gromit #4"),
-    (0x5841097D, "Sam & Max Save the World"),
-    (0x5841097E, "Battlefield 1943"),
-    (0x58410981, "Family Game Night"),
-    (0x58410982, "Invincible Tiger"),
-    (0x58410983, "Crimson Alliance"),
-    (0x58410985, "Virtual-On OT"),
-    (0x58410987, "Scrap Metal"),
-    (0x58410989, "CRYSTAL DEFENDERS"),
-    (0x5841098B, "Yosumin! LIVE"),
-    (0x5841098D, "Full House Poker"),
-    (0x5841098E, "Star Trek: D A C"),
-    (0x5841098F, "Splosion Man"),
-    (0x58410990, "EXIT2"),
-    (0x58410991, "ARKANOID Live!"),
-    (0x58410994, "KrissX"),
-    (0x58410995, "Monkey Island: SE"),
-    (0x58410996, "Space Invaders Extreme"),
-    (0x58410998, "NCAA Basketball MME"),
-    (0x58410999, "Sam & Max Beyond Time and Space"),
-    (0x5841099E, "Toy Soldiers"),
-    (0x5841099F, "The Path of Go"),
-    (0x584109A0, "WATCHMEN PART 2"),
-    (0x584109A1, "Comix Zone"),
-    (0x584109A2, "Altered Beast"),
-    (0x584109A3, "Sonic The Hedgehog 3"),
-    (0x584109A4, "Shinobi"),
-    (0x584109A5, "Phantasy Star II"),
-    (0x584109A6, "Gunstar Heroes"),
-    (0x584109A7, "Wolfenstein 3D"),
-    (0x584109A8, "0D Beat Drop"),
-    (0x584109AA, "Islands of Wakfu "),
-    (0x584109AC, "The King of Fighters 2002"),
-    (0x584109AD, "KOF2002UM"),
-    (0x584109AE, "KOF SKY STAGE"),
-    (0x584109B0, "BUBBLE BOBBLE Neo!"),
-    (0x584109B1, "Football Genius"),
-    (0x584109B2, "Bomberman Battlefest"),
-    (0x584109B3, "Bloody Good Time"),
-    (0x584109B4, "Panzer General"),
-    (0x584109B5, "Snoopy Flying Ace"),
-    (0x584109B6, "DOOM II"),
-    (0x584109B7, "0 day Attack on Earth"),
-    (0x584109B8, "TROUBLE WITCHES NEO"),
-    (0x584109BB, "Droplitz"),
-    (0x584109BC, "RAYSTORM HD"),
-    (0x584109BE, "Spelunky"),
-    (0x584109BF, "Commander's Challenge"),
-    (0x584109C0, "Fez"),
-    (0x584109C1, "PUZZLE BOBBLE Live!"),
-    (0x584109C2, "Perfect Dark"),
-    (0x584109C3, "RAINBOW ISLANDS: T.A."),
-    (0x584109C4, "Sonic & Knuckles"),
-    (0x584109C5, "Aqua"),
-    (0x584109C6, "Gyromancer"),
-    (0x584109C7, "DEATH BY CUBE"),
-    (0x584109C8, "Madballs Babo:Invasion"),
-    (0x584109C9, "Matt Hazard: BBB"),
-    (0x584109CB, "Wolfenstein 3D"),
-    (0x584109CF, "Inferno Pool"),
-    (0x584109D1, "LIMBO"),
-    (0x584109D2, "Turtles In Time RS"),
-    (0x584109D4, "NBA UNRIVALED"),
-    (0x584109D5, "Puzzlegeddon"),
-    (0x584109D6, "Fret Nice"),
-    (0x584109D8, "NBA 2K10 Draft Combine"),
-    (0x584109D9, "Winterbottom"),
-    (0x584109DA, "Serious Sam HD: TFE"),
-    (0x584109DB, "A World of Keflings"),
-    (0x584109DC, "Zeno Clash UE"),
-    (0x584109DD, "Final Fight: DblImpact"),
-    (0x584109DE, "BC - Rearmed 2"),
-    (0x584109E2, "Earthworm Jim HD"),
-    (0x584109E3, "Lucidity"),
-    (0x584109E4, "QIX++"),
-    (0x584109E9, "Hybrid"),
-    (0x584109EA, "Ben 10 The Rise of Hex"),
-    (0x584109EC, "Polar Panic"),
-    (0x584109ED, "Madden NFL Arcade"),
-    (0x584109EE, "MEGA MAN 10"),
-    (0x584109EF, "Hydrophobia"),
-    (0x584109FF, "Plants vs. Zombies"),
-    (0x58410A02, "Rocket Knight"),
-    (0x58410A03, "Yu-Gi-Oh! 5D's Decade Duels Plus"),
-    (0x58410A05, "Outland"),
-    (0x58410A06, "After Burner Climax"),
-    (0x58410A07, "Sonic The Hedgehog 4 Episode I"),
-    (0x58410A0F, "Days of Thunder: Arcade"),
-    (0x58410A11, "Unbound Saga"),
-    (0x58410A13, "RISK Factions"),
-    (0x58410A16, "Chime"),
-    (0x58410A17, "Rush€N Attack: Ex-Patriot"),
-    (0x58410A18, "Hard Corps: Uprising"),
-    (0x58410A19, "Might & Magic Clash of Heroes"),
-    (0x58410A1B, "ilomilo"),
-    (0x58410A1D, "Hole In The Wall"),
-    (0x58410A1F, "NEOGEO BATTLE COLISEUM"),
-    (0x58410A20, "Comic Jumper"),
-    (0x58410A21, "The Dishwasher: VS"),
-    (0x58410A22, "Alien Breed 2: Assault"),
-    (0x58410A23, "Pinball FX2"),
-    (0x58410A24, "Greed Corp"),
-    (0x58410A25, "Sonic Adventure"),
-    (0x58410A2C, "SCOTT PILGRIM THE GAME"),
-    (0x58410A2D, "MOON DIVER"),
-    (0x58410A30, "BattleBlock Theater"),
-    (0x58410A34, "Crazy Taxi"),
-    (0x58410A36, "Things on Wheels"),
-    (0x58410A37, "Duke Nukem - Manhattan"),
-    (0x58410A38, "Dance! It's your Stage"),
-    (0x58410A39, "Space Channel 5 Part 2"),
-    (0x58410A3B, "3D Ultra MiniGolf 2"),
-    (0x58410A3C, "Puzzle Quest 2"),
-    (0x58410A3D, "Monday Night Combat"),
-    (0x58410A3E, "Dream Chronicles"),
-    (0x58410A4D, "Space Invaders: IG"),
-    (0x58410A52, "Voodoo Dice"),
-    (0x58410A53, "Monkey Island 2: SE"),
-    (0x58410A54, "Radiant Silvergun"),
-    (0x58410A57, "Bejeweled Blitz LIVE"),
-    (0x58410A58, "TNT Racers"),
-    (0x58410A59, "Lara Croft: GoL"),
-    (0x58410A5A, "Super Meat Boy"),
-    (0x58410A5B, "Blacklight: Tango Down"),
-    (0x58410A5C, "Adventures of Shuggy"),
-    (0x58410A5D, "Destination: Arcade"),
-    (0x58410A60, "Tecmo Bowl Throwback"),
-    (0x58410A62, "Shank"),
-    (0x58410A65, "Gotham City Impostors"),
-    (0x58410A67, "Dust: An Elysian Tail"),
-    (0x58410A68, "Breach"),
-    (0x58410A69, "Deadliest Warrior"),
-    (0x58410A6B, "Fusion: Genesis"),
-    (0x58410A6C, "Red Faction: Battlegrounds "),
-    (0x58410A6F, "DeathSpank"),
-    (0x58410A70, "Harms Way"),
-    (0x58410A71, "Doritos Crash Course"),
-    (0x58410A72, "Serious Sam HD: TSE"),
-    (0x58410A73, "Stacking"),
-    (0x58410A74, "Double Dragon II"),
-    (0x58410A75, "Rotastic"),
-    (0x58410A77, "Faery: Legends of Avalon"),
-    (0x58410A78, "Guwange"),
-    (0x58410A79, "Costume Quest"),
-    (0x58410A7A, "Castlevania: Harmony of Despair"),
-    (0x58410A7B, "Dead Space Ignition"),
-    (0x58410A7C, "Spare Parts"),
-    (0x58410A7E, "Torchlight"),
-    (0x58410A82, "Fire Pro Wrestling"),
-    (0x58410A83, "SEGA Bass Fishing"),
-    (0x58410A86, "Blade Kitten"),
-    (0x58410A87, "The UnderGarden"),
-    (0x58410A88, "Haunted House"),
-    (0x58410A89, "Ghostbusters: Sanctum of Slime"),
-    (0x58410A8B, "DeathSpank: T.O.V."),
-    (0x58410A8C, "PAC-MAN CE DX+"),
-    (0x58410A8D, "Dead Rising 2: Case Zero"),
-    (0x58410A91, "From Dust"),
-    (0x58410A95, "Iron Brigade"),
-    (0x58410A96, "Aero-Cross - Namco Generations"),
-    (0x58410A9A, "Rock of Ages"),
-    (0x58410A9B, "Trine 2"),
-    (0x58410A9C, "MicroBot"),
-    (0x58410A9F, "The Fancy Pants Adventures"),
-    (0x58410AA1, "Yar's Revenge"),
-    (0x58410AA2, "Dungeons & Dragons Daggerdale"),
-    (0x58410AA3, "Alien Breed 3: Descent"),
-    (0x58410AA5, "Strania"),
-    (0x58410AA9, "Zeit²"),
-    (0x58410AAC, "Toy Soldiers: Cold War"),
-    (0x58410AAD, "Trials Evolution"),
-    (0x58410ABE, "Renegade Ops"),
-    (0x58410AC2, "SEGA Rally Online Arcade"),
-    (0x58410ACB, "BloodRayne: Betrayal"),
-    (0x58410AE9, "Happy Wars"),
-    (0x58410AF1, "Boulder Dash-XL"),
-    (0x58410AF2, "Bangai-O HD"),
-    (0x58410AF4, "Xotic"),
-    (0x58410AF7, "Crazy Machines Elements"),
-    (0x58410AF9, "Galaga Legions DX"),
-    (0x58410AFA, "Beyond Good & Evil HD"),
-    (0x58410AFD, "Worms: Ultimate Mayhem"),
-    (0x58410B00, "Dead Rising 2: Case West"),
-    (0x58410B07, "Swarm"),
-    (0x58410B0B, "Wrecked Revenge Revisited"),
-    (0x58410B0F, "Motocross Madness"),
-    (0x58410B10, "Red Bull X-Fighters"),
-    (0x58410B11, "X-MEN"),
-    (0x58410B14, "Crimson Dragon"),
-    (0x58410B16, "Street Fighter III: Online Edition"),
-    (0x58410B19, "Ms. Splosion Man"),
-    (0x58410B1C, "Insanely Twisted Shadow Planet"),
-    (0x58410B1D, "DAYTONA USA"),
-    (0x58410B22, "Alien Rage"),
-    (0x58410B25, "NIN2-JUMP"),
-    (0x58410B27, "Warlords"),
-    (0x58410B2B, "GUARDIAN HEROES (TM)"),
-    (0x58410B2C, "Lucha Fury"),
-    (0x58410B32, "Gatling Gears"),
-    (0x58410B34, "Haunt"),
-    (0x58410B37, "Defenders of Ardania"),
-    (0x58410B39, "Backbreaker Vengeance"),
-    (0x58410B3A, "I Am Alive"),
-    (0x58410B3C, "Diabolical Pitch"),
-    (0x58410B3D, "HALF-MINUTE HERO -Super Mega Neo Climax-"),
-    (0x58410B3E, "Star Raiders"),
-    (0x58410B3F, "Magic 2012"),
-    (0x58410B41, "Zombie Driver HD"),
-    (0x58410B42, "Battle: Los Angeles"),
-    (0x58410B45, "Section 8: Prejudice"),
-    (0x58410B47, "Apples to Apples"),
-    (0x58410B48, "Dead Block"),
-    (0x58410B49, "SkyDrift"),
-    (0x58410B4A, "Bang Bang Racing"),
-    (0x58410B51, "Mercury HG"),
-    (0x58410B52, "Warhammer 40,000: Kill Team"),
-    (0x58410B55, "Who Wants To Be A Millionaire? Special Editions"),
-    (0x58410B5A, "Awesomenauts"),
-    (0x58410B5C, "Deadliest Warrior: Legends"),
-    (0x58410B5D, "Burnout Crash!"),
-    (0x58410B62, "Mortal Kombat Arcade"),
-    (0x58410B63, "Ugly Americans: Apocalypsegeddon"),
-    (0x58410B64, "The War of the Worlds"),
-    (0x58410B65, "Ascend: Hand of Kul"),
-    (0x58410B66, "Bastion"),
-    (0x58410B6C, "Kung Fu Strike"),
-    (0x58410B6D, "Dungeon Defenders"),
-    (0x58410B6F, "Sega Vintage Collection: Golden Axe"),
-    (0x58410B70, "Sega Vintage Collection: Alex Kidd & Co."),
-    (0x58410B71, "Sega Vintage Collection: Streets of Rage"),
-    (0x58410B72, "Sega Vintage Collection: Monster World"),
-    (0x58410B76, "Zombie Apocalypse: Never Die Alone"),
-    (0x58410B79, "Fruit Ninja Kinect"),
-    (0x58410B7A, "Bloodforge"),
-    (0x58410B7B, "MLB Bobblehead Pros"),
-    (0x58410B7C, "MLB Bobblehead Battle"),
-    (0x58410B7F, "Dungeon Fighter LIVE: Fall Of Hendon Myre"),
-    (0x58410B80, "South Park: Tenorman's Revenge"),
-    (0x58410B82, "Nexuiz"),
-    (0x58410B83, "Charlie Murder"),
-    (0x58410B86, "Fable Heroes"),
-    (0x58410B87, "Saint's Row Moneyshot"),
-    (0x58410B88, "Scene It? Movie Night"),
-    (0x58410B8A, "Dragon's Lair"),
-    (0x58410B8B, "Mercury Hg"),
-    (0x58410B8C, "Wreckateer"),
-    (0x58410B8D, "Deadlight"),
-    (0x58410B8F, "Voltron"),
-    (0x58410B90, "BurgerTime World Tour"),
-    (0x58410B92, "HELL YEAH! Wrath of the Dead Rabbit"),
-    (0x58410B95, "Babel Rising"),
-    (0x584111D5, "Leedmees"),
-    (0x584111D6, "Bejeweled 3"),
-    (0x584111D8, "Skulls of the Shogun"),
-    (0x584111D9, "Puddle"),
-    (0x584111DA, "Jeremy McGrath's Offroad"),
-    (0x584111DB, "Skullgirls"),
-    (0x584111DC, "Warp"),
-    (0x584111DD, "Guilty Gear XX A Core Plus"),
-    (0x584111DE, "The Walking Dead"),
-    (0x584111E0, "REAL STEEL"),
-    (0x584111E1, "The Splatters"),
-    (0x584111E2, "Deep Black - Ep. 1"),
-    (0x584111E5, "DeathSpank: The Baconing"),
-    (0x584111E8, "State of Decay"),
-    (0x584111E9, "Zuma's Revenge!"),
-    (0x584111EA, "Quarrel"),
-    (0x584111EB, "Orcs Must Die!"),
-    (0x584111EC, "NBA JAM: On Fire Edition"),
-    (0x584111EE, "RAW"),
-    (0x584111F1, "Sonic CD"),
-    (0x584111F2, "Sonic The Hedgehog 4 Episode II"),
-    (0x584111F3, "Double Fine Happy Action Theater"),
-    (0x584111F5, "Joe Danger Special Edition"),
-    (0x584111F6, "Joe Danger 2: The Movie"),
-    (0x584111F7, "Minecraft: Xbox 360 Edition"),
-    (0x584111F9, "Frogger: Hyper Arcade Edition"),
-    (0x584111FA, "The Simpsons Arcade Game"),
-    (0x584111FB, "Shoot Many Robots"),
-    (0x584111FE, "Virtua Fighter 5 Final Showdown"),
-    (0x584111FF, "Quantum Conundrum"),
-    (0x58411201, "Rhythm Party"),
-    (0x58411202, "Sonic Adventure 2"),
-    (0x58411203, "Bellator: MMA Onslaught"),
-    (0x58411204, "All Zombies Must Die!"),
-    (0x5841120C, "Shank2"),
-    (0x58411212, "Scourge: Outbreak"),
-    (0x58411213, "Magic 2013"),
-    (0x58411216, "The Wolf Among Us"),
-    (0x58411219, "Anomaly Warzone Earth"),
-    (0x5841121C, "World Gone Sour"),
-    (0x5841121D, "Pinball Arcade"),
-    (0x58411220, "AMY"),
-    (0x58411221, "Runner2"),
-    (0x58411222, "Alien Spidy"),
-    (0x58411223, "Marvel vs. Capcom: Origins"),
-    (0x58411226, "Scarygirl"),
-    (0x58411228, "Mark of the Ninja"),
-    (0x5841122D, "Rock Band Blitz"),
-    (0x5841122F, "Darkstalkers Resurrection"),
-    (0x58411230, "American Mensa Academy"),
-    (0x58411232, "Choplifter HD"),
-    (0x58411233, "Alan Wake"),
-    (0x58411234, "SINE MORA"),
-    (0x58411235, "NFL Blitz"),
-    (0x58411236, "Sacred Citadel"),
-    (0x58411238, "The Cave"),
-    (0x5841123A, "Girl Fight"),
-    (0x5841123B, "Black Knight Sword"),
-    (0x5841123C, "Mini Ninjas Adventures"),
-    (0x5841123E, "Far Cry Classic"),
-    (0x5841123F, "Rayman 3 HD"),
-    (0x58411240, "Mad Riders"),
-    (0x58411245, "Jam Live Music Arcade"),
-    (0x58411247, "Jet Set Radio"),
-    (0x58411249, "Serious Sam Double D XXL"),
-    (0x5841124A, "Tony Hawk's PS HD"),
-    (0x5841124B, "The Expendables 2 Videogame"),
-    (0x5841124C, "Sega Vintage Collection: ToeJam & Earl"),
-    (0x5841124E, "JOJO'S BIZARRE ADVENTURE HD Ver."),
-    (0x5841124F, "Doritos Crash Course 2"),
-    (0x58411250, "Planets Under Attack"),
-    (0x58411252, "Double Dragon Neon"),
-    (0x58411253, "Red Bull Crashed Ice Kinect"),
-    (0x58411256, "Spartacus Legends"),
-    (0x58411259, "CAPCOM ARCADE CABINET"),
-    (0x5841125A, "Counter-Strike: GO"),
-    (0x5841125C, "Kinect Party - Base Game"),
-    (0x5841125D, "Phantom Breaker:Battle Grounds"),
-    (0x5841125E, "Joy Ride Turbo"),
-    (0x58411260, "Red Johnson's Chronicles"),
-    (0x58411265, "Dogfight 1942"),
-    (0x58411266, "Home Run Stars"),
-    (0x58411268, "NiGHTS into dreams..."),
-    (0x58411277, "Zeno Clash 2"),
-    (0x58411278, "Let's Sing And Dance"),
-    (0x58411279, "Defense Technica"),
-    (0x5841127A, "Pool Nation"),
-    (0x5841127D, "Naughty Bear Panic in Paradise"),
-    (0x5841127F, "Serious Sam 3: BFE"),
-    (0x58411282, "Guardians of Middle-earth"),
-    (0x58411283, "Dollar Dash"),
-    (0x58411285, "Retro City Rampage"),
-    (0x58411289, "Pid"),
-    (0x5841128A, "LocoCycle"),
-    (0x5841128C, "Karateka"),
-    (0x5841128D, "Double Dragon II"),
-    (0x5841128F, "Terraria – Xbox 360 Edition"),
-    (0x58411290, "Worms Revolution"),
-    (0x58411291, "Way of the Dogg"),
-    (0x58411293, "Narco Terror"),
-    (0x58411297, "Monaco: What's Yours is Mine"),
-    (0x5841129A, "CastleStorm"),
-    (0x5841129C, "Virtua Fighter 2"),
-    (0x5841129D, "Fighting Vipers"),
-    (0x5841129E, "Sonic the Fighters"),
-    (0x584112A0, "Max: The Curse of Brotherhood"),
-    (0x584112A1, "Ski Race"),
-    (0x584112A5, "Brothers"),
-    (0x584112AC, "Darts Vs Zombies"),
-    (0x584112AD, "STX"),
-    (0x584112AE, "Prize Driver"),
-    (0x584112AF, "10 Frame Bowling"),
-    (0x584112B0, "Call of Juarez Gunslinger"),
-    (0x584112B1, "Field Goal Contest"),
-    (0x584112B2, "Penalty Saver"),
-    (0x584112B3, "Ping Pong"),
-    (0x584112B4, "Boxing Fight"),
-    (0x584112B5, "Reaction Rally"),
-    (0x584112B6, "3 Point Contest"),
-    (0x584112B9, "WSOP: Full House Pro"),
-    (0x584112BB, "Intel Discovered"),
-    (0x584112C1, "Pacific Rim"),
-    (0x584112C3, "Blood Knights"),
-    (0x584112C5, "Magic 2014 Duels of the Planeswalkers"),
-    (0x584112C7, "Castle of Illusion Starring Mickey Mouse"),
-    (0x584112C8, "Skydive"),
-    (0x584112CE, "Super Time Force"),
-    (0x58411329, "Sanctum 2"),
-    (0x584113BC, "Magrunner: Dark Pulse"),
-    (0x584113BD, "Dustforce"),
-    (0x584113BF, "Far Cry 3 Blood Dragon"),
-    (0x584113C0, "Mars: War Logs"),
-    (0x584113C2, "TMNT: OOTS"),
-    (0x584113C3, "Dungeons & Dragons: Chronicles of Mystara"),
-    (0x584113C4, "Orc Attack"),
-    (0x584113C5, "Virtual-on"),
-    (0x584113C6, "DuckTales: Remastered (XBLA)"),
-    (0x584113C7, "STRIDER"),
-    (0x584113C8, "Cloudberry Kingdom"),
-    (0x584113C9, "Hunter's Trophy 2 - America"),
-    (0x584113CA, "Hunter's Trophy 2 - Australia"),
-    (0x584113CD, "Virtua Striker"),
-    (0x584113CF, "God Mode"),
-    (0x584113D1, "WRC Powerslide"),
-    (0x584113D6, "Giana Sisters: Twisted Dreams"),
-    (0x584113DB, "Marvel Puzzle Quest"),
-    (0x584113DC, "Anna - Extended Edition"),
-    (0x584113DD, "Poker Night 2"),
-    (0x584113E3, "Who Wants To Be A Millionaire? "),
-    (0x584113E7, "Takedown:  Red Sabre"),
-    (0x584113E8, "How to Survive"),
-    (0x584113ED, "A.R.E.S."),
-    (0x584113EE, "R.I.P.D.: The Game"),
-    (0x584113F0, "CAPSIZED"),
-    (0x584113F2, "Storm"),
-    (0x584113F3, "Final Exam"),
-    (0x584113F5, "Thunder Wolves"),
-    (0x584113F6, "Doodle Jump for Kinect"),
-    (0x584113F8, "Fireburst"),
-    (0x584113FC, "Assassins Creed Liberation HD"),
-    (0x584113FE, "Flashback"),
-    (0x58411401, "Trials Fusion"),
-    (0x58411402, "HEXODIUS"),
-    (0x58411403, "Marlow Briggs"),
-    (0x58411405, "Syberia"),
-    (0x58411406, "Syberia 2"),
-    (0x58411407, "Freefall Racers"),
-    (0x58411408, "Castlevania: Lords of Shadow - Mirror of Fate HD"),
-    (0x58411409, "Foul Play"),
-    (0x5841140A, "The Raven Episode 1"),
-    (0x5841140D, "Child of Light"),
-    (0x58411410, "PAC-MAN MUSEUM"),
-    (0x58411411, "SOULCALIBUR II HD"),
-    (0x58411412, "Halo: Spartan Assault"),
-    (0x58411413, "The Walking Dead: Season Two"),
-    (0x58411414, "The Bridge"),
-    (0x5841141A, "Contrast"),
-    (0x5841141F, "Magic 2015"),
-    (0x58411420, "Rekoil: Liberator"),
-    (0x58411421, "Constant C"),
-    (0x58411426, "AirMech Arena"),
-    (0x58411427, "Abyss Odyssey"),
-    (0x58411428, "Valiant Hearts"),
-    (0x5841142B, "The Bluecoats - North vs South"),
-    (0x5841142E, "Blood of the Werewolf"),
-    (0x58411430, "Slender: The Arrival"),
-    (0x58411434, "Battle Academy"),
-    (0x58411435, "Putty Squad"),
-    (0x58411436, "Peggle  2"),
-    (0x5841143A, "Tales from the Borderlands"),
-    (0x5841143D, "Mutant Blobs Attack"),
-    (0x5841143E, "Guncraft"),
-    (0x58411441, "Blackgate Deluxe Ed."),
-    (0x58411442, "Yu-Gi-Oh! Millennium Duels"),
-    (0x58411443, "Forgotten Wars"),
-    (0x58411446, "Guacamelee! STCE"),
-    (0x58411447, "The Legend of Korra™"),
-    (0x58411448, "Cobalt "),
-    (0x5841144C, "Resident Evil: Revelations 2"),
-    (0x5841144E, "R.B.I. Baseball 14"),
-    (0x5841144F, "Jet Car Stunts"),
-    (0x58411451, "Chivalry"),
-    (0x58411452, "Costume Quest 2"),
-    (0x58411453, "Geometry Wars™ 3"),
-    (0x58411457, "MONOPOLY PLUS"),
-    (0x58411458, "MONOPOLY DEAL"),
-    (0x58411459, "TMNT Training Lair"),
-    (0x5841145B, "Trivial Pursuit Live!"),
-    (0x5841145E, "Max: The Curse of Brotherhood"),
-    (0x58411460, "RISK"),
-    (0x58411461, "Risk Urban Assault"),
-    (0x58411463, "Toybox Turbos"),
-    (0x5841147C, "Life Is Strange Episode 1"),
-    (0x58411488, "Game of Thrones "),
-    (0x58411489, "JUJU"),
-    (0x5841148E, "Monster Jam Battlegrounds"),
-    (0x58411490, "DW: Battlegrounds"),
-    (0x58411495, "Masquerade"),
-    (0x58411498, "Goat Simulator"),
-    (0x5841149C, "Frozen Free Fall: Snowball Fight"),
-    (0x5841149E, "Minecraft: Story Mode"),
-    (0x5841149F, "Goosebumps: The Game"),
-    (0x584114A4, "Mighty No. 9"),
-    (0x584114AD, "Mmore Goatz Edition"),
-    (0x584114B0, "Prison Architect: Xbox 360 Edition"),
-    (0x584114B2, "The Walking Dead: Michonne - Episode 1"),
-    (0x584114BB, "Batman: The Telltale Series"),
-    (0x584114C6, "Minecraft: Story Mode - Season Two"),
-    (0x584807D5, "Netflix"),
-    (0x58480811, "Kinect Fun Labs"),
-    (0x58480812, "Build A Buddy"),
-    (0x58480813, "Bobble Head"),
-    (0x58480814, "Kinect Me"),
-    (0x58480816, "Kinect Sparkler"),
-    (0x58480817, "Kinect Googly Eyes"),
-    (0x58480818, "Battle Stuff"),
-    (0x5848081A, "Avatar Kinect"),
-    (0x5848081B, "Air Band"),
-    (0x5848081D, "Mutation Station"),
-    (0x5848081E, "Musical Feet"),
-    (0x5848081F, "Junk Fu"),
-    (0x58480831, "I Am Super!"),
-    (0x58480835, "Kinect Rush: Snapshot"),
-    (0x58480836, "Mars Rover Landing"),
-    (0x58490004, "Black Stone"),
-    (0x58490007, "Dredd vs Death"),
-    (0x584A07D1, "1 Vs. 100"),
-    (0x584D07D1, "Avatar Editor"),
-    (0x584E07D1, "XNA Creators Club"),
-    (0x584E07D2, "Community Game"),
-    (0x585207F0, "Destroy All Humans"),
-    (0x585500F4, "Cubage"),
-    (0x585500F9, "Being"),
-    (0x585500FB, "Fuel Depot 360"),
-    (0x585500FD, "Drift"),
-    (0x585500FE, "Lines"),
-    (0x585500FF, "Bloc"),
-    (0x58550102, "Weapon of Choice"),
-    (0x58550103, "Head Banger"),
-    (0x58550105, "DUOtrix"),
-    (0x58550108, "Abstacked"),
-    (0x58550109, "Endless Swarm"),
-    (0x5855010B, "UAV Wars"),
-    (0x5855010E, "Blow"),
-    (0x5855010F, "Totem"),
-    (0x58550110, "Colosseum"),
-    (0x58550112, "Galax-e-mail"),
-    (0x58550113, "In the Pit"),
-    (0x58550117, "Rift"),
-    (0x58550118, "Crystal Crush"),
-    (0x58550119, "No Frills Sudoku"),
-    (0x5855011B, "Snake360"),
-    (0x5855011C, "Easy Golf"),
-    (0x5855011D, "Fatal Abyss"),
-    (0x5855011E, "Audiball"),
-    (0x58550121, "sin(Surfing)"),
-    (0x58550122, "Exisled"),
-    (0x58550123, "Satellites"),
-    (0x58550124, "Garrett the Slug"),
-    (0x58550126, "Arriba!"),
-    (0x58550128, "StarPilot"),
-    (0x58550129, "ZSX4 Guitarpocalypse"),
-    (0x5855012A, "MemoStomp"),
-    (0x5855012C, "Aaron's Ping-Pong"),
-    (0x5855012D, "Pastry Wars"),
-    (0x58550130, "Swords & Monsters"),
-    (0x58550131, "Hive"),
-    (0x58550132, "Monaco 360 - retro racing"),
-    (0x58550133, "Plasma Spheres"),
-    (0x58550135, "Shed"),
-    (0x58550136, "Ladybird Galaxy"),
-    (0x58550137, "Torino-X"),
-    (0x58550138, "RPaints"),
-    (0x5855013C, "Tower Revolution"),
-    (0x5855013D, "Retro One"),
-    (0x58550142, "Light Catcher"),
-    (0x58550145, "Type Attacks"),
-    (0x58550146, "EZMuze Break and House edition"),
-    (0x58550147, "Dr. Popper"),
-    (0x58550148, "Writer's Block"),
-    (0x58550149, "Hexothermic"),
-    (0x5855014C, "Fireplace"),
-    (0x5855014E, "Max the Frog"),
-    (0x58550151, "Block Fight!"),
-    (0x58550152, "Ultratron"),
-    (0x58550153, "Johnny Platform's Biscuit Romp"),
-    (0x58550154, "Shoot the Rocks"),
-    (0x58550156, "Aceball"),
-    (0x58550157, "Indirian Solitaire"),
-    (0x58550158, "The Collector"),
-    (0x58550159, "Machiavelli's Ascent"),
-    (0x5855015B, "Constrictor"),
-    (0x5855015C, "Joint Strike Future"),
-    (0x5855015D, "Solicheck"),
-    (0x5855015F, "Amazing Wizard"),
-    (0x58550163, "Happyface"),
-    (0x58550164, "Landmark"),
-    (0x58550166, "A.I.R. Play"),
-    (0x58550169, "Groov"),
-    (0x5855016B, "Chromatism"),
-    (0x5855016C, "Exhaust"),
-    (0x5855016D, "Poker Squares"),
-    (0x5855016F, "The Perfect Match"),
-    (0x58550170, "Toxic"),
-    (0x58550171, "RC-AirSim"),
-    (0x58550173, "Ocean Scenes"),
-    (0x58550175, "Sol Invasion"),
-    (0x58550176, "Onslaught"),
-    (0x58550177, "BounceCrazy"),
-    (0x58550179, "Rumble Massage"),
-    (0x5855017B, "Don't Fear The Sweeper"),
-    (0x5855017C, "Simply HangMan"),
-    (0x5855017D, "Slime Volleyball, 2.5D"),
-    (0x5855017E, "A Game of Tennis"),
-    (0x5855017F, "Grid Chasers"),
-    (0x58550180, "A Wizard's Odyssey"),
-    (0x58550182, "Miner Dig Deep"),
-    (0x58550183, "Penga's Peril"),
-    (0x58550185, "Poker Stacker"),
-    (0x58550186, "Find Teddy"),
-    (0x58550188, "Memorania"),
-    (0x58550189, "2176 Supernova Storm"),
-    (0x5855018A, "Scrambled Egzz"),
-    (0x5855018B, "BattleSweeper"),
-    (0x5855018C, "Remote Masseuse"),
-    (0x5855018D, "Bowling X"),
-    (0x5855018E, "Barf And Beer"),
-    (0x58550191, "Puzzlix"),
-    (0x58550193, "Micro Invasion"),
-    (0x58550194, "The Gravity Effect"),
-    (0x58550195, "bricks4ever"),
-    (0x58550196, "Lights Off"),
-    (0x58550198, "Zoomaroom"),
-    (0x5855019A, "FreaKick"),
-    (0x5855019B, "2win Ghost"),
-    (0x5855019D, "Trajectory"),
-    (0x5855019F, "Doodleman"),
-    (0x585501A0, "Celebrate"),
-    (0x585501A2, "TV Calibration"),
-    (0x585501A3, "DrumKit"),
-    (0x585501A4, "Aquarium HD"),
-    (0x585501A5, "Planet Crashmania 9,000,000"),
-    (0x585501A8, "Fitba"),
-    (0x585501AA, "Tomato Blaster"),
-    (0x585501AE, "Bored Meeting"),
-    (0x585501B2, "SpaceAttack"),
-    (0x585501BC, "Pegzo"),
-    (0x585501BF, "Tanks.V.S"),
-    (0x585501C1, "Matchem"),
-    (0x585501C4, "Philip Muwanga's Hexy Trench"),
-    (0x585501C6, "RBR - Fading Memories"),
-    (0x585501C7, "The Exterminator"),
-    (0x585501C8, "A Fading Melody"),
-    (0x585501C9, "Solar"),
-    (0x585501CA, "Calculator360"),
-    (0x585501CB, "Battle Havoc"),
-    (0x585501CE, "Dromedary"),
-    (0x585501D1, "Little Racers"),
-    (0x585501D2, "Rocket Fart"),
-    (0x585501D3, "NextWar: The Quest for Earth"),
-    (0x585501D4, "Fish Racer Arcade"),
-    (0x585501D6, "A Bomb's Way"),
-    (0x585501D9, "Halfbrick Blast Off"),
-    (0x585501DA, "Easter Bunneh"),
-    (0x585501DB, "Break In"),
-    (0x585501DD, "MothBallz"),
-    (0x585501DF, "Bullet Trap"),
-    (0x585501E0, "Hexement"),
-    (0x585501E1, "Rabid Gophers"),
-    (0x585501E2, "Aria"),
-    (0x585501E3, "Pwned"),
-    (0x585501E5, "Time and Tune"),
-    (0x585501E6, "Patterns Of Light"),
-    (0x585501E7, "Xeno Arena"),
-    (0x585501E8, "Rotorize"),
-    (0x585501EA, "Ping Time"),
-    (0x585501ED, "Game about a soup factory"),
-    (0x585501EF, "BluePrint Racer 4D"),
-    (0x585501F0, "Retro One - Part 2"),
-    (0x585501F2, "JigDoku"),
-    (0x585501F4, "Time Flows But Does Not Return"),
-    (0x585501F8, "Let's Draw A Picture Together!"),
-    (0x585501F9, "Carrum"),
-    (0x585501FA, "Cocktail Paradise"),
-    (0x585501FB, "Pocoro"),
-    (0x585501FD, "Orbyx - Mystic Orbs of Chaos"),
-    (0x585501FF, "Fridgy"),
-    (0x58550202, "QuadTrix"),
-    (0x58550203, "Ring of Fire"),
-    (0x58550204, "Math Sniper 3D"),
-    (0x58550206, "A Robots Conundrum"),
-    (0x58550207, "Trino"),
-    (0x58550209, "Pellmell"),
-    (0x5855020B, "Iris"),
-    (0x58550210, "Dock'em"),
-    (0x58550211, "GravSheep"),
-    (0x58550213, "StreamLine"),
-    (0x58550214, "SpeedMath"),
-    (0x58550215, "Sqong"),
-    (0x58550219, "A Perfect Massage"),
-    (0x5855021C, "myFishtank"),
-    (0x5855021F, "Prismatic"),
-    (0x58550221, "Jungle Blocks"),
-    (0x58550222, "2060 Cyber Racer"),
-    (0x58550224, "Defender of Zorgaba"),
-    (0x58550227, "Scallywags"),
-    (0x58550229, "Project Alpha"),
-    (0x5855022A, "Constructor360"),
-    (0x5855022C, "TicTacToe"),
-    (0x5855022D, "Heli Good Spelunker"),
-    (0x5855022E, "Spy Chameleon"),
-    (0x58550230, "Puzzwords"),
-    (0x58550231, "Battle Balls"),
-    (0x58550232, "unRevolutionary"),
-    (0x58550233, "Starfighter"),
-    (0x58550235, "The Galactic Aquarium"),
-    (0x58550236, "Revenge of the Evil Aliens"),
-    (0x5855023A, "Plasma TV Break In"),
-    (0x5855023B, "EyeCandy: Chromadose"),
-    (0x5855023D, "Bobby Says - Dance with me!"),
-    (0x5855023E, "Sushi Bumper"),
-    (0x5855023F, "Math Aerobat"),
-    (0x58550244, "System Override"),
-    (0x58550246, "Sky-Defender"),
-    (0x58550247, "Shield Defense"),
-    (0x58550248, "Gravity Bounce"),
-    (0x5855024A, "Meet UR Match"),
-    (0x5855024B, "Shiatsu Massage"),
-    (0x5855024C, "Kodu Game Lab"),
-    (0x5855024E, "World Clock"),
-    (0x5855024F, "RotorMania"),
-    (0x58550255, "Eye-Ball"),
-    (0x58550259, "Get The Ball"),
-    (0x5855025C, "Nebulon"),
-    (0x5855025E, "Fireworks HD"),
-    (0x58550260, "Rock Paper Scissors Xtreme!"),
-    (0x58550262, "Math Gardener"),
-    (0x58550264, "TheAmazingRumblingHypnoDisc"),
-    (0x58550265, "Super Xblox 360!"),
-    (0x58550266, "Choc-a-riffic"),
-    (0x58550267, "LED Display"),
-    (0x58550268, "Vega Magnitude"),
-    (0x58550269, "TabeBALL"),
-    (0x5855026C, "Xtreme Polygon"),
-    (0x58550270, "クレッシェンドシンフォニー"),
-    (0x58550271, "Shopping Crazy"),
-    (0x58550273, "Skwug"),
-    (0x58550275, "Space Math"),
-    (0x58550277, "The Periodic Table"),
-    (0x58550278, "Drop Zone"),
-    (0x5855027A, "Who Did I Date Last Night"),
-    (0x5855027C, "Heavy Payload"),
-    (0x5855027D, "GhastlyGhouls"),
-    (0x58550281, "Reef Rave"),
-    (0x58550282, "The Oracle"),
-    (0x58550284, "Mayan Countdown"),
-    (0x58550285, "尊い犠牲　Ultimate sacrifice"),
-    (0x58550286, "CardArcade"),
-    (0x58550288, "GLITNIR"),
-    (0x5855028A, "Veks and Silence"),
-    (0x5855028B, "Headshot"),
-    (0x5855028C, "Zen Sound"),
-    (0x58550290, "X OVER（クロス・オーバー）"),
-    (0x58550291, "BitStream"),
-    (0x58550292, "MADRISM"),
-    (0x58550293, "Chaos"),
-    (0x58550294, "ULTRA-POWERED MASSAGER!"),
-    (0x58550295, "Zombie Mania"),
-    (0x58550296, "Beer Screensaver"),
-    (0x58550297, "Agent MOO: Maximum Overdeath"),
-    (0x58550298, "PebbleDash"),
-    (0x58550299, "Music Roll"),
-    (0x5855029A, "DESTRUCTION TANK"),
-    (0x5855029B, "360 Mega App Pack HD"),
-    (0x5855029D, "Advanced Rock Paper Scissors"),
-    (0x585502A0, "The Last Cannonball"),
-    (0x585502A2, "Megalopolis"),
-    (0x585502A3, "PENTOMINON"),
-    (0x585502A4, "Wacky Water Fun"),
-    (0x585502A5, "Golden Tangram"),
-    (0x585502A6, "I Made a Game with Zombies in It!"),
-    (0x585502A8, "Power Hour"),
-    (0x585502A9, "The Drinking Game"),
-    (0x585502AA, "Zens 360"),
-    (0x585502AB, "Johnny's Minefield"),
-    (0x585502AE, "GameFinder"),
-    (0x585502B2, "1337 Ship Deathmatch"),
-    (0x585502B3, "Wacky Karts"),
-    (0x585502B5, "Arbor Vitae"),
-    (0x585502B6, "NINJA TRAIN"),
-    (0x585502B7, "A Frog Game"),
-    (0x585502B8, "クレッシェンドシンフォニー 鏡の騎士"),
-    (0x585502BA, "Dodge These Balls"),
-    (0x585502BF, "Shellblast HD"),
-    (0x585502C0, "Puzzle"),
-    (0x585502C3, "Streets of Fury"),
-    (0x585502C6, "Storage Inc"),
-    (0x585502C7, "ダウンタウン激凸ドッジボール！"),
-    (0x585502C8, "Hotel Danger"),
-    (0x585502C9, "BallsAndBlocks"),
-    (0x585502CB, "Arrow Run"),
-    (0x585502CC, "15 Puzzle"),
-    (0x585502CE, "Ninja Guardian"),
-    (0x585502CF, "Colotristar"),
-    (0x585502D0, "Dark Skies: Constellations"),
-    (0x585502D1, "Crystal Spear remake"),
-    (0x585502D4, "Fireworks Spectacular"),
-    (0x585502D5, "Mini Golf 360"),
-    (0x585502D7, "Wizard’s Tower Screen Saver"),
-    (0x585502D8, "Avatar Golf"),
-    (0x585502DA, "Avatar Drop"),
-    (0x585502DE, "Color Break"),
-    (0x585502DF, "Kuchibi"),
-    (0x585502E1, "I Heart Shift"),
-    (0x585502E3, "Gitigiti"),
-    (0x585502E7, "Bailout!"),
-    (0x585502E8, "Pulsar"),
-    (0x585502EB, "Filler"),
-    (0x585502ED, "Words Search"),
-    (0x585502EF, "Crazy Coins"),
-    (0x585502F1, "Hollow Ball"),
-    (0x585502F4, " Sol Survivor"),
-    (0x585502F5, "Fire"),
-    (0x585502F6, "Dead Meat"),
-    (0x585502F8, "Johnny's Skydiving Lessons"),
-    (0x585502FA, "Origin"),
-    (0x585502FB, "PyroManic - Solo"),
-    (0x585502FC, "Fishing Girl"),
-    (0x585502FE, "Pixel Man"),
-    (0x58550304, "VectorForce"),
-    (0x58550305, "Astro Taxi"),
-    (0x58550307, "MoleGame"),
-    (0x58550308, "Jake's Room"),
-    (0x58550309, "Cassie's Corner"),
-    (0x5855030B, "Junkyard Battle"),
-    (0x5855030C, "Lander Mania"),
-    (0x5855030D, "Picture Slider"),
-    (0x5855030E, "The Adhara War"),
-    (0x58550312, "PushCrates"),
-    (0x58550313, "SummerVacation"),
-    (0x58550317, "SpiderEvader"),
-    (0x58550318, "D-Cypher"),
-    (0x58550319, "Alien Pyramid Challenge!"),
-    (0x5855031A, "Tarot"),
-    (0x5855031D, "Pumpkin Carver"),
-    (0x5855031E, "Arkedo Series - 01 JUMP!"),
-    (0x58550322, "NINJA BROS. 忍者ブラザーズ"),
-    (0x58550323, "WhipCrack"),
-    (0x58550326, "Elfland Reloaded Vol. 1"),
-    (0x58550328, "Aimeroids"),
-    (0x58550329, "Dark"),
-    (0x5855032B, "A-Maze!-ING"),
-    (0x5855032C, "Colosseum: Hammerball"),
-    (0x5855032D, "URA KAITEN PATISSIER"),
-    (0x5855032E, "Capture the Oong"),
-    (0x58550330, "Hangman"),
-    (0x58550331, "Movipas"),
-    (0x58550333, "Minigolf Mania"),
-    (0x58550334, "Influence"),
-    (0x58550336, "Echoes+"),
-    (0x58550339, "Astro Match3"),
-    (0x5855033C, "Boring Space Shooter"),
-    (0x5855033F, "Night Of Doom"),
-    (0x58550343, "Carcophony"),
-    (0x58550344, "Poker Blinds"),
-    (0x58550346, "Krisotron"),
-    (0x58550347, "Run Away"),
-    (0x58550348, "Arkedo Series - 02 SWAP!"),
-    (0x58550349, "Amniotic"),
-    (0x5855034D, "GANBARE NATSUKI SAN"),
-    (0x5855034F, "Primary Attack"),
-    (0x58550350, "Pass The Pad!"),
-    (0x58550352, "Guns Loaded"),
-    (0x58550355, "Beat Hazard"),
-    (0x58550356, "T-KARA"),
-    (0x58550358, "SFG Soccer"),
-    (0x5855035A, "They Came From the Ground"),
-    (0x5855035B, "Squid Yes! Not So Octopus!"),
-    (0x5855035D, "Lodestar 1000"),
-    (0x5855035F, "Antigrav Racing Championship"),
-    (0x58550362, "Snaker Ace"),
-    (0x58550363, "Tank-tastic!"),
-    (0x58550364, "The Flashlight"),
-    (0x58550365, "Head Shot 2"),
-    (0x58550367, "Lemonade Ltd."),
-    (0x58550368, "Avatar Rockets"),
-    (0x5855036C, "yOm_fury"),
-    (0x5855036D, "Kissy Poo"),
-    (0x58550370, "Jumper Robot"),
-    (0x58550371, "Crisis Nuclear Holocaust"),
-    (0x58550372, "XMAS Taxi"),
-    (0x58550373, "Aesop's Garden"),
-    (0x58550375, "Wool"),
-    (0x58550376, "The Earth Move With The Moon"),
-    (0x58550377, "Marvelous Galaxy"),
-    (0x58550378, "Aardvark"),
-    (0x58550379, "Neo Terra"),
-    (0x5855037B, "Catch22"),
-    (0x5855037C, "Platypus"),
-    (0x5855037E, "The Impossible Game"),
-    (0x58550382, "City Rain"),
-    (0x58550384, "Jump Duck"),
-    (0x58550385, "ButtonHoldingExtravaganzaParty"),
-    (0x58550387, "Words In A Word"),
-    (0x58550389, "Buttons"),
-    (0x5855038B, "Starbase Epsilon"),
-    (0x5855038D, "Sqong Christmas"),
-    (0x5855038F, "Squirm"),
-    (0x58550390, "Globe Clicker"),
-    (0x58550391, "Boom Chick Chick"),
-    (0x58550392, "Holiday Trivia"),
-    (0x58550393, "Spooky"),
-    (0x58550394, "Snake Maze"),
-    (0x58550395, "Rebound"),
-    (0x58550396, "Avatar Avenue"),
-    (0x58550397, "Pixelpix"),
-    (0x58550398, "Ikaroids"),
-    (0x5855039D, "Dual Zone"),
-    (0x585503A0, "Hydro Electric Worker"),
-    (0x585503A1, "Fractal Dive"),
-    (0x585503A4, "Johnny Platform Saves Xmas!"),
-    (0x585503A5, "Dont B Nervous Talking 2 Girls"),
-    (0x585503A6, "Chris Unarmed"),
-    (0x585503A7, "Christmas Tree 3D"),
-    (0x585503A8, "Runic"),
-    (0x585503A9, "Rate My Avatar"),
-    (0x585503AB, "Molly the Were-Zompire"),
-    (0x585503AF, "Border Wars"),
-    (0x585503B0, "Leave Home"),
-    (0x585503B2, "Soaring Santa"),
-    (0x585503B6, "Dot Error"),
-    (0x585503B7, "The Flight Before Christmas!"),
-    (0x585503B9, "Magnetic Defender"),
-    (0x585503BA, "Pilot Test"),
-    (0x585503BC, "Game Created by a 5th Grader"),
-    (0x585503BE, "PARTYBOAT"),
-    (0x585503C0, "Square Off"),
-    (0x585503C1, "Avatar Wave: Snowball Fight"),
-    (0x585503C2, "Avatar Match"),
-    (0x585503C5, "Painting Party"),
-    (0x585503C6, "Xtreme Polygon 2"),
-    (0x585503C9, "Arkedo Series - 03 PIXEL!"),
-    (0x585503CB, "Zombie Outhouse"),
-    (0x585503CE, "Arcade Snake"),
-    (0x585503D0, "Pixel Whirled"),
-    (0x585503D2, "See The Light"),
-    (0x585503D3, "Chipmunk Christmas"),
-    (0x585503D4, "Planet Pinball"),
-    (0x585503D5, "Avatar Aquarium"),
-    (0x585503D8, "Henway"),
-    (0x585503D9, "Pioneer"),
-    (0x585503DB, "Toybox Racing"),
-    (0x585503DC, "Akujin"),
-    (0x585503DE, "Mech Gladiator"),
-    (0x585503E0, "Speed"),
-    (0x585503E2, "Along Came A Spider"),
-    (0x585503E5, "Full Contact Racing"),
-    (0x585503E9, "Oichokabu Online"),
-    (0x585503ED, "Avatar Run"),
-    (0x585503EE, "Temple Topper"),
-    (0x585503EF, "Jake's Discount Video Game"),
-    (0x585503F0, "RC Racing 360"),
-    (0x585503F1, "Brixx"),
-    (0x585503F5, "Tobe's Vertical Adventure"),
-    (0x585503F6, "Dungeon Adventure"),
-    (0x585503F7, "MAKIVA"),
-    (0x585503F9, "Pwn Age: 31337 AD"),
-    (0x585503FA, "Blaze Of Glory"),
-    (0x585503FB, "SlideColors"),
-    (0x585503FC, "Overscan Sniper HD"),
-    (0x585503FE, "Abaddon"),
-    (0x58550400, "Audio Diagnostics"),
-    (0x58550401, "Gamefarm Math Quiz"),
-    (0x58550405, "You Will Die"),
-    (0x58550406, "ToyBorgs"),
-    (0x5855040A, "Decimation X"),
-    (0x5855040C, "Plucky's 3D Adventure"),
-    (0x5855040D, "Asphalt Jungle"),
-    (0x5855040F, "Rubble Muddle"),
-    (0x58550414, "House Of Cockroach"),
-    (0x58550415, "Bible Navigator X: HCSB"),
-    (0x58550416, "Football League Simulator"),
-    (0x58550419, "Canno-Blast"),
-    (0x5855041E, "You Can't Win!"),
-    (0x58550420, "Warhounds"),
-    (0x58550422, "Cell Life"),
-    (0x58550425, "Lethal Judgment"),
-    (0x58550426, "Soul"),
-    (0x58550427, "A Dreamland Chronicles Game"),
-    (0x58550428, "Avatar Alarm Clock"),
-    (0x5855042A, "Adventures Of Sid"),
-    (0x5855042B, "Glide"),
-    (0x5855042D, "Fire Up"),
-    (0x58550431, "Absorb"),
-    (0x58550433, "Samurai vs Zombie"),
-    (0x58550436, "Earth Shaker"),
-    (0x58550437, "Snake4D"),
-    (0x58550438, "Hang 'em High A Hangman Game"),
-    (0x58550439, "Avatar Meet Up Live!"),
-    (0x5855043A, "Yet Another Zombie Defense"),
-    (0x58550440, "10 Seconds or Less"),
-    (0x58550441, "SHOOTING CHICKEN REVENGE"),
-    (0x58550442, "Impossible Shoota"),
-    (0x58550443, "nega"),
-    (0x58550444, "Kaleidoscope"),
-    (0x58550446, "Asylum"),
-    (0x58550447, "Marauder Madness!"),
-    (0x58550449, "Classic Solitaire"),
-    (0x5855044A, "Curling 2010"),
-    (0x5855044B, "Brethren of the Coast"),
-    (0x5855044F, "Avatar Boogie"),
-    (0x58550450, "Asteroid Capture"),
-    (0x58550451, "As Easy As 123 BlackJack"),
-    (0x58550452, "Avatar Showdown"),
-    (0x58550454, "SPACE RADAR OPERA 360"),
-    (0x58550455, "Shoot 1UP"),
-    (0x58550456, "kukaku"),
-    (0x5855045B, "Autumn"),
-    (0x58550460, "Mega Monster Mania"),
-    (0x58550461, "Globoid"),
-    (0x58550462, "Zatacka 360"),
-    (0x58550464, "Ask Angela!"),
-    (0x58550467, "Toad Man's Bizarre Quest"),
-    (0x58550469, "GoonyCru Day One"),
-    (0x5855046A, "Spot The Difference"),
-    (0x5855046E, "Drooop!!"),
-    (0x58550470, "Word Duelist"),
-    (0x58550472, "Space Nuggets"),
-    (0x58550473, "Cocktails: shake it up!"),
-    (0x58550477, "The Spirit Box"),
-    (0x58550479, "Euphorium"),
-    (0x5855047A, "Dark Seal"),
-    (0x5855047B, "Avatar Street Basketball"),
-    (0x5855047C, "Catch Me If You Can"),
-    (0x5855047E, "Shake!"),
-    (0x5855047F, "Avatar Cannon"),
-    (0x58550480, "Spiders! Why Spiders?"),
-    (0x58550481, "Avatar Bumper Cars"),
-    (0x58550482, "Star Chart"),
-    (0x58550483, "Falling"),
-    (0x58550485, "Stick 'Em Up"),
-    (0x58550486, "Missile Escape"),
-    (0x58550487, "World War Toon"),
-    (0x58550488, "BlindGiRl"),
-    (0x58550489, "Newton's New Tones"),
-    (0x5855048A, "FlipSide"),
-    (0x5855048B, "Flotilla"),
-    (0x5855048C, "Are you fast enough?"),
-    (0x5855048D, "Blackjack Strategy King"),
-    (0x5855048E, "Aliens Go Boom"),
-    (0x5855048F, "Zulu Hour"),
-    (0x58550490, "MechFish"),
-    (0x58550492, "Dangerous Conflicts 3D"),
-    (0x58550497, "Qoccer"),
-    (0x58550499, "Lewjmele -レウメーレ-"),
-    (0x5855049A, "Avatar Hunter"),
-    (0x5855049D, "TECH SHOOTER"),
-    (0x5855049E, "Truth or Treason"),
-    (0x585504A1, "A Game For Alexander"),
-    (0x585504A2, "Tunescape"),
-    (0x585504A4, "Zombie Square"),
-    (0x585504A9, "Project Delta"),
-    (0x585504AA, "Old Space"),
-    (0x585504AC, "Let's Learn Japanese: Beginner"),
-    (0x585504AD, "Blazin' Balls"),
-    (0x585504AE, "Baby Maker Extreme"),
-    (0x585504B1, "Olu"),
-    (0x585504B2, "Massage Me"),
-    (0x585504B3, "Pinky vs Blocks"),
-    (0x585504B4, "3D Infinity"),
-    (0x585504B6, "Avatars, Ghosts'n Zombies"),
-    (0x585504B7, "The Tower: A Bomb's Climb"),
-    (0x585504B8, "Quad Force"),
-    (0x585504B9, "Abduction Action!"),
-    (0x585504BB, "Block Killer"),
-    (0x585504BD, "Breath of Death VII"),
-    (0x585504BF, "YASS"),
-    (0x585504C0, "EXELINYA BURST"),
-    (0x585504C4, "Truth or Dare?"),
-    (0x585504C6, "Bizzy Bee"),
-    (0x585504C7, "Ace Gals Tennis"),
-    (0x585504C9, "Inferno Ball"),
-    (0x585504CA, "Avatar Onslaught"),
-    (0x585504CB, "Singularity (Indie)"),
-    (0x585504CC, "HomeSlice & the Zombie Bunnies"),
-    (0x585504CE, "Space Maze"),
-    (0x585504CF, "Avatar Hamster Panic"),
-    (0x585504D0, "Decay - Part 1"),
-    (0x585504D1, "Super Avatar Hero Force"),
-    (0x585504D2, "Juggler"),
-    (0x585504D3, "Classic Spider"),
-    (0x585504D4, "Avatar Deluxe Blackjack"),
-    (0x585504D6, "Ambient Water"),
-    (0x585504DB, "BattleWorms"),
-    (0x585504DE, "Kapture"),
-    (0x585504DF, "Avatar Dash"),
-    (0x585504E2, "Attack of the Verbose Dinosaur"),
-    (0x585504E4, "The Perfect Pickup Line"),
-    (0x585504E5, "Retrofit - Overload"),
-    (0x585504ED, "HaXor LooZ"),
-    (0x585504F5, "Murphid"),
-    (0x585504F6, "Retent"),
-    (0x585504F7, "Avatar Hangman"),
-    (0x585504FB, "Avatar Racedrome"),
-    (0x585504FD, "Zombie Sniper 3D"),
-    (0x585504FE, "Rock4U"),
-    (0x585504FF, "LOL LOL Kitty Commando"),
-    (0x58550503, "Avatar Ninja!"),
-    (0x58550508, "GET TO THA CHOPPA!!1"),
-    (0x58550509, "Paipa"),
-    (0x5855050B, "Bit Crunch"),
-    (0x5855050C, "Doppelganger"),
-    (0x5855050E, "Amazing 8bit Ball"),
-    (0x5855050F, "Modern Pirate Hunter: Ep 1"),
-    (0x58550510, "Coastal Defence"),
-    (0x58550512, "3 in 1 - game compilation"),
-    (0x58550513, "The MANLY Game for MANLY Men"),
-    (0x58550514, "MoonShine"),
-    (0x58550517, "Nova Lancer"),
-    (0x58550518, "Allegory"),
-    (0x5855051D, "Knock Balls - Spin to Win"),
-    (0x5855051E, "Avatar Calendar"),
-    (0x58550520, "Divining Orb"),
-    (0x58550521, "Dossun Island"),
-    (0x58550523, "Words of Wisdom"),
-    (0x58550524, "Scribble Defense"),
-    (0x58550525, "ElectronZ"),
-    (0x58550526, "Avatar Penalty Kick"),
-    (0x58550527, "Reversi"),
-    (0x5855052A, "Avatars Skate - Old Spice"),
-    (0x5855052B, "Mind's Eye of Jupiter - 02"),
-    (0x5855052C, "Solar Hoops"),
-    (0x5855052D, "Penkin Ball Dodge"),
-    (0x5855052F, "Plasma Spheres 2"),
-    (0x58550530, "Apple Jack"),
-    (0x58550531, "A Game of Hockey"),
-    (0x58550532, "Avatar Paintball"),
-    (0x58550535, "Prismatic Solid"),
-    (0x58550537, "Ice Crush 10.000 B.C."),
-    (0x58550539, "Aah Little Atlantis"),
-    (0x5855053A, "Light Bikes 360"),
-    (0x5855053B, "Blood Tempest"),
-    (0x5855053E, "Avatar Takedown"),
-    (0x5855053F, "SFG Beach Volleyball"),
-    (0x58550540, "Avatar Penalty Shootout"),
-    (0x58550541, "Try Not To Fart"),
-    (0x58550543, "I Suck At: Word Games"),
-    (0x58550544, "That Seagull Stole My Chips"),
-    (0x58550545, "Nice Try"),
-    (0x58550549, "Spell Find"),
-    (0x5855054B, "Zombie Sniper HD"),
-    (0x5855054D, "Legend of the Rune Lords"),
-    (0x5855054E, "PolyWobble"),
-    (0x5855054F, "Block Smash"),
-    (0x58550550, "Bumblicity"),
-    (0x58550552, "Towers"),
-    (0x58550555, "Zombie Hunter"),
-    (0x58550556, "RC Airplane Challenge"),
-    (0x5855055B, "Ultimate Drums"),
-    (0x5855055D, "360 Lines"),
-    (0x5855055E, "Zap Zap: Pew Pew"),
-    (0x5855055F, "Alawishus Pixel"),
-    (0x58550563, "PointMass"),
-    (0x58550564, "Avatar Jump!"),
-    (0x58550565, "A Day at Grandma's House"),
-    (0x58550568, "Orbit One"),
-    (0x58550569, "Impact of Towers"),
-    (0x5855056B, "Hurdle Turtle"),
-    (0x5855056D, "Pellets QuadraStack"),
-    (0x5855056E, "Kong360: Gorilla Warfare"),
-    (0x5855056F, "Ninja Escape"),
-    (0x58550570, "Old School Racer"),
-    (0x58550571, "Strategy Soccer"),
-    (0x58550574, "MathFlash"),
-    (0x58550578, "Combat tanks"),
-    (0x58550579, "Academy Trivia"),
-    (0x5855057A, "Sharpshooter"),
-    (0x5855057B, "Anger Management 4"),
-    (0x5855057C, "Fluidityk"),
-    (0x5855057D, "MonkeyAirBoard"),
-    (0x5855057F, "H0W 2 P1NG A(TrademarkRemoved)"),
-    (0x58550580, "Avatar Insane Race 3D"),
-    (0x58550581, "Avatar Bowman"),
-    (0x58550582, "Avatar Karate"),
-    (0x58550583, "Licky Licky Lizards"),
-    (0x58550585, "The Omniscient Orb"),
-    (0x58550586, "Avatar Scent Wars"),
-    (0x58550587, "Avatars VS Zombies"),
-    (0x58550588, "German 101"),
-    (0x5855058A, "Vampire Rage"),
-    (0x5855058C, "8-bit Girlfriend"),
-    (0x5855058D, "Crazy Wizard in Creepy Castle"),
-    (0x5855058E, "Pwnage of Empires"),
-    (0x58550590, "Packito lost in space"),
-    (0x58550594, "テスターフェイズワン(TESTER PHASE ONE)"),
-    (0x58550595, "Paradox"),
-    (0x58550596, "Nightmare"),
-    (0x58550597, "Avatar... Fly!"),
-    (0x58550599, "Avatar Shatter"),
-    (0x5855059B, "MyBand"),
-    (0x5855059C, "radiangames Crossfire"),
-    (0x5855059D, "Aphelion"),
-    (0x585505A0, "MetaElectric Guitar"),
-    (0x585505A2, "Bombs Away"),
-    (0x585505A3, "Sleepwalker"),
-    (0x585505A4, "UFO Commando"),
-    (0x585505A5, "Decay - Part 2"),
-    (0x585505A6, "7strains : The Infectious"),
-    (0x585505A7, "Lumi"),
-    (0x585505A9, "MusicNow!"),
-    (0x585505AC, "Ballern2D"),
-    (0x585505B1, "Acid Rain"),
-    (0x585505B5, "Ophidian Wars: Opac's Journey"),
-    (0x585505B7, "Avatar High Five"),
-    (0x585505B8, "Super Avatar World"),
-    (0x585505B9, "AvaGlide"),
-    (0x585505BB, "Phat Fly!"),
-    (0x585505BC, "SudokuGeek"),
-    (0x585505BD, "Bubble Buster"),
-    (0x585505C0, "Wordsearch Rush"),
-    (0x585505C1, "Avatar Bowling"),
-    (0x585505C3, "Fly, Butterfly, Fly!"),
-    (0x585505C4, "Operation Old Spice"),
-    (0x585505C5, "Opposites"),
-    (0x585505C6, "Hive Attack"),
-    (0x585505C7, "How Smart Are You? IQ Test"),
-    (0x585505C8, "Runic 3D"),
-    (0x585505C9, "Blox"),
-    (0x585505CA, "Chemical Chaos"),
-    (0x585505CB, "CoreDase"),
-    (0x585505CD, "PLATFORMANCE : Castle Pain"),
-    (0x585505CE, "The war of the end of the days"),
-    (0x585505CF, "SketchBox 360"),
-    (0x585505D0, "Seaside Racing"),
-    (0x585505D3, "Spring Up Harmony"),
-    (0x585505D4, "Basketball Machine"),
-    (0x585505D5, "Cyber Lander: Mission Space"),
-    (0x585505D6, "Rock Blocks"),
-    (0x585505D8, "Avatar Hurdle"),
-    (0x585505DC, "Crazy Ride with Zombies"),
-    (0x585505DE, "Obesity Epidemic"),
-    (0x585505E0, "Space Swine"),
-    (0x585505E2, "Ultimate Electronic Drums"),
-    (0x585505E3, "Slowturn Checker"),
-    (0x585505E9, "Go Avatar Go"),
-    (0x585505EA, "Unplugged"),
-    (0x585505EB, "So Many Girls So Little Time"),
-    (0x585505ED, "Satellite Eagle"),
-    (0x585505EE, "BioHack"),
-    (0x585505F0, "Protect Zoey from the Zombies"),
-    (0x585505F1, "RC Helicopter Sim"),
-    (0x585505F2, "Word Attack Action"),
-    (0x585505F6, "Star Crisis"),
-    (0x585505F9, "Basketball Trick Shot"),
-    (0x585505FA, "Breeze"),
-    (0x585505FB, "MILITARY SNIPER-SIM 3.18"),
-    (0x585505FD, "Meep"),
-    (0x585505FF, "Avatar Zombie Massager Extreme"),
-    (0x58550600, "Spherendipity"),
-    (0x58550601, "Smiley Smashout"),
-    (0x58550602, "Avatar Cash Dash"),
-    (0x58550604, "Akimbo Paddles!"),
-    (0x58550608, "Poker Stacker 2"),
-    (0x58550609, "A Jumper Robot Adventure"),
-    (0x5855060C, "BLASTER AND MORIONブラスターアンドモリオン"),
-    (0x58550611, "Amazing Facts"),
-    (0x58550612, "Monsters (Probably) Stole..."),
-    (0x58550613, "THE ZOMBIE SHOTGUN MASSACRE"),
-    (0x58550615, "Avatar Grand Prix"),
-    (0x58550618, "Avatar Man"),
-    (0x58550619, "Stick Massage"),
-    (0x5855061A, "Balloon Boy"),
-    (0x5855061C, "Bigger And/Or Better"),
-    (0x5855061D, "Gravitron360"),
-    (0x5855061E, "Astro Taxi 2"),
-    (0x58550621, "Hideout!"),
-    (0x58550628, "Fork Lift"),
-    (0x58550629, "Avatar Slam Dunk"),
-    (0x5855062A, "Run Rectangle! Jump! Shoot!"),
-    (0x5855062E, "Drag Impact"),
-    (0x5855062F, "Magnetic Wars"),
-    (0x58550630, "StormGate"),
-    (0x58550631, "Fish Squid Time Machine"),
-    (0x58550632, "Snake Death"),
-    (0x58550634, "Bloop!"),
-    (0x58550635, "Tap Training"),
-    (0x58550636, "Device Hero"),
-    (0x58550637, "Treasure Treasure: FFEE"),
-    (0x58550638, "radiangames Inferno"),
-    (0x58550639, "Ninja VS. Zombie"),
-    (0x5855063B, "Human Invasion"),
-    (0x5855063C, "Jewel Dervish"),
-    (0x5855063D, "Avatar Bowman 2"),
-    (0x5855063F, "Pure Carnage"),
-    (0x58550640, "VEXIS (Buckshot Games)"),
-    (0x58550641, "Juggle!"),
-    (0x58550642, "Divided Combat"),
-    (0x58550648, "Avatar Fact or Fiction"),
-    (0x58550649, "Neighbourhood is GANG INFESTED"),
-    (0x5855064C, "Blockey"),
-    (0x5855064D, "Angry Robot Rampage"),
-    (0x5855064E, "360 Mega Pack HD Remix"),
-    (0x5855064F, "Avatar Crate Crusaders"),
-    (0x58550650, "Abaddon: Retribution"),
-    (0x58550651, "Old School Adventure"),
-    (0x58550653, "Snailien Invasion!"),
-    (0x58550657, "Cattle Crashers"),
-    (0x58550659, "Hypership Out of Control"),
-    (0x5855065A, "Ricochet Assassin"),
-    (0x5855065B, "Avatar Baseball"),
-    (0x5855065C, "FS racing"),
-    (0x5855065D, "Notebook Shooter"),
-    (0x5855065F, "Dossun World"),
-    (0x58550660, "Isagoras"),
-    (0x58550661, "Avatar Puzzle Pop"),
-    (0x58550662, "Jungle (Animal Chess)"),
-    (0x58550663, "Walketh"),
-    (0x58550665, "Didgery"),
-    (0x58550666, "Demo Durb"),
-    (0x58550667, "Aurelia"),
-    (0x58550668, "Ant Mission"),
-    (0x58550669, "Anti-aircraft Warfare"),
-    (0x5855066B, "A shooter"),
-    (0x5855066E, "Starchon"),
-    (0x58550674, "Tank Battles"),
-    (0x58550676, "The Tribes of Ursea"),
-    (0x58550678, "Go"),
-    (0x58550679, "Summer Breeze"),
-    (0x5855067A, "Sniper Defense"),
-    (0x5855067D, "U Want Cookie?"),
-    (0x58550680, "Avatar Kung-fu!"),
-    (0x58550681, "Fruitbash"),
-    (0x58550682, "DeadlySpace"),
-    (0x58550683, "Zombie Academy"),
-    (0x58550684, "Explosionade"),
-    (0x58550685, "EleMental Orbs"),
-    (0x58550686, "Dungeon Tales"),
-    (0x58550687, "Controdazone"),
-    (0x5855068C, "Get Rich or Die Gaming"),
-    (0x5855068D, "Aagh!"),
-    (0x5855068E, "Avatar Massage ONLINE"),
-    (0x5855068F, "Cutouts!"),
-    (0x58550696, "Ava Bash"),
-    (0x58550699, "Lab Rabbit"),
-    (0x5855069A, "Alpha Chimp : Episode 1"),
-    (0x5855069C, "Avatar Laser Tag"),
-    (0x5855069D, "Harmonium"),
-    (0x585506A0, "Avatar Laser Wars"),
-    (0x585506A1, "Tiny Tim's Tremendous Tank"),
-    (0x585506A3, "Ripple"),
-    (0x585506A6, "Mower"),
-    (0x585506A8, "Head Shot Z"),
-    (0x585506A9, "Avatar Mini-Putt Challenge"),
-    (0x585506AA, "Oh Noes!!1"),
-    (0x585506AC, "Chain Reaction"),
-    (0x585506B2, "Avatarmageddon"),
-    (0x585506B3, "Quantum Ninja"),
-    (0x585506B4, "Turret Battlefield"),
-    (0x585506B5, "For Glory"),
-    (0x585506B7, "Seizonrenda"),
-    (0x585506B8, "Dice XY"),
-    (0x585506BA, "Acid Rain Heroes"),
-    (0x585506BC, "Snail Story"),
-    (0x585506BD, "Suuey"),
-    (0x585506BF, "Way of the Exploding Balls"),
-    (0x585506C1, "Garden Gnome Carnage"),
-    (0x585506C2, "The Wizards Who Fell In A Hole"),
-    (0x585506C3, "Gizmo the Puzzle"),
-    (0x585506C4, "Storybook Tactics"),
-    (0x585506C7, "Silent Call"),
-    (0x585506C8, "Elite Pinball HD"),
-    (0x585506CA, "CheneyStar"),
-    (0x585506CD, "Null Divide"),
-    (0x585506CE, "Chopsticks"),
-    (0x585506D0, "The TEMPURA of the DEAD"),
-    (0x585506D2, "ig Pool"),
-    (0x585506D4, "Fast Food"),
-    (0x585506D5, "Game 35"),
-    (0x585506D6, "Furball"),
-    (0x585506D7, "Skully"),
-    (0x585506D8, "Finger Twist-Up"),
-    (0x585506D9, "Finnish"),
-    (0x585506DA, "Chaos Shift"),
-    (0x585506DE, "radiangames Fireball"),
-    (0x585506E0, "Gravi Dot"),
-    (0x585506E1, "THE ZOMBIE SHOTGUN MASSACRE 2"),
-    (0x585506E2, "Pie Collect"),
-    (0x585506E3, "Whakman"),
-    (0x585506E4, "Krazy Alienz"),
-    (0x585506E5, "Angle of Attack"),
-    (0x585506E7, "Commander - World 1"),
-    (0x585506E9, "Aah Impossible Rescue"),
-    (0x585506EA, "Death"),
-    (0x585506EB, "Avatar Banker: Greed is Great"),
-    (0x585506ED, "Dogfight SKETCH!"),
-    (0x585506EE, "Outbreak On Uranus"),
-    (0x585506F1, "Table Soccer X"),
-    (0x585506F3, "A  Game you can't BEAT!!"),
-    (0x585506F4, "Valet Parking Inc."),
-    (0x585506F6, "Sketchy Tower Defense"),
-    (0x585506F7, "The Deep Cave"),
-    (0x585506F8, "Console Info"),
-    (0x585506FA, "Neutrino Wars"),
-    (0x585506FF, "ArrowStorm II"),
-    (0x58550700, "A Snowman's Survival"),
-    (0x58550701, "The Impossible Game Level Pack"),
-    (0x58550702, "AXBY"),
-    (0x58550705, "Decay - Part 3"),
-    (0x58550707, "Alpha Blasters"),
-    (0x58550708, "Snow Day"),
-    (0x58550709, "Break Limit"),
-    (0x5855070B, "Planet Popper"),
-    (0x5855070C, "Retro One - Part 3"),
-    (0x5855070E, "Rumble Checkers Online"),
-    (0x5855070F, "Ubergridder"),
-    (0x58550710, "Zombie Accountant"),
-    (0x58550712, "PentomiGo"),
-    (0x58550717, "Decimation X3"),
-    (0x58550719, "A Madman's Guide To Happiness."),
-    (0x5855071A, "Avatar Boogie 2"),
-    (0x5855071C, "Score Rush"),
-    (0x5855071D, "Chus Dynasty"),
-    (0x5855071E, "Football Games Room"),
-    (0x58550721, "Switch!"),
-    (0x58550722, "Freak Out"),
-    (0x58550723, "Avatar Bump!"),
-    (0x58550725, "Shooting Models"),
-    (0x58550727, "Drum Studio: Platinum Edition"),
-    (0x58550728, "Rainbow Ball into Adventure"),
-    (0x58550729, "Toy Stunt Bike"),
-    (0x5855072A, "Kozu"),
-    (0x5855072B, "8 Ball Pool Champion"),
-    (0x5855072D, "radiangames Crossfire 2"),
-    (0x58550732, "ZP2KX: Zombies & Pterodactyls!"),
-    (0x58550733, "Finish 2: Colors"),
-    (0x58550734, "Aphelion 2"),
-    (0x58550735, "A  Game yOu can't BEAT! 2"),
-    (0x58550737, "Zombiez 8 My Cookiez"),
-    (0x58550738, "Retrovaders"),
-    (0x58550739, "The Earth Machine"),
-    (0x5855073A, "CuBlocks"),
-    (0x5855073E, "GGC X"),
-    (0x58550740, "CyberSnake"),
-    (0x58550744, "Astroman"),
-    (0x58550746, "Xenocide"),
-    (0x58550747, "The Xmas Chainsaw Massacre"),
-    (0x58550748, "Insanity"),
-    (0x5855074A, "Petunk"),
-    (0x5855074B, "Casino Nights"),
-    (0x5855074C, "Bad Golf"),
-    (0x5855074D, "BLOCKPLUS"),
-    (0x5855074E, "Alien Hunter"),
-    (0x5855074F, "REVOLVER360"),
-    (0x58550751, "Mancala"),
-    (0x58550754, "Snake"),
-    (0x58550756, "Cthulhu Saves the World"),
-    (0x58550757, "True Exorcism"),
-    (0x58550758, "Vorpal"),
-    (0x58550759, "New Year Countdown"),
-    (0x5855075A, "Wizard Apprentice"),
-    (0x5855075B, "Avatar Karting"),
-    (0x5855075D, "GrappleBoy"),
-    (0x5855075E, "Lawnmower Vs. Zombies"),
-    (0x5855075F, "Zombie Cow-Milking"),
-    (0x58550760, "Add Some Light"),
-    (0x58550761, "Color"),
-    (0x58550762, "Crazy Ball"),
-    (0x58550763, "SSRGFM"),
-    (0x58550766, "Bounce!"),
-    (0x58550767, "Hyper Button"),
-    (0x58550768, "Pixelbit Snooker & Pool"),
-    (0x5855076A, "2010: A New Threat"),
-    (0x5855076C, "SkullAttack"),
-    (0x5855076D, "Cosmic Cab"),
-    (0x5855076E, "Alpha Squad"),
-    (0x58550770, "Controller Doctor"),
-    (0x58550771, "Onager!"),
-    (0x58550773, "Distant Galaxies"),
-    (0x58550774, "Super Sequence"),
-    (0x58550777, "Block Destruction"),
-    (0x58550778, "Paper Sky"),
-    (0x58550779, "The Charge"),
-    (0x5855077C, "Lethal Judgment Origins"),
-    (0x5855077E, "Beach Bubbles"),
-    (0x58550780, "Aban Hawkins & the 1000 SPIKES"),
-    (0x58550782, "Squid"),
-    (0x58550783, "Story Tale Mania 360"),
-    (0x58550784, "Solar Struggle: Survival"),
-    (0x58550786, "Antipole"),
-    (0x58550788, "Sword and Hammer"),
-    (0x5855078A, "Dirchie Kart"),
-    (0x5855078D, "Jigsaw Guru"),
-    (0x5855078E, "Beer Pong"),
-    (0x58550792, "Snowball Showdown"),
-    (0x58550793, "Sweepy"),
-    (0x58550796, "radiangames Ballistic"),
-    (0x58550797, "Asteroid Sharpshooter"),
-    (0x58550798, "Asteroid Pooper"),
-    (0x5855079B, "Zombie Arena 2"),
-    (0x5855079C, "Bioerosion バイオエロージョン"),
-    (0x5855079D, "Synesthesia Amnesia"),
-    (0x585507A0, "All Out of Bubblegum!"),
-    (0x585507A1, "Gladiators Escapade"),
-    (0x585507A3, "Baby Maker Extreme 2"),
-    (0x585507A4, "Timeslip"),
-    (0x585507A5, "Voice Changer 360"),
-    (0x585507A8, "M2AF: Message 2 All Friends"),
-    (0x585507A9, "Night of Doom 2"),
-    (0x585507AA, "Alien Super Mega Blaster"),
-    (0x585507AB, "FIREBIES, The New Zombies!"),
-    (0x585507AC, "NYAN-TECH"),
-    (0x585507AD, "Avatar Ragdolls"),
-    (0x585507B1, "Bird Assassin"),
-    (0x585507B2, "Zombie Concentration"),
-    (0x585507B6, "Zombie Sausages"),
-    (0x585507B7, "Spermatozoon"),
-    (0x585507B8, "Hypercube Arcade"),
-    (0x585507BF, "Trivia Or Die"),
-    (0x585507C0, "A lot of balls"),
-    (0x585507C1, "Adventures of Rocaman"),
-    (0x585507C4, "Googly-Eyed Splitters"),
-    (0x585507C5, "Avatar Adventurers Online"),
-    (0x585507C8, "Cuckoo Crack"),
-    (0x585507C9, "Orbital Arena"),
-    (0x585507CB, "Hurdle Turtle - Level Pack # 1"),
-    (0x585507CD, "Toy Cars"),
-    (0x585507CE, "Avalis Dungeon"),
-    (0x585507D2, "Super Sequence 2"),
-    (0x585507D3, "Amass and Collapse"),
-    (0x585507D5, "Proximity 2"),
-    (0x585507D6, "Unstoppable Chopper"),
-    (0x585507DA, "Gyroball"),
-    (0x585507DB, "Office Affairs"),
-    (0x585507DC, "Toddler Tantrum!"),
-    (0x585507DD, "iCandy"),
-    (0x585507DF, "Love Hurts"),
-    (0x585507E0, "Snake Man"),
-    (0x585507E3, "Laser Logic"),
-    (0x585507E4, "Are You Smart Enough?"),
-    (0x585507E7, "Guitar Tuner 360"),
-    (0x585507E8, "Solar System 4D"),
-    (0x585507EA, "The Sugar Killerz"),
-    (0x585507EB, "BugO StinkO"),
-    (0x585507F0, "Black Jack Card Counter"),
-    (0x585507F3, "Mute Crimson"),
-    (0x585507F5, "Crisis: North Korea"),
-    (0x585507F6, "Bureau - Agent Kendall"),
-    (0x585507F7, "A tricky game with a cookie"),
-    (0x585507F8, "Hello Rocket"),
-    (0x585507F9, "Cute Pets"),
-    (0x585507FB, "MoonLander"),
-    (0x585507FD, "Bouncing Avatars"),
-    (0x585507FE, "Avatar Fighter"),
-    (0x58550801, "Lazy Gamer"),
-    (0x58550803, "Infinity Danger"),
-    (0x58550805, "Satellite"),
-    (0x58550806, "AardBloxX"),
-    (0x58550809, "CEPINAS"),
-    (0x5855080A, "Can You Handle 2 At Once?"),
-    (0x5855080B, "Oozi: Earth Adventure Ep. 1"),
-    (0x5855080F, "Hoardzz"),
-    (0x58550813, "Insane Bash"),
-    (0x58550816, "Barnstormer"),
-    (0x58550817, "Cell"),
-    (0x58550818, "PewPewPewPewPewPewPewPewPew"),
-    (0x58550819, "Cow Catch"),
-    (0x5855081A, "Origin X"),
-    (0x5855081B, "Monsters Swimming"),
-    (0x5855081D, "Swamp Defense"),
-    (0x58550820, "Sol Intelligence"),
-    (0x58550821, "FortressCraft Chapter 1"),
-    (0x58550822, "The Most Addicting Sheep Game"),
-    (0x58550828, "360 Video Poker"),
-    (0x58550829, "Undead Empire"),
-    (0x5855082B, "Zombie Poker Defense"),
-    (0x5855082D, "Udder Chaos"),
-    (0x5855082F, "Starzzle"),
-    (0x58550830, "Ultraviolet"),
-    (0x58550832, "Farbles"),
-    (0x58550835, "Bombies"),
-    (0x58550837, "Monster Talk"),
-    (0x58550838, "We Come In Peace"),
-    (0x58550839, "Hack This Game"),
-    (0x5855083C, "Neon Prime"),
-    (0x5855083D, "Decay - Part 4 (Final)"),
-    (0x5855083E, "Art of Feedback Circuit"),
-    (0x58550840, "Avatar Workout"),
-    (0x58550841, "Prank Call"),
-    (0x58550842, "Modern Hunt"),
-    (0x58550843, "LaserCat"),
-    (0x58550844, "The Red Ring of Death"),
-    (0x58550848, "AppPack"),
-    (0x58550849, "Balls"),
-    (0x5855084C, "Explosive Gas"),
-    (0x5855084D, "Endless Midnight: Zombie Swarm"),
-    (0x58550850, "Sequence"),
-    (0x58550851, "Music Box"),
-    (0x58550855, "greenTech+"),
-    (0x58550857, "10 Amazingly Awful Games"),
-    (0x58550859, "Urban Micro Racers"),
-    (0x5855085A, "Story Tale Mania Apocalypse"),
-    (0x5855085B, "Blocks That Matter"),
-    (0x5855085F, "Marble Puzzle"),
-    (0x58550862, "Mr. Gravity"),
-    (0x58550864, "Apple Orchard Math"),
-    (0x58550865, "PaperCraft"),
-    (0x58550868, "Nucleon"),
-    (0x5855086A, "BombLocker"),
-    (0x5855086B, "Octave Bar Clock"),
-    (0x5855086C, "Hedge Wizard"),
-    (0x5855086D, "Dragon Forge"),
-    (0x58550872, "Conquest"),
-    (0x58550873, "Avatar's Rock Paper Scissors"),
-    (0x58550874, "Refractor"),
-    (0x58550876, "UpBot Goes Up"),
-    (0x58550877, "7 gunfighters"),
-    (0x58550878, "Plane Traffic"),
-    (0x58550879, "Avatar Legends"),
-    (0x5855087A, "Zombie Sausages 2"),
-    (0x5855087B, "Akane the Kunoichi"),
-    (0x5855087E, "Avatars Don't Bleed"),
-    (0x5855087F, "Shape Shop"),
-    (0x58550880, "Monsters In Neon Space"),
-    (0x58550882, "Avatar Fantasy RPG"),
-    (0x58550883, "Avatar Superstar"),
-    (0x58550884, "Super Sequence 3"),
-    (0x58550888, "Bunker Buster"),
-    (0x5855088B, "Tacticolor"),
-    (0x5855088C, "Abmastrophy"),
-    (0x5855088E, "Pick"),
-    (0x58550890, "Dr Li's Extreme Beam Machine"),
-    (0x58550891, "Avatar Word Wave"),
-    (0x58550892, "Bamdizzle"),
-    (0x58550893, "Techno Kitten Adventure!"),
-    (0x58550894, "QuadSmash"),
-    (0x58550895, "9 Ball Pool Champion"),
-    (0x58550896, "Xtremes vs Zombies"),
-    (0x58550897, "The Great Paper Adventure"),
-    (0x58550899, "Ricochet Assassin Lost Levels"),
-    (0x5855089B, "Righteous Axe"),
-    (0x5855089C, "Topochopper"),
-    (0x5855089D, "Call Me Skyfish"),
-    (0x5855089E, "4-in-1 Cardgame Collection"),
-    (0x585508A0, "Card Creator"),
-    (0x585508A2, "Pixelbit Helicopter Challenge"),
-    (0x585508A4, "Trigger Finger"),
-    (0x585508A5, "Astro Tempest"),
-    (0x585508A7, "Poopocalypse"),
-    (0x585508A9, "Them Blockz"),
-    (0x585508AA, "Lair of the Evildoer"),
-    (0x585508AC, "Paint Ball - Splashes of Fun"),
-    (0x585508AD, "Kung Fu FIGHT!"),
-    (0x585508AE, "Block the Laser"),
-    (0x585508AF, "Fredriksdal Assault"),
-    (0x585508B2, "A Game About My Cat"),
-    (0x585508B3, "Shift It"),
-    (0x585508B4, "Solar 2"),
-    (0x585508B5, "Who is the Best: Math"),
-    (0x585508B6, "Avatar Battle Bees"),
-    (0x585508B7, "PLATFORMANCE: Temple Death"),
-    (0x585508B9, "Case o' Games"),
-    (0x585508BA, "BloodyCheckers"),
-    (0x585508BC, "The Angry Hand of God"),
-    (0x585508BD, "Games Master:Xbox Game Guides"),
-    (0x585508C0, "Send In Jimmy"),
-    (0x585508C2, "Beach Paddle"),
-    (0x585508C3, "Total Miner: Forge"),
-    (0x585508C4, "Avatar Typing: Horde Invasion"),
-    (0x585508C5, "House of 1000 Demons"),
-    (0x585508C6, "Arranger - My Avatar"),
-    (0x585508C9, "Ballbuster"),
-    (0x585508CB, "Gem Breaker Twist"),
-    (0x585508CC, "Avatar Without a Chute"),
-    (0x585508CE, "Stick 'Em Up 2"),
-    (0x585508CF, "Block Jump"),
-    (0x585508D0, "Sniper Gallery"),
-    (0x585508D2, "A Hard Game Without Zombies"),
-    (0x585508D3, "TechAssault"),
-    (0x585508D4, "Monsters Shoot 'n Monsters"),
-    (0x585508D6, "Vizati"),
-    (0x585508D7, "Math Cruncher"),
-    (0x585508D9, "BEYOND"),
-    (0x585508DB, "Zombie Death Car"),
-    (0x585508DC, "Avatar Ninja! 2"),
-    (0x585508E0, "Hack This Game 2"),
-    (0x585508E2, "Block Vengeance Alpha"),
-    (0x585508E3, "URGENT EVASION 360"),
-    (0x585508E4, "TurboRocket"),
-    (0x585508E5, "Wiki Read: Wikipedia for Xbox"),
-    (0x585508E7, "Production Panic"),
-    (0x585508E9, "Aliens VS Aliens"),
-    (0x585508EA, "QuickDraw"),
-    (0x585508EB, "Ace of Dynamites"),
-    (0x585508EF, "Place The Arrows On The Floor"),
-    (0x585508F2, "Ballochet"),
-    (0x585508F4, "Star Ninja"),
-    (0x585508F5, "Sky Cat"),
-    (0x585508F7, "Virtual Attraction - Part 1"),
-    (0x585508F9, "GHXYK2 Classics Vol. 1"),
-    (0x585508FA, "Assembly Line"),
-    (0x585508FB, "Venture"),
-    (0x585508FC, "HexChess 360"),
-    (0x585508FD, "Sketchy Bounce"),
-    (0x585508FF, "Pixel Blocked!"),
-    (0x58550900, "CastleMiner"),
-    (0x58550901, "Phase Runner"),
-    (0x58550902, "Trailer Park King"),
-    (0x58550906, "Who is God"),
-    (0x58550908, "Xeno Waster"),
-    (0x58550909, "Random the Dungeon"),
-    (0x5855090A, "Parasitus: Ninja Zero"),
-    (0x5855090B, "27 Ball Funky Pool"),
-    (0x5855090F, "No Luca No"),
-    (0x58550912, "Zombies ruined my day"),
-    (0x58550914, "Crazy Balloon Lite"),
-    (0x58550915, "WispLisp Array of List"),
-    (0x58550917, "Cycloid"),
-    (0x58550918, "Moon Cheese"),
-    (0x5855091B, "Beware of the Penguins"),
-    (0x5855091C, "Virtual Money Plant"),
-    (0x5855091D, "Andromium"),
-    (0x5855091E, "Blow Me Up"),
-    (0x58550920, "SWASHBUCKEL UR SEATBELTS"),
-    (0x58550921, "Let's Draw & Share!"),
-    (0x58550923, "Inverno!"),
-    (0x58550924, "Pajamorama"),
-    (0x58550925, "GravArena"),
-    (0x58550927, "Nova Remnant"),
-    (0x58550928, "4目並べ3D"),
-    (0x58550929, "Nene minigames"),
-    (0x5855092A, "Coagulate"),
-    (0x5855092B, "Doc Logic"),
-    (0x5855092C, "Attanck!"),
-    (0x5855092E, "Raventhorne"),
-    (0x58550930, "Cursed Loot"),
-    (0x58550931, "Heroes vs. Zombies"),
-    (0x58550932, "Meep 2 - Jumping Evolved"),
-    (0x58550933, "Lucky Game"),
-    (0x58550934, "After Dusk"),
-    (0x58550935, "Steel Avenger"),
-    (0x58550936, "Cute Things Dying Violently"),
-    (0x58550937, "VideoWars"),
-    (0x58550939, "Ambient Travels"),
-    (0x5855093B, "T.E.C 3001"),
-    (0x5855093C, "Cubicle"),
-    (0x5855093F, "Asphalt Jungle 2"),
-    (0x58550940, "Rocks. In. Spaaace!"),
-    (0x58550943, "Guarding"),
-    (0x58550944, "Beach Bubbles 2"),
-    (0x58550945, "Take Arms"),
-    (0x58550946, "All the Bad Parts"),
-    (0x58550947, "Professional $tealer"),
-    (0x58550949, "Zombie Racers"),
-    (0x5855094A, "Avatar Running"),
-    (0x5855094C, "SpeedRunner HD"),
-    (0x5855094E, "Train Frontier Express"),
-    (0x5855094F, "Chain Crusher"),
-    (0x58550950, "Avatar Connection"),
-    (0x58550951, "Chester"),
-    (0x58550952, "Jet Star Universe"),
-    (0x58550957, "Head Shot God"),
-    (0x5855095A, "Redd: The Lost Temple"),
-    (0x5855095B, "Monkey Madness"),
-    (0x5855095D, "King's Cup"),
-    (0x58550960, "HACOTAMA"),
-    (0x58550962, "2D Math Panic"),
-    (0x58550963, "Aron's Journey in Dreamland"),
-    (0x58550964, "Avatar Street Basketball 2"),
-    (0x58550967, "Avalis Dungeon 2"),
-    (0x5855096A, "GOOLIN"),
-    (0x5855096C, "Balls N  Walls"),
-    (0x5855096D, "President John America"),
-    (0x5855096F, "Call Me"),
-    (0x58550970, "Dead Pixels"),
-    (0x58550971, "A Pimp RPG"),
-    (0x58550972, "Angry Fish"),
-    (0x58550973, "Puzzled Rabbit"),
-    (0x58550975, "Avatar Fighter Online"),
-    (0x58550976, "Pigs Can't Fly"),
-    (0x58550979, "The Fall of Gods"),
-    (0x5855097A, "Don't Feed the Trolls"),
-    (0x5855097D, "Devil Blood"),
-    (0x5855097F, "Ranger Wars"),
-    (0x58550980, "Astralia"),
-    (0x58550981, "Wizorb"),
-    (0x58550983, "ZOMBIECRAFTT!!1 SAMPLER"),
-    (0x58550984, "Falling Blocks"),
-    (0x58550985, "Robotriot"),
-    (0x58550986, "FastBall 2"),
-    (0x58550987, "Convict Minigames"),
-    (0x58550989, "Ball Lightning"),
-    (0x5855098A, "Sherbet Thieves"),
-    (0x5855098B, "Pixelosity"),
-    (0x5855098D, "Mega Shooter 11"),
-    (0x58550993, "Flight Adventure 2"),
-    (0x58550995, "Escape The Car"),
-    (0x58550997, "Ninja War STOLEN SCROLLS"),
-    (0x58550999, "Merball Tournament"),
-    (0x5855099C, "Flag Hunt"),
-    (0x5855099E, "SmartBomb"),
-    (0x5855099F, "Avatar Punch"),
-    (0x585509A0, "Techno Chopper"),
-    (0x585509A1, "International Mooing Contest"),
-    (0x585509A2, "Avatar Panic"),
-    (0x585509A3, "Platform Hack"),
-    (0x585509AB, "Milie & Telly"),
-    (0x585509AF, "Four Winds Fantasy"),
-    (0x585509B2, "UnBound"),
-    (0x585509B4, "LightFish"),
-    (0x585509B5, "Castle Of Pixel Skulls"),
-    (0x585509B7, "Avatar Tower"),
-    (0x585509B8, "Paddle Wars"),
-    (0x585509B9, "Miner Man"),
-    (0x585509BB, "Sensory Overload"),
-    (0x585509BC, "Die Pad Die"),
-    (0x585509BD, "Desert Commando"),
-    (0x585509BE, "Prairie Dog Massacre"),
-    (0x585509BF, "4-tet Music Maker"),
-    (0x585509C1, "WARR"),
-    (0x585509C2, "MOTOJUMP"),
-    (0x585509C3, "Avatar Chess"),
-    (0x585509C6, "Moon Miner"),
-    (0x585509C7, "DLC Quest"),
-    (0x585509C8, "Escape Goat"),
-    (0x585509C9, "Bub Block"),
-    (0x585509CA, "CatSaber"),
-    (0x585509CC, "Moe Mekuri 2.5"),
-    (0x585509CD, "World Wars II"),
-    (0x585509CE, "Devilsong"),
-    (0x585509D2, "Pumpkin Smasher"),
-    (0x585509D3, "3D Pixel Art Studio"),
-    (0x585509D4, "RE: Get To Schol On Time"),
-    (0x585509D7, "Bobby"),
-    (0x585509D8, "Iron Core"),
-    (0x585509DA, "Snake 2"),
-    (0x585509DC, "CastleMiner Z"),
-    (0x585509DD, "Growing Pains"),
-    (0x585509E4, "Who's the Daddy"),
-    (0x585509E5, "Red Tie Miner"),
-    (0x585509E6, "3D-struction"),
-    (0x585509E7, "Hidden in Plain Sight"),
-    (0x585509EC, "Star Defender"),
-    (0x585509ED, "Oozi: Earth Adventure Ep. 2"),
-    (0x585509EE, "Temple of Dogolrak"),
-    (0x585509EF, "Ultimate Polygon"),
-    (0x585509F1, "Lolita of Labyrinth"),
-    (0x585509F2, "A Day at Gma's House in Winter"),
-    (0x585509F3, "UK Regions & Counties"),
-    (0x585509F4, "Zombies, Zombies Everywhere!"),
-    (0x585509F6, "TurboRocket 2"),
-    (0x585509F7, "Elfsquad7"),
-    (0x585509F8, "VolChaos"),
-    (0x585509F9, "Orbitron: Revolution"),
-    (0x585509FB, "Orcs!"),
-    (0x585509FC, "Ninja Recapture"),
-    (0x58550A01, "EP1C CAT G4ME FOREV3R !!"),
-    (0x58550A03, "21 Ball Snooker Champion"),
-    (0x58550A05, "Avatar Rumble"),
-    (0x58550A07, "Abzolium"),
-    (0x58550A08, "STG VS"),
-    (0x58550A0A, "Virtual Attraction - Part 2"),
-    (0x58550A0B, "Santa's Xmas Dash"),
-    (0x58550A0C, "Gamerscore Tracker"),
-    (0x58550A0E, "Wabbits! Jumping For Fun"),
-    (0x58550A11, "Cassie's Christmas Magic"),
-    (0x58550A12, "Fun in the Gulf"),
-    (0x58550A13, "Nyan Cat Adventure"),
-    (0x58550A14, "FishPop"),
-    (0x58550A16, "Magic Racing GP 2"),
-    (0x58550A17, "Cassie's Animal Sounds"),
-    (0x58550A18, "I, Zombie"),
-    (0x58550A19, "Extreme Jogging"),
-    (0x58550A1A, "Let's Get Fiscal"),
-    (0x58550A1C, "FireFly Vegas"),
-    (0x58550A1D, "Crazy Particles"),
-    (0x58550A1E, "Attanck!2"),
-    (0x58550A21, "Starship Slots"),
-    (0x58550A22, "Playing in Traffic"),
-    (0x58550A23, "Homeless"),
-    (0x58550A24, "Card Creator Xmas"),
-    (0x58550A25, "Avatar Gold Grab X-mas Version"),
-    (0x58550A27, "Game Type"),
-    (0x58550A28, "Fierce Game Hunting"),
-    (0x58550A2A, "Alien Jelly"),
-    (0x58550A2C, "Merry Match3 Christmas"),
-    (0x58550A2D, "EchoSpace"),
-    (0x58550A31, "Santa Sleigh Sling"),
-    (0x58550A34, "Invasion One"),
-    (0x58550A37, "AppPack 2.0"),
-    (0x58550A38, "Rainbow Rapture"),
-    (0x58550A39, "AvatarBall弾き"),
-    (0x58550A3A, "Avatar Climbing"),
-    (0x58550A3B, "Zombie Snowmen"),
-    (0x58550A3C, "Hack This Game 3"),
-    (0x58550A3E, "Ascension"),
-    (0x58550A3F, "RoomRental"),
-    (0x58550A41, "BlindGiRl2"),
-    (0x58550A42, "Gravity Dash 2"),
-    (0x58550A43, "One Man Army"),
-    (0x58550A45, "Eyestorm"),
-    (0x58550A46, "P3"),
-    (0x58550A47, "Smarter Than A Cheerleader?"),
-    (0x58550A4A, "Trailer Park King Episode 2"),
-    (0x58550A4B, "4P Ping-Pong"),
-    (0x58550A4D, "Dot."),
-    (0x58550A4E, "Octopede"),
-    (0x58550A52, "Fish Game"),
-    (0x58550A54, "Musickness"),
-    (0x58550A56, "Don't Die Dateless, Dummy!"),
-    (0x58550A59, "Block Zombies!"),
-    (0x58550A5A, "Cowbots"),
-    (0x58550A5B, "Perfect Shooting Training Camp"),
-    (0x58550A5C, "Hypership Still Out of Control"),
-    (0x58550A5D, "An Elfy Winter"),
-    (0x58550A5E, "Detect"),
-    (0x58550A5F, "3D Stereo Studio"),
-    (0x58550A60, "StardustHunterPopoy"),
-    (0x58550A61, "Ocean Drive Challenge"),
-    (0x58550A62, "Avatar Grand Prix 2"),
-    (0x58550A63, "Who's the Daddy? 2"),
-    (0x58550A65, "Ninja Sneaking2"),
-    (0x58550A67, "TurboRocket 3"),
-    (0x58550A68, "Little Racers STREET"),
-    (0x58550A6A, "Defy Gravity Extended"),
-    (0x58550A6C, "Diamond Digger"),
-    (0x58550A6D, "SpaceFighter4000 Training"),
-    (0x58550A6F, "Avatar Onslaught 2"),
-    (0x58550A71, "LCD Dungeon System"),
-    (0x58550A76, "Hardboiled Pinball"),
-    (0x58550A77, "Katana Land"),
-    (0x58550A79, "Avatar Block War"),
-    (0x58550A7A, "a Voxel Action"),
-    (0x58550A7C, "Kingdom"),
-    (0x58550A7D, "Marathon Monk"),
-    (0x58550A83, "Atom"),
-    (0x58550A84, "Balloon Guard"),
-    (0x58550A85, "The Never Ending Game 2"),
-    (0x58550A88, "Oozi: Earth Adventure Ep. 3"),
-    (0x58550A89, "FatSheep"),
-    (0x58550A8A, "Alexander Learning Series 360"),
-    (0x58550A8B, "Super Zombie Smash"),
-    (0x58550A8E, "FPS Trainer"),
-    (0x58550A8F, "Toy Stunt Bike 2"),
-    (0x58550A90, "Space Battles"),
-    (0x58550A91, "O.C.D."),
-    (0x58550A93, "Space Command"),
-    (0x58550A95, "Gravitas"),
-    (0x58550A96, "Evan Quest"),
-    (0x58550A9B, "Cro-Mag Rally® Extreme!"),
-    (0x58550A9E, "UBERZOMBIE USA"),
-    (0x58550AA0, "Zombie Invasion"),
-    (0x58550AA2, "Red Tie Miner Zombie"),
-    (0x58550AA3, "zombie crossing"),
-    (0x58550AA5, "Rectangle Battle"),
-    (0x58550AA7, "Transgression"),
-    (0x58550AA9, "Startfighter 2-9"),
-    (0x58550AAA, "Argh Aliens"),
-    (0x58550AAB, "Astral"),
-    (0x58550AAC, "Brittle"),
-    (0x58550AAF, "Bit Digger"),
-    (0x58550AB0, "Attanck!3"),
-    (0x58550AB1, "Redshift"),
-    (0x58550AB2, "Miner4Ever"),
-    (0x58550AB9, "Birth Order"),
-    (0x58550ABA, "Cataboom"),
-    (0x58550ABB, "Avatar Deathmatch"),
-    (0x58550ABE, "Match with Wally"),
-    (0x58550AC1, "Oil Magnate"),
-    (0x58550AC5, "ZombieRun"),
-    (0x58550AC6, "Super Ninja Warrior Extreme"),
-    (0x58550AC8, "Red Hot Poker"),
-    (0x58550AC9, "Picture Puzzle"),
-    (0x58550ACD, "20 Games"),
-    (0x58550ACE, "Hungry Snake"),
-    (0x58550AD1, "Spectrangle360"),
-    (0x58550AD3, "Racing Wings"),
-    (0x58550AD4, "Bit-Hacker"),
-    (0x58550AD6, "Mayfly's Castle"),
-    (0x58550AD9, "Hopper"),
-    (0x58550ADA, "Bytown Lumberjack"),
-    (0x58550ADB, "Scuba Adventure"),
-    (0x58550ADD, "War In Box (HAKONIWASEN)"),
-    (0x58550ADE, "Dyad"),
-    (0x58550ADF, "We Are Cubes"),
-    (0x58550AE0, "Snakez Gunz & Ladderz"),
-    (0x58550AE1, "Red Invasion: Tower Defense"),
-    (0x58550AE3, "Dunjax"),
-    (0x58550AE6, "MegaCity"),
-    (0x58550AE7, "10 Amazingly Awful Games Vol 2"),
-    (0x58550AE8, "Vidiot Game"),
-    (0x58550AE9, "Auxillium Magi"),
-    (0x58550AEA, "48 Chambers"),
-    (0x58550AEB, "Miner Warfare"),
-    (0x58550AEC, "Gems N Rocks"),
-    (0x58550AF0, "Quiet, Please!"),
-    (0x58550AF1, "Avatar Running 2"),
-    (0x58550AF2, "A-Band"),
-    (0x58550AF4, "Fish Bait"),
-    (0x58550AF6, "Ace on Steroids"),
-    (0x58550AF7, "Letter Munch"),
-    (0x58550AF8, "Retribution"),
-    (0x58550AFC, "Cylinder"),
-    (0x58550AFE, "Firing Range 2"),
-    (0x58550AFF, "Insane Zombie Carnage"),
-    (0x58550B01, "Zombie Monkeys TD"),
-    (0x58550B03, "Tiles With Buddies"),
-    (0x58550B04, "Avatar Air Wars"),
-    (0x58550B05, "Plasma Spheres 3"),
-    (0x58550B06, "Gold Miner"),
-    (0x58550B07, "Orbit Craze"),
-    (0x58550B08, "Crystal Hunters"),
-    (0x58550B0A, "Running With Scissors"),
-    (0x58550B0E, "Gingerbread Holiday"),
-    (0x58550B11, "Arena of Fire"),
-    (0x58550B12, "Chains"),
-    (0x58550B14, "Dot Dash: episode 1"),
-    (0x58550B16, "Diamond Caves"),
-    (0x58550B17, "Little Monsters"),
-    (0x58550B19, "Droppin' Ballz"),
-    (0x58550B1A, "SNAKESTER"),
-    (0x58550B1C, "SEAL Team 12"),
-    (0x58550B1E, "Pendulous"),
-    (0x58550B1F, "Tap Blox"),
-    (0x58550B21, "Aron 2: Mysterious Troubles"),
-    (0x58550B22, "Beep Brrp Bing Bang Bosh"),
-    (0x58550B23, "Spitfire"),
-    (0x58550B25, "Story Sender"),
-    (0x58550B27, "ZOMBIES Stole My Liquor!"),
-    (0x58550B28, "5MinRPG"),
-    (0x58550B29, "MiG Madness"),
-    (0x58550B2A, "Avatar Fart!!"),
-    (0x58550B2B, "Chompy Chomp Chomp"),
-    (0x58550B2D, "Compromised"),
-    (0x58550B31, "Revenge of the Sammich"),
-    (0x58550B33, "Avatar Falls Down Stairs"),
-    (0x58550B34, "College Lacrosse 2012"),
-    (0x58550B35, "1on1 Soccer"),
-    (0x58550B36, "UniXNA : Cube"),
-    (0x58550B37, "Rolling Hot Balls"),
-    (0x58550B38, "Plasmania"),
-    (0x58550B39, "Bureau - Shattered Slipper"),
-    (0x58550B3A, "Build 'n Bump"),
-    (0x58550B3B, "Jewel Fever"),
-    (0x58550B3C, "Flying Zombie Invasion"),
-    (0x58550B3D, "Microbial"),
-    (0x58550B3F, "Battlepaths"),
-    (0x58550B42, "Minigame Marathon"),
-    (0x58550B43, "A Pixel Escape"),
-    (0x58550B45, "Perdition"),
-    (0x58550B47, "Herbert and the Skyship"),
-    (0x58550B48, "Jetpack Rapture"),
-    (0x58550B4B, "37 Days to Die"),
-    (0x58550B4D, "Apple Jack 2"),
-    (0x58550B4E, "Miner Of Duty"),
-    (0x58550B53, "Learn Basic French"),
-    (0x58550B54, "Aqualibrium"),
-    (0x58550B55, "Pig & Bullet"),
-    (0x58550B56, "Avatar Random Chat"),
-    (0x58550B57, "Manic Miner 360"),
-    (0x58550B58, "Overdriven"),
-    (0x58550B59, "Alchemy God"),
-    (0x58550B5A, "Space Wars RTS"),
-    (0x58550B5B, "Avatar Euro Penalty Shootout"),
-    (0x58550B5C, "Mystic Forest"),
-    (0x58550B5F, "Pulse: Fight the Night"),
-    (0x58550B60, "Penny Arcade's Rain-slick 3"),
-    (0x58550B62, "Andy's Notepad [Saucers]"),
-    (0x58550B64, "Shooting at Monsters"),
-    (0x58550B67, "The Rise of Gaddafish"),
-    (0x58550B68, "S. Avatar Fighting Tournament"),
-    (0x58550B69, "Absolute War"),
-    (0x58550B6A, "Super Amazing Wagon Adventure"),
-    (0x58550B6C, "Stellar Fortress"),
-    (0x58550B6F, "Little Ninjas"),
-    (0x58550B72, "Sushi Castle"),
-    (0x58550B73, "Wushi"),
-    (0x58550B74, "Peg Solitaire"),
-    (0x58550B75, "About To Blow Up Part 1"),
-    (0x58550B79, "Pi"),
-    (0x58550B7B, "21 Ball Snooker LIVE"),
-    (0x58550B7C, "That Wacky Fish Game"),
-    (0x58550B7D, "Avatar downhill"),
-    (0x58550B7E, "Super Killer Hornet"),
-    (0x58550B84, "Mister Jump"),
-    (0x58550B85, "Space Invasion"),
-    (0x58550B86, "Dead sea"),
-    (0x58550B87, "Guppy: Collects! 2"),
-    (0x58550B88, "CatHunterPopoy"),
-    (0x58550B89, "1on1 Basketball"),
-    (0x58550B8A, "Ninja Exorcist Episode 1"),
-    (0x58550B8B, "Slick"),
-    (0x58550B8C, "Red Tie Miner Zombie 2"),
-    (0x58550B8D, "Twenty Twelve"),
-    (0x58550B8E, "Acid Drift: Solar"),
-    (0x58550B91, "Rock, Paper, Lazers"),
-    (0x58550B92, "Avatar Trivia Party 2"),
-    (0x58550B94, "The Undead Syndrome"),
-    (0x58550B96, "PING"),
-    (0x58550B97, "2012 Athletics Tournament"),
-    (0x58550B98, "8 Ball Champion LIVE"),
-    (0x58550B99, "Sunflower farm"),
-    (0x58550B9D, "Pacific Wings"),
-    (0x58550B9E, "Geo Bots"),
-    (0x58550B9F, "QTE Champ"),
-    (0x58550BA1, "Chopper Storm"),
-    (0x58550BA2, "Face Slapper"),
-    (0x58550BA3, "Every Other Shooter"),
-    (0x58550BA4, "Empire of Towers"),
-    (0x58550BA5, "Avatar Laser Wars 2"),
-    (0x58550BA6, "The 4th Wall"),
-    (0x58550BA7, "Dark Matter"),
-    (0x58550BA8, "Ant General"),
-    (0x58550BA9, "Insane Game"),
-    (0x58550BAA, "THE MONKEY WANTS BANANAS"),
-    (0x58550BAB, "Maze of Apes"),
-    (0x58550BAD, "Sticky Bump"),
-    (0x58550BAE, "One Million Taps"),
-    (0x58550BAF, "0 Gravity Y3030"),
-    (0x58550BB0, "2012"),
-    (0x58550BB1, "Block World"),
-    (0x58550BB3, "Human Subject"),
-    (0x58550BB5, "AvatAAAH!!!"),
-    (0x58550BB6, "2D House of Terror"),
-    (0x58550BB9, "Ambiance"),
-    (0x58550BBC, "Drinkards Beer Pong"),
-    (0x58550BBD, "Jet Set Willy 360"),
-    (0x58550BBE, "2 JAWSOME"),
-    (0x58550BBF, "Merger"),
-    (0x58550BC1, "Rogue AI"),
-    (0x58550BC7, "Null Battles"),
-    (0x58550BC9, "Oozi Earth Adventure Ep. 4"),
-    (0x58550BCF, "Resistor"),
-    (0x58550BD0, "Trap Ball Edición Billar"),
-    (0x58550BD4, "Hack This Game 4"),
-    (0x58550BD5, "Yetholm Castle Aries Attack"),
-    (0x58550BD7, "City Tuesday"),
-    (0x58550BD8, "Death Orb Wars"),
-    (0x58550BDA, "ezmuze+ 2"),
-    (0x58550BDB, "Murder Miners"),
-    (0x58550BDD, "Color Sense"),
-    (0x58550BE0, "qrth-phyl"),
-    (0x58550BE3, "Senoka"),
-    (0x58550BE5, "Splodge"),
-    (0x58550BE7, "Diehard Dungeon"),
-    (0x58550BE8, "Snops Attack! Zombie Defense"),
-    (0x58550BE9, "A Snowy Slalom"),
-    (0x58550BEB, "Smooth Operators"),
-    (0x58550BF1, "Diamond Challe"),
-    (0x58550BF4, "Xenominer"),
-    (0x58550BF5, "Pixel"),
-    (0x58550BF6, "Strategy Soccer Live Club 2013"),
-    (0x58550BF7, "Nuke War '84"),
-    (0x58550BF8, "Cars Shooting at ALIENS"),
-    (0x58550BFA, "9 Ball Champion LIVE"),
-    (0x58550BFB, "8 Ball"),
-    (0x58550BFE, "Moe Mekuri 3 萌めくり3"),
-    (0x58550BFF, "Red Invasion: TD Blitzkrieg"),
-    (0x58550C00, "Rick Rescue"),
-    (0x58550C01, "Ovary Overload"),
-    (0x58550C02, "Caterpillar Capers"),
-    (0x58550C05, "Angry Fish: Halloween"),
-    (0x58550C06, "Beer Pong 2"),
-    (0x58550C07, "Johnny Carnage"),
-    (0x58550C09, "Atoms"),
-    (0x58550C0C, "Color Ball"),
-    (0x58550C0D, "Alphabet Sub"),
-    (0x58550C0F, "Sky Islands"),
-    (0x58550C10, "Avatar Snowboarding"),
-    (0x58550C11, "Airrise"),
-    (0x58550C13, "Ninja Crash"),
-    (0x58550C14, "Nessie"),
-    (0x58550C16, "Prank Call 2"),
-    (0x58550C17, "Captain Foraxian"),
-    (0x58550C19, "Extreme XOXO"),
-    (0x58550C1D, "Hypotenuse"),
-    (0x58550C1E, "Piz-ong"),
-    (0x58550C20, "Zombie Death Survival"),
-    (0x58550C22, "Dungeons of Desolation"),
-    (0x58550C28, "Fishing Frenzy"),
-    (0x58550C2A, "Avatar Driving"),
-    (0x58550C2B, "Vocabulary Builder"),
-    (0x58550C2C, "Main Sequence"),
-    (0x58550C2E, "Rocketgirl"),
-    (0x58550C2F, "Alien Siege"),
-    (0x58550C30, "Space Rage"),
-    (0x58550C31, "Shark Attack Deathmatch"),
-    (0x58550C32, "Oh Noes!!2"),
-    (0x58550C34, "Hostile Hustle"),
-    (0x58550C36, "Zomp 3: The Quest for Z's"),
-    (0x58550C37, "Avatar Farm Online"),
-    (0x58550C38, "Code Breaker"),
-    (0x58550C3A, "RAD Infinitum"),
-    (0x58550C3B, "Trailer Park King 3DD"),
-    (0x58550C43, "Aeternum"),
-    (0x58550C46, "Robot Legions"),
-    (0x58550C47, "Christmas Carnage"),
-    (0x58550C48, "Space Crüesader"),
-    (0x58550C49, "Red Tie Miner Zombie 3"),
-    (0x58550C4B, "Cool Shapes"),
-    (0x58550C4E, "Space Egypt"),
-    (0x58550C4F, "2D Voxel Madness"),
-    (0x58550C50, "TrickyTreat"),
-    (0x58550C51, "Retro Pixel Racers"),
-    (0x58550C55, "60 Seconds to Park"),
-    (0x58550C58, "Bleed"),
-    (0x58550C59, "Heavy Recoil"),
-    (0x58550C5C, "White Noise: A Tale of Horror"),
-    (0x58550C5D, "Zombie Strippers Ate My Brains"),
-    (0x58550C5F, "Chrophidian"),
-    (0x58550C61, "Gingerbread Holiday Sharing"),
-    (0x58550C62, "8BitsRetroZSurvivals"),
-    (0x58550C65, "Awesome Pirates"),
-    (0x58550C66, "End of Days: Survivor"),
-    (0x58550C67, "How Quick Are You"),
-    (0x58550C68, "Zombie Death Quota"),
-    (0x58550C69, "Odyssey 3011"),
-    (0x58550C6A, "Revenge of the Sammich 2"),
-    (0x58550C6B, "Platformer From Hell"),
-    (0x58550C6C, "Temple of Dogolrak 2"),
-    (0x58550C6E, "Super Santa Slayer"),
-    (0x58550C6F, "8-Bit Defense"),
-    (0x58550C72, "No Air Out Here"),
-    (0x58550C73, "Battle Sub"),
-    (0x58550C75, "Avatar Karting 2"),
-    (0x58550C76, "Snowflakes"),
-    (0x58550C77, "Flood Frenzy!"),
-    (0x58550C78, "Arcade of Neon"),
-    (0x58550C79, "Squadron Scramble"),
-    (0x58550C7A, "Trivia Or Die: Movie Edition"),
-    (0x58550C7B, "Scribendus"),
-    (0x58550C7C, "Pirates Attack"),
-    (0x58550C7D, "Avatar Football"),
-    (0x58550C7E, "Tank Attack 2D"),
-    (0x58550C80, "Harvester"),
-    (0x58550C82, "Decoy"),
-    (0x58550C85, "The Runner"),
-    (0x58550C86, "Little Acorns Deluxe"),
-    (0x58550C8A, "Dynasty of Dusk"),
-    (0x58550C8B, "The Flying Dead"),
-    (0x58550C8C, "Grid Space Shooter"),
-    (0x58550C8D, "Galactic Conquest"),
-    (0x58550C8E, "Lights Out"),
-    (0x58550C90, "Story Tale Mania Christmas"),
-    (0x58550C91, "Avatar Stealth"),
-    (0x58550C94, "Exploding Imp"),
-    (0x58550C95, "Geriatrix - Zombie City"),
-    (0x58550C96, "Pester"),
-    (0x58550C97, "Match Frenzy"),
-    (0x58550C9A, "Protect Our Base"),
-    (0x58550C9B, "Arcadecraft"),
-    (0x58550C9D, "Labyrinth X"),
-    (0x58550C9F, "On A Roll 3D"),
-    (0x58550CA4, "Zombie Square 2"),
-    (0x58550CA6, "Let's Learn Japanese: Deluxe"),
-    (0x58550CA7, "Undead Empire: Hellfire"),
-    (0x58550CA8, "Bad Caterpillar"),
-    (0x58550CAA, "The Fantastic Fivesome"),
-    (0x58550CB4, "Procrastinating Squirrel"),
-    (0x58550CBB, "Chronoblast"),
-    (0x58550CD2, "Don't Die Pluto"),
-    (0x58550CD3, "DLC Quest: Live Freemium or Die"),
-    (0x58550CDD, "Rise of the Ravager"),
-    (0x58550CDE, "Death Goat"),
-    (0x58550CDF, "Beach Bubbles 3"),
-    (0x58550CE7, "Learn the Controller"),
-    (0x58550CE8, "Seeds of Ralark"),
-    (0x58550CEA, "Aircraft RC"),
-    (0x58550CEC, "Smatter"),
-    (0x58550CEE, "Path of King Arthur"),
-    (0x58550CF0, "EZ Message Sender"),
-    (0x58550CF1, "Zombie Estate 2"),
-    (0x58550CF4, "S.D.G.T"),
-    (0x58550CF5, "Discovery+"),
-    (0x58550CF7, "Zombie Compound"),
-    (0x58550CF8, "War Rapier 43210"),
-    (0x58550CF9, "Larry's Loot"),
-    (0x58550CFB, "Hack This Game Collection"),
-    (0x58550CFC, "Planetary Shield"),
-    (0x58550CFD, "MPL Slots #1"),
-    (0x58550D01, "Itano Alpha Flight"),
-    (0x58550D04, "Heart Breaker"),
-    (0x58550D07, "Learn Indo European Language"),
-    (0x58550D0B, "Virus Attack"),
-    (0x58550D0D, "JamSouls !"),
-    (0x58550D0E, "Marshmallow Duel"),
-    (0x58550D10, "Planet Wars"),
-    (0x58550D11, "Write your own music"),
-    (0x58550D14, "Naoki Tales"),
-    (0x58550D16, "Orbit Crash"),
-    (0x58550D17, "Blocks and Tanks"),
-    (0x58550D19, "Lootfest2"),
-    (0x58550D1C, "Zombie Outback"),
-    (0x58550D1F, "Avatar and the Deadly Tomb"),
-    (0x58550D21, "Aah! Who’s that Character?"),
-    (0x58550D22, "Dinora"),
-    (0x58550D23, "Pushmasters"),
-    (0x58550D25, "Happy Pong"),
-    (0x58550D27, "Guns N Noses"),
-    (0x58550D29, "Reality Bytes"),
-    (0x58550D2B, "Bulbs"),
-    (0x58550D2E, "Avatar Arena Action"),
-    (0x58550D30, "Avatar Mayhem"),
-    (0x58550D33, "Get Your Cell Together"),
-    (0x58550D35, "DoCoNoid"),
-    (0x58550D36, "Mount Your Friends"),
-    (0x58550D37, "Thunder Moon"),
-    (0x58550D38, "Aqua Kitty"),
-    (0x58550D39, "Toxic Trash"),
-    (0x58550D3A, "Penny Arcade's Rain-Slick 4"),
-    (0x58550D3B, "Fanny Granny's Great Escape"),
-    (0x58550D3D, "Mystic Cards"),
-    (0x58550D3E, "Flyby"),
-    (0x58550D3F, "Atomic Blast"),
-    (0x58550D40, "Make It Rain"),
-    (0x58550D42, "Rasternauts"),
-    (0x58550D43, "Avatar Warfare!"),
-    (0x58550D47, "Saturn 9"),
-    (0x58550D49, "Sector 7-4-9er"),
-    (0x58550D4A, "One Finger Death Punch"),
-    (0x58550D4B, "VOX STUDIOS: RAID"),
-    (0x58550D4C, "Uncraft Me !"),
-    (0x58550D4D, "Boss"),
-    (0x58550D4E, "DERP of DUTY"),
-    (0x58550D4F, "comet turn"),
-    (0x58550D55, "Apocalyptic Path: ToF"),
-    (0x58550D56, "Feelin' Lucky, Punk?"),
-    (0x58550D5A, "Tokyo Hosto"),
-    (0x58550D5C, "Dark Quest"),
-    (0x58550D5D, "Fishy Warfare"),
-    (0x58550D60, "Avatar Tennis!"),
-    (0x58550D63, "Avatar Physics: Running"),
-    (0x58550D64, "2 in 1 Trivia All-Stars"),
-    (0x58550D65, "Space Hordes"),
-    (0x58550D67, "Blocks and Big Robots"),
-    (0x58550D68, "BLOOD&BACON"),
-    (0x58550D69, "ZombieMonkeys Out of the Trees"),
-    (0x58550D6A, "Gem Chaser II"),
-    (0x58550D6B, "Sexy Island Adventure"),
-    (0x58550D6C, "Journey To The Top: QFTLG"),
-    (0x58550D6E, "Tatakai Tank Rush!"),
-    (0x58550D6F, "Action Arcade Wrestling 2"),
-    (0x58550D70, "Beat Hazard Ultra"),
-    (0x58550D71, "Monkey Nuts"),
-    (0x58550D72, "Fist Puncher"),
-    (0x58550D74, "GravityPong"),
-    (0x58550D75, "Indiemon Villain Version"),
-    (0x58550D77, "Space Pilots"),
-    (0x58550D78, "Word Cheat"),
-    (0x58550D7B, "Dawn Of The Fred"),
-    (0x58550D7C, "Squeebels"),
-    (0x58550D7D, "A Light Start"),
-    (0x58550D81, "The Unbreakable Chain"),
-    (0x58550D82, "Reflector"),
-    (0x58550D86, "Magicians & Looters"),
-    (0x58550D87, "X-Orbtek II"),
-    (0x58550D89, "Catch A Cold"),
-    (0x58550D8A, "Smash-A-Seal"),
-    (0x58550D8F, "Hurdle Turtle: Celebrations!"),
-    (0x58550D90, "Mechanician Alex"),
-    (0x58550D92, "PABLO'S FRUIT"),
-    (0x58550D93, "Avatar Honor and Duty"),
-    (0x58550D95, "Retro Racer Combat"),
-    (0x58550D96, "Avatar Survival Games"),
-    (0x58550D97, "Architektris"),
-    (0x58550D98, "Thunder Moon Online"),
-    (0x58550D9B, "Dirchie Kart 2"),
-    (0x58550D9C, "Power-Up"),
-    (0x58550D9D, "NINJA STG 忍者武右ヱ門のいちばん長い日"),
-    (0x58550D9E, "Valkyrius"),
-    (0x58550D9F, "一>◇"),
-    (0x58550DA0, "Paint Wars"),
-    (0x58550DA1, "Six O'Clock High"),
-    (0x58550DA2, "Unholy Heights"),
-    (0x58550DA7, "The Useful Dead"),
-    (0x58550DAC, "X S.E.E.D"),
-    (0x58550DAE, "Defend Earth or Die!"),
-    (0x58550DB0, "EOL"),
-    (0x58550DB4, "M.A.C.E."),
-    (0x58550DB7, "Snowboard 2D"),
-    (0x58550DCD, "Tune Her Out"),
-    (0x58550DDA, "Evolution:Fighting 4 Survival"),
-    (0x58550DE6, "Avatar Legends: 2360"),
-    (0x58550DEF, "Iota"),
-    (0x58550DF2, "Uncraft Me 2"),
-    (0x58550DF5, "That Orange Guy 1"),
-    (0x58550DF6, "Allure"),
-    (0x58550DF8, "Steam and Metal"),
-    (0x58550DF9, "Titan Attacks"),
-    (0x58550DFB, "Snowfall"),
-    (0x58550E07, "ZDQ II Ghost Dogs"),
-    (0x58550E08, "TIME VIKING!!!!!AND SPACE RAPTOR"),
-    (0x58550E09, "Solar Chaos"),
-    (0x58550E0B, "Abduction Action! Plus"),
-    (0x58550E11, "DownGate Deathmatch"),
-    (0x58550E1C, "7 Quests of the Hero"),
-    (0x58550E1D, "Little Flappers"),
-    (0x58550E1E, "Survivalist"),
-    (0x58550E1F, "Twin Tiger Shark"),
-    (0x58550E25, "Claw Machine Arcade"),
-    (0x58550E27, "Avils Dungeon 3"),
-    (0x58550E31, "Lunar Panda Deluxe"),
-    (0x58550E32, "ApocZ"),
-    (0x58550E39, "Lifeguard"),
-    (0x58550E46, "2468"),
-    (0x58550E47, "2048: Xbox 360 Edition"),
-    (0x58550E4C, "DEM.S-Blue Partisan-"),
-    (0x58550E5B, "Venus Explorer"),
-    (0x58550E5D, "The Money Game"),
-    (0x58550E64, "Evolution II:Fighting4Survival"),
-    (0x58550E6F, "Amazing Princess Sarah"),
-    (0x58550E75, "Recall"),
-    (0x58550E78, "Space Flowers"),
-    (0x58550E7B, "Xenominer Swarm"),
-    (0x58550E7E, "Nandeyanen!? - The 1st Sûtra"),
-    (0x58550E83, "BLASTEROIDS"),
-    (0x58550E84, "4.5 Trillion Puzzle"),
-    (0x58550E87, "2D World Creator"),
-    (0x58550E8B, "A Difficult Game"),
-    (0x58550E8F, " Massive Cleavage versus Zombies"),
-    (0x58550E90, "Assault Ops"),
-    (0x58550E99, "Date The Boss"),
-    (0x58550E9A, "Candy, Please!"),
-    (0x58550E9B, "Survival Games Season 1"),
-    (0x58550E9C, "Zombie Purge"),
-    (0x58550EA2, "Uncraft'Em All"),
-    (0x58550EAA, "Boot Hill Heroes"),
-    (0x58550EAE, "Broken Pearl"),
-    (0x58550EB9, "T-Kara Puzzles"),
-    (0x58550EC0, "Ghouls N Gals"),
-    (0x58550EC1, "Pirates! Quest for Booty"),
-    (0x58550EC9, "Zombie Shotgun Massacre 3"),
-    (0x58550ECD, "3 Days of Nurse Fanny"),
-    (0x58550ED0, "More Fun With Twins"),
-    (0x58550ED3, "The Sexy Exorcist"),
-    (0x58550EDD, "Press X to Not Die"),
-    (0x58550EDF, "2D World Creator Online"),
-    (0x58550EE4, "Geology Business"),
-    (0x58550F06, "ESPERriririnTelekinesisTIME"),
-    (0x58550F09, "Chasing Styx"),
-    (0x58550F0B, "Survival Games Zombies"),
-    (0x58550F0D, "Solaroids: Prologue"),
-    (0x585709A1, "UFC Undisputed 3"),
-    (0x594B07D1, "Wrestle Kingdom"),
-    (0x594F07D1, "Yoostar2"),
-    (0x594F07D7, "Yoostar On MTV"),
-    (0x5A440004, "Alien Hominid"),
-    (0x5A4507D1, "Check vs Mate"),
-    (0x5A4507DA, "Two Worlds II"),
-    (0x5A4507E0, "Iron Sky Invasion"),
-    (0x5A4907D1, "Rambo The Video Game"),
-    (0x5A5007D1, "Minute To Win It"),
-    (0xC0DE9999, "Xexmenu"),
-    (0xFFFE07D1, "XeLL Launch"),
-    (0xFFFF0018, "ColinMcraeRally04"),
-    (0xFFFF0055, "360 NAND Flasher"),
-];
+#[cfg(all(test, feature = "builtin-db"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn title_types_match_content_types() {
+        let xbox1 = find_entry_by_id(0x4D530004).and_then(|e| e.title_type);
+        assert_eq!(xbox1, Some(TitleType::Xbox1));
+
+        assert!(TitleType::Xbox1.matches_content_type(ContentType::XboxOriginal));
+        assert!(!TitleType::Xbox1.matches_content_type(ContentType::GamesOnDemand));
+        assert!(!TitleType::Xbla.matches_content_type(ContentType::XboxOriginal));
+        assert!(TitleType::HomeBrew.matches_content_type(ContentType::GamesOnDemand));
+    }
+}
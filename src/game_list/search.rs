@@ -0,0 +1,175 @@
+use super::{GameListEntry, entries};
+
+#[derive(Clone, Copy, Debug)]
+pub struct TitleMatch<'a> {
+    pub entry: &'a GameListEntry,
+    /// Lower is better; 0 is an exact match, ignoring case and punctuation
+    pub distance: u32,
+}
+
+/// Searches the title list by name, ignoring case and punctuation and
+/// tolerating a few typos per word. Returns up to `limit` best candidates.
+pub fn search_titles(query: &str, limit: usize) -> Vec<TitleMatch<'static>> {
+    search_entries(entries().iter(), query, limit)
+}
+
+pub(super) fn search_entries<'a>(
+    entries: impl Iterator<Item = &'a GameListEntry>,
+    query: &str,
+    limit: usize,
+) -> Vec<TitleMatch<'a>> {
+    let query = Normalized::new(query);
+    if query.compact.is_empty() {
+        return Vec::new();
+    }
+
+    let mut matches: Vec<_> = entries
+        .filter_map(|entry| {
+            let distance = match_distance(&query, &Normalized::new(&entry.name))?;
+            Some(TitleMatch { entry, distance })
+        })
+        .collect();
+
+    matches.sort_by(|a, b| {
+        a.distance
+            .cmp(&b.distance)
+            .then_with(|| a.entry.name.len().cmp(&b.entry.name.len()))
+            .then_with(|| a.entry.title_id.cmp(&b.entry.title_id))
+    });
+
+    matches.truncate(limit);
+    matches
+}
+
+struct Normalized {
+    words: Vec<Vec<char>>,
+    compact: Vec<char>,
+}
+
+impl Normalized {
+    fn new(s: &str) -> Normalized {
+        let words: Vec<Vec<char>> = s
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|w| !w.is_empty())
+            .map(|w| w.chars().flat_map(char::to_lowercase).collect())
+            .collect();
+        let compact = words.concat();
+        Normalized { words, compact }
+    }
+}
+
+fn match_distance(query: &Normalized, title: &Normalized) -> Option<u32> {
+    if title.compact == query.compact {
+        return Some(0);
+    }
+    if title.compact.starts_with(&query.compact) {
+        return Some(1);
+    }
+    if contains(&title.compact, &query.compact) {
+        return Some(2);
+    }
+
+    let whole = edit_distance(&query.compact, &title.compact);
+    let whole = (whole <= allowed_typos(query.compact.len())).then_some(whole);
+
+    // every word of the query has to be found in the title, give or take a few typos
+    let by_words = query.words.iter().try_fold(0, |total, query_word| {
+        let best = title
+            .words
+            .iter()
+            .map(|title_word| word_distance(query_word, title_word))
+            .min()?;
+        (best <= allowed_typos(query_word.len())).then_some(total + best)
+    });
+
+    match (whole, by_words) {
+        (Some(a), Some(b)) => Some(3 + a.min(b) as u32),
+        (Some(d), None) | (None, Some(d)) => Some(3 + d as u32),
+        (None, None) => None,
+    }
+}
+
+/// A query word may also be a prefix of a title word, "hal" finds "halo"
+fn word_distance(query_word: &[char], title_word: &[char]) -> usize {
+    let prefix = &title_word[..title_word.len().min(query_word.len())];
+    edit_distance(query_word, title_word).min(edit_distance(query_word, prefix) + 1)
+}
+
+fn allowed_typos(len: usize) -> usize {
+    match len {
+        0..=3 => 0,
+        4..=7 => 1,
+        _ => 2,
+    }
+}
+
+fn contains(haystack: &[char], needle: &[char]) -> bool {
+    haystack.windows(needle.len()).any(|w| w == needle)
+}
+
+/// Optimal string alignment distance: Levenshtein plus adjacent transpositions
+fn edit_distance(a: &[char], b: &[char]) -> usize {
+    let mut rows = vec![vec![0_usize; b.len() + 1]; a.len() + 1];
+
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, d) in rows[0].iter_mut().enumerate() {
+        *d = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut d = (rows[i - 1][j] + 1)
+                .min(rows[i][j - 1] + 1)
+                .min(rows[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                d = d.min(rows[i - 2][j - 2] + 1);
+            }
+            rows[i][j] = d;
+        }
+    }
+
+    rows[a.len()][b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chars(s: &str) -> Vec<char> {
+        s.chars().collect()
+    }
+
+    #[cfg(feature = "builtin-db")]
+    fn best(query: &str) -> Option<u32> {
+        search_titles(query, 1).first().map(|m| m.entry.title_id)
+    }
+
+    #[test]
+    fn edit_distance_counts_transpositions_once() {
+        assert_eq!(edit_distance(&chars("halo"), &chars("halo")), 0);
+        assert_eq!(edit_distance(&chars("hlao"), &chars("halo")), 1);
+        assert_eq!(edit_distance(&chars("hal"), &chars("halo")), 1);
+    }
+
+    #[test]
+    #[cfg(feature = "builtin-db")]
+    fn finds_titles_regardless_of_case_and_punctuation() {
+        assert_eq!(best("HALO 3"), Some(0x4D5307E6));
+        assert_eq!(best("rent a hero no 1"), Some(0x00000002));
+    }
+
+    #[test]
+    #[cfg(feature = "builtin-db")]
+    fn tolerates_typos() {
+        assert_eq!(best("Hlao 3"), Some(0x4D5307E6));
+    }
+
+    #[test]
+    fn finds_nothing_for_gibberish() {
+        assert!(search_titles("qwxzvbnm", 5).is_empty());
+        assert!(search_titles("  --  ", 5).is_empty());
+    }
+}
@@ -1,19 +1,331 @@
+use std::io::Read;
+
+use anyhow::{Context, Error, bail};
+use bitflags::bitflags;
 use byteorder::{BE, ByteOrder, LE};
 
 use sha1::{Digest, Sha1};
 
 use crate::executable::TitleExecutionInfo;
 
+use super::{
+    CONSOLE_CERTIFICATE_SIZE, ConsoleCertificate, ConsoleKeys, ContentType, ICON_SLOT_SIZE, Locale,
+    SIGNATURE_SIZE, SvodVolumeDescriptor,
+};
+
 const EMPTY_LIVE: &[u8] = include_bytes!("empty_live.bin");
 
-pub struct ConHeaderBuilder {
-    buffer: Vec<u8>,
+// Field offsets shared by `ConHeaderBuilder` and `ConHeader`
+const CERTIFICATE: usize = 0x0004;
+const SIGNATURE: usize = 0x01ac;
+const SIGNED_DATA: usize = 0x022c;
+const LICENSES: usize = 0x022c;
+const HEADER_HASH: usize = 0x032c;
+const HEADER_SIZE: usize = 0x0340;
+const CONTENT_TYPE: usize = 0x0344;
+const METADATA_VERSION: usize = 0x0348;
+const CONTENT_SIZE: usize = 0x034c;
+const EXECUTION_INFO: usize = 0x0354;
+const CONSOLE_ID: usize = 0x036c;
+const PROFILE_ID: usize = 0x0371;
+const VOLUME_DESCRIPTOR: usize = 0x0379;
+const DESCRIPTOR_TYPE: usize = 0x03a9;
+const DEVICE_ID: usize = 0x03fd;
+const DISPLAY_NAMES: usize = 0x0411;
+const EXTRA_DISPLAY_NAMES: usize = 0x541a;
+const DESCRIPTIONS: usize = 0x0d11;
+const EXTRA_DESCRIPTIONS: usize = 0x941a;
+const PUBLISHER: usize = 0x1611;
+const TITLE_NAME: usize = 0x1691;
+const TRANSFER_FLAGS: usize = 0x1711;
+const ICON_SIZE: usize = 0x1712;
+const TITLE_ICON_SIZE: usize = 0x1716;
+const ICON: usize = 0x171a;
+const TITLE_ICON: usize = 0x571a;
+
+/// Metadata version 1 headers end right after the title icon slot
+const MIN_HEADER_SIZE: usize = 0x971a;
+
+const PNG_MAGIC: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+pub const VOLUME_DESCRIPTOR_SIZE: usize = 0x24;
+pub const LICENSE_ENTRY_COUNT: usize = 0x10;
+pub const CONSOLE_ID_SIZE: usize = 5;
+pub const DEVICE_ID_SIZE: usize = 0x14;
+
+/// The magic at the start of the header, telling how the package is signed
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PackageType {
+    /// Signed with a console key
+    Con,
+    /// Signed by Microsoft, for content from Xbox Live
+    Live,
+    /// Signed by Microsoft, for content from other sources
+    Pirs,
+}
+
+impl PackageType {
+    pub fn magic(&self) -> &'static [u8; 4] {
+        match self {
+            PackageType::Con => b"CON ",
+            PackageType::Live => b"LIVE",
+            PackageType::Pirs => b"PIRS",
+        }
+    }
+
+    pub fn from_magic(magic: &[u8]) -> Option<PackageType> {
+        [PackageType::Con, PackageType::Live, PackageType::Pirs]
+            .into_iter()
+            .find(|t| t.magic() == magic)
+    }
+}
+
+/// Who may use the package; the template has a single entry allowing anyone
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LicenseEntry {
+    /// `0xffffffffffffffff` for any profile or console, otherwise an XUID or a console ID
+    pub licensee_id: u64,
+    pub license_bits: u32,
+    pub license_flags: u32,
+}
+
+impl LicenseEntry {
+    pub const ANYONE: LicenseEntry = LicenseEntry {
+        licensee_id: u64::MAX,
+        license_bits: 0,
+        license_flags: 0,
+    };
+
+    fn parse(buf: &[u8]) -> LicenseEntry {
+        LicenseEntry {
+            licensee_id: BE::read_u64(&buf[0x00..]),
+            license_bits: BE::read_u32(&buf[0x08..]),
+            license_flags: BE::read_u32(&buf[0x0c..]),
+        }
+    }
+}
+
+bitflags! {
+    /// Whether the dashboard lets the package be moved or copied to other profiles and devices
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct TransferFlags: u8 {
+        const DEEP_LINK_SUPPORTED = 0x04;
+        const DISABLE_NETWORK_STORAGE = 0x08;
+        const KINECT_ENABLED = 0x10;
+        const MOVE_ONLY_TRANSFER = 0x20;
+        const DEVICE_ID_TRANSFER = 0x40;
+        const PROFILE_ID_TRANSFER = 0x80;
+    }
+}
+
+/// How the data following the header is laid out
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VolumeType {
+    /// Secure Transacted File System, a small file system with hash tables
+    Stfs,
+    /// Secure Virtual Optical Disc, a disc image split into parts, as written by `write_part`
+    Svod,
 }
 
-#[derive(Clone, Copy)]
-pub enum ContentType {
-    GamesOnDemand = 0x7000,
-    XboxOriginal = 0x5000,
+/// A parsed XContent header, in the same layout `ConHeaderBuilder` writes
+#[derive(Clone, Debug)]
+pub struct ConHeader {
+    pub package_type: PackageType,
+    pub header_hash: [u8; 20],
+    pub header_size: u32,
+    pub content_type: ContentType,
+    pub metadata_version: u32,
+    pub content_size: u64,
+    pub execution_info: TitleExecutionInfo,
+    /// Interpreted according to `volume_type`
+    pub volume_descriptor: [u8; VOLUME_DESCRIPTOR_SIZE],
+    pub volume_type: VolumeType,
+    /// Non-empty display names only
+    pub display_names: Vec<(Locale, String)>,
+    /// Non-empty descriptions only
+    pub descriptions: Vec<(Locale, String)>,
+    pub publisher: String,
+    pub title_name: String,
+    /// Non-empty license entries only
+    pub licenses: Vec<LicenseEntry>,
+    pub profile_id: u64,
+    pub console_id: [u8; CONSOLE_ID_SIZE],
+    pub device_id: [u8; DEVICE_ID_SIZE],
+    pub transfer_flags: TransferFlags,
+    pub icon: Option<Vec<u8>>,
+    pub title_icon: Option<Vec<u8>>,
+    bytes: Vec<u8>,
+}
+
+impl ConHeader {
+    /// Reads the header along with its padding up to the next 0x1000 boundary,
+    /// leaving `reader` at the start of the data.
+    pub fn read<R: Read>(mut reader: R) -> Result<ConHeader, Error> {
+        let mut buffer = vec![0_u8; CONTENT_TYPE];
+        reader.read_exact(&mut buffer)?;
+
+        let Some(package_type) = PackageType::from_magic(&buffer[0..4]) else {
+            bail!("not an XContent package");
+        };
+
+        let header_size = BE::read_u32(&buffer[HEADER_SIZE..]);
+        if (header_size as usize) < MIN_HEADER_SIZE || header_size > 0x10000 {
+            bail!("invalid header size {header_size:#x}");
+        }
+
+        buffer.resize(Self::data_offset_for(header_size) as usize, 0);
+        reader.read_exact(&mut buffer[CONTENT_TYPE..])?;
+
+        let content_type = BE::read_u32(&buffer[CONTENT_TYPE..]);
+        let content_type = ContentType::try_from(content_type)
+            .with_context(|| format!("unknown content type {content_type:08X}"))?;
+
+        let metadata_version = BE::read_u32(&buffer[METADATA_VERSION..]);
+
+        let volume_type = match BE::read_u32(&buffer[DESCRIPTOR_TYPE..]) {
+            0 => VolumeType::Stfs,
+            1 => VolumeType::Svod,
+            t => bail!("unknown volume descriptor type {t}"),
+        };
+
+        let read_localized = |first_block: usize, extra_block: usize| {
+            Locale::ALL
+                .into_iter()
+                // the extra slots came with metadata version 2
+                .filter(|locale| metadata_version >= 2 || (*locale as usize) < 9)
+                .filter_map(|locale| {
+                    let offset = locale.slot_offset(first_block, extra_block);
+                    let text = read_utf16_be(&buffer[offset..offset + 0x100]);
+                    Some((locale, text)).filter(|(_, text)| !text.is_empty())
+                })
+                .collect()
+        };
+
+        let licenses = buffer[LICENSES..LICENSES + LICENSE_ENTRY_COUNT * 0x10]
+            .chunks_exact(0x10)
+            .map(LicenseEntry::parse)
+            .filter(|license| license.licensee_id != 0)
+            .collect();
+
+        let read_icon = |size_offset: usize, offset: usize| {
+            let size = (BE::read_u32(&buffer[size_offset..]) as usize).min(ICON_SLOT_SIZE);
+            Some(buffer[offset..offset + size].to_vec()).filter(|icon| !icon.is_empty())
+        };
+
+        Ok(ConHeader {
+            package_type,
+            header_hash: buffer[HEADER_HASH..HEADER_HASH + 20].try_into().unwrap(),
+            header_size,
+            content_type,
+            metadata_version,
+            content_size: BE::read_u64(&buffer[CONTENT_SIZE..]),
+            execution_info: TitleExecutionInfo::from_xex(&buffer[EXECUTION_INFO..])?,
+            volume_descriptor: buffer
+                [VOLUME_DESCRIPTOR..VOLUME_DESCRIPTOR + VOLUME_DESCRIPTOR_SIZE]
+                .try_into()
+                .unwrap(),
+            volume_type,
+            display_names: read_localized(DISPLAY_NAMES, EXTRA_DISPLAY_NAMES),
+            descriptions: read_localized(DESCRIPTIONS, EXTRA_DESCRIPTIONS),
+            publisher: read_utf16_be(&buffer[PUBLISHER..PUBLISHER + 0x80]),
+            title_name: read_utf16_be(&buffer[TITLE_NAME..TITLE_NAME + 0x80]),
+            licenses,
+            profile_id: BE::read_u64(&buffer[PROFILE_ID..]),
+            console_id: buffer[CONSOLE_ID..CONSOLE_ID + CONSOLE_ID_SIZE]
+                .try_into()
+                .unwrap(),
+            device_id: buffer[DEVICE_ID..DEVICE_ID + DEVICE_ID_SIZE]
+                .try_into()
+                .unwrap(),
+            transfer_flags: TransferFlags::from_bits_retain(buffer[TRANSFER_FLAGS]),
+            icon: read_icon(ICON_SIZE, ICON),
+            title_icon: read_icon(TITLE_ICON_SIZE, TITLE_ICON),
+            bytes: buffer,
+        })
+    }
+
+    fn data_offset_for(header_size: u32) -> u64 {
+        (header_size as u64).next_multiple_of(0x1000)
+    }
+
+    /// Where the volume data starts, right after the header
+    pub fn data_offset(&self) -> u64 {
+        Self::data_offset_for(self.header_size)
+    }
+
+    /// The raw header, including the padding
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Checks the digest `ConHeaderBuilder::finalize` writes. It does not
+    /// check the signature, which covers the digest itself.
+    pub fn verify_header_hash(&self) -> bool {
+        let digest: [u8; 20] = Sha1::digest(&self.bytes[CONTENT_TYPE..]).into();
+        digest == self.header_hash
+    }
+
+    /// Checks a `CON ` package signature against the certificate embedded in the header.
+    /// `LIVE` and `PIRS` packages are signed by Microsoft, whose key we do not have.
+    pub fn verify_signature(&self) -> Result<bool, Error> {
+        if self.package_type != PackageType::Con {
+            bail!(
+                "can not verify signatures of {:?} packages",
+                self.package_type
+            );
+        }
+
+        let certificate = ConsoleCertificate::parse(
+            &self.bytes[CERTIFICATE..CERTIFICATE + CONSOLE_CERTIFICATE_SIZE],
+        )
+        .context("invalid console certificate in the header")?;
+        let signature: [u8; SIGNATURE_SIZE] = self.bytes[SIGNATURE..SIGNATURE + SIGNATURE_SIZE]
+            .try_into()
+            .unwrap();
+
+        certificate.verify(&signed_digest(&self.bytes), &signature)
+    }
+
+    pub fn display_name(&self, locale: Locale) -> Option<&str> {
+        self.display_names
+            .iter()
+            .find(|(l, _)| *l == locale)
+            .map(|(_, name)| name.as_str())
+    }
+
+    pub fn svod_volume_descriptor(&self) -> Result<SvodVolumeDescriptor, Error> {
+        if self.volume_type != VolumeType::Svod {
+            bail!("not an SVOD package");
+        }
+        SvodVolumeDescriptor::parse(&self.volume_descriptor)
+    }
+
+    pub fn description(&self, locale: Locale) -> Option<&str> {
+        self.descriptions
+            .iter()
+            .find(|(l, _)| *l == locale)
+            .map(|(_, description)| description.as_str())
+    }
+}
+
+/// The signature covers the license entries, the header hash and the header size
+fn signed_digest(header: &[u8]) -> [u8; 20] {
+    Sha1::digest(&header[SIGNED_DATA..CONTENT_TYPE]).into()
+}
+
+fn read_utf16_be(buf: &[u8]) -> String {
+    let units: Vec<u16> = buf
+        .chunks_exact(2)
+        .map(BE::read_u16)
+        .take_while(|c| *c != 0)
+        .collect();
+    String::from_utf16_lossy(&units)
+}
+
+#[derive(Clone)]
+pub struct ConHeaderBuilder {
+    buffer: Vec<u8>,
+    console_keys: Option<ConsoleKeys>,
 }
 
 impl Default for ConHeaderBuilder {
@@ -24,11 +336,66 @@ impl Default for ConHeaderBuilder {
 
 impl ConHeaderBuilder {
     pub fn new() -> Self {
+        let mut buffer = Vec::from(EMPTY_LIVE);
+
+        // the template comes with version numbers we do not want
+        buffer[0x035b] = 0;
+        buffer[0x035f] = 0;
+
         ConHeaderBuilder {
-            buffer: Vec::from(EMPTY_LIVE),
+            buffer,
+            console_keys: None,
         }
     }
 
+    /// Starts from an existing header, to change some of its metadata without
+    /// touching the data it describes. A `CON ` signature has to be redone with
+    /// `with_console_keys`, or it will no longer match.
+    pub fn from_header(header: &ConHeader) -> Self {
+        ConHeaderBuilder {
+            buffer: header.bytes.clone(),
+            console_keys: None,
+        }
+    }
+
+    /// Starts from a user-supplied template instead of the built-in one, so that
+    /// the fields the builder does not set are kept as they are in the template.
+    /// Takes a bare header, or a whole package to clone the header of.
+    ///
+    /// The signature of the package the template came from is cleared, so the
+    /// result is a `LIVE` package until `with_console_keys` makes it a `CON ` one.
+    /// Version 1 templates are upgraded to version 2.
+    pub fn from_template<R: Read>(reader: R) -> Result<Self, Error> {
+        let header = ConHeader::read(reader).context("invalid header template")?;
+
+        let mut builder = Self::from_header(&header);
+        match header.metadata_version {
+            1 => builder.upgrade_metadata_version(),
+            2 => {}
+            version => bail!("unsupported header template metadata version {version}"),
+        }
+
+        builder.buffer[CERTIFICATE..SIGNED_DATA].fill(0);
+        Ok(builder.with_package_type(PackageType::Live))
+    }
+
+    /// Version 1 thumbnail slots are larger, running on into the slots version 2
+    /// added for the extra locales; thumbnails that do not fit the smaller slots are dropped
+    fn upgrade_metadata_version(&mut self) {
+        for (size_offset, offset, extra_slots) in [
+            (ICON_SIZE, ICON, EXTRA_DISPLAY_NAMES),
+            (TITLE_ICON_SIZE, TITLE_ICON, EXTRA_DESCRIPTIONS),
+        ] {
+            if BE::read_u32(&self.buffer[size_offset..]) as usize > ICON_SLOT_SIZE {
+                self.write_u32_be(size_offset, 0);
+                self.buffer[offset..extra_slots].fill(0);
+            }
+            self.buffer[extra_slots..extra_slots + 0x300].fill(0);
+        }
+
+        self.write_u32_be(METADATA_VERSION, 2);
+    }
+
     fn write_u8(&mut self, offset: usize, value: u8) {
         self.buffer[offset] = value;
     }
@@ -37,14 +404,14 @@ impl ConHeaderBuilder {
         BE::write_u16(&mut self.buffer[offset..], value);
     }
 
-    fn write_u24_be(&mut self, offset: usize, value: u32) {
-        BE::write_u24(&mut self.buffer[offset..], value);
-    }
-
     fn write_u32_be(&mut self, offset: usize, value: u32) {
         BE::write_u32(&mut self.buffer[offset..], value);
     }
 
+    fn write_u64_be(&mut self, offset: usize, value: u64) {
+        BE::write_u64(&mut self.buffer[offset..], value);
+    }
+
     fn write_u32_le(&mut self, offset: usize, value: u32) {
         LE::write_u32(&mut self.buffer[offset..], value);
     }
@@ -53,20 +420,71 @@ impl ConHeaderBuilder {
         self.buffer[offset..offset + buf.len()].copy_from_slice(buf);
     }
 
-    fn write_utf16_be(&mut self, offset: usize, s: &str) {
-        for (i, c) in s.encode_utf16().chain([0]).enumerate() {
+    /// Writes a zero-padded string, cut off to fit a `size` byte field
+    fn write_utf16_be_field(&mut self, offset: usize, size: usize, s: &str) {
+        let max_len = size / 2 - 1;
+        let mut units: Vec<u16> = s.encode_utf16().take(max_len).collect();
+        // do not leave half of a surrogate pair behind
+        if units.last().is_some_and(|u| (0xd800..0xdc00).contains(u)) {
+            units.pop();
+        }
+        units.resize(size / 2, 0);
+
+        for (i, c) in units.into_iter().enumerate() {
             self.write_u16_be(offset + i * 2, c);
         }
     }
 
-    pub fn with_block_counts(mut self, blocks_allocated: u32, blocks_not_allocated: u16) -> Self {
-        self.write_u24_be(0x0392, blocks_allocated);
-        self.write_u16_be(0x0395, blocks_not_allocated);
+    /// The template is a LIVE package
+    pub fn with_package_type(mut self, package_type: PackageType) -> Self {
+        self.write_bytes(0, package_type.magic());
+        self
+    }
+
+    pub fn with_media_id(mut self, media_id: u32) -> Self {
+        self.write_u32_be(EXECUTION_INFO, media_id);
+        self
+    }
+
+    /// Disc numbers start at 1
+    pub fn with_disc_info(mut self, disc_number: u8, disc_count: u8) -> Self {
+        self.write_u8(EXECUTION_INFO + 0x12, disc_number);
+        self.write_u8(EXECUTION_INFO + 0x13, disc_count);
+        self
+    }
+
+    /// Sets the title ID alone, for packages without an executable
+    pub fn with_title_id(mut self, title_id: u32) -> Self {
+        self.write_u32_be(EXECUTION_INFO + 0x0c, title_id);
+        self
+    }
+
+    pub fn with_content_size(mut self, content_size: u64) -> Self {
+        self.write_u64_be(CONTENT_SIZE, content_size);
+        self
+    }
+
+    pub fn with_svod_volume_descriptor(self, volume_descriptor: &SvodVolumeDescriptor) -> Self {
+        self.with_volume_descriptor(VolumeType::Svod, &volume_descriptor.to_bytes())
+    }
+
+    /// The template has an SVOD volume, better set with `with_svod_volume_descriptor`
+    pub fn with_volume_descriptor(
+        mut self,
+        volume_type: VolumeType,
+        volume_descriptor: &[u8; VOLUME_DESCRIPTOR_SIZE],
+    ) -> Self {
+        self.write_bytes(VOLUME_DESCRIPTOR, volume_descriptor);
+        let volume_type = match volume_type {
+            VolumeType::Stfs => 0,
+            VolumeType::Svod => 1,
+        };
+        self.write_u32_be(DESCRIPTOR_TYPE, volume_type);
         self
     }
 
     pub fn with_content_type(mut self, content_type: ContentType) -> Self {
-        self.write_u32_be(0x0344, content_type as u32);
+        self.write_u32_be(CONTENT_TYPE, content_type as u32);
         self
     }
 
@@ -78,45 +496,328 @@ impl ConHeaderBuilder {
 
     pub fn with_execution_info(mut self, exe_info: &TitleExecutionInfo) -> Self {
         // TODO: maybe just pick a suitable repr() for the struct, and write it whole?
-        self.write_u32_be(0x0354, exe_info.media_id);
-        self.write_u32_be(0x0360, exe_info.title_id);
-        self.write_u8(0x0364, exe_info.platform);
-        self.write_u8(0x0365, exe_info.executable_type);
-        self.write_u8(0x0366, exe_info.disc_number);
-        self.write_u8(0x0367, exe_info.disc_count);
+        self.write_u32_be(EXECUTION_INFO, exe_info.media_id);
+        self.write_u32_be(EXECUTION_INFO + 0x0c, exe_info.title_id);
+        self.write_u8(EXECUTION_INFO + 0x10, exe_info.platform);
+        self.write_u8(EXECUTION_INFO + 0x11, exe_info.executable_type);
+        self.write_u8(EXECUTION_INFO + 0x12, exe_info.disc_number);
+        self.write_u8(EXECUTION_INFO + 0x13, exe_info.disc_count);
         self
     }
 
-    pub fn with_game_icon(mut self, png_bytes: Option<&[u8]>) -> Self {
-        let png_bytes = png_bytes.unwrap_or(&[]);
-        assert!(png_bytes.len() <= 0x0400);
+    /// Sets both the content and the title thumbnail
+    pub fn with_game_icon(self, png_bytes: Option<&[u8]>) -> Result<Self, Error> {
+        self.with_content_thumbnail(png_bytes)?
+            .with_title_thumbnail(png_bytes)
+    }
 
-        self.write_u32_be(0x1712, png_bytes.len() as u32);
-        self.write_u32_be(0x1716, png_bytes.len() as u32);
-        self.write_bytes(0x171a, png_bytes);
-        self.write_bytes(0x571a, png_bytes);
-        self
+    /// The thumbnail shown for the package itself
+    pub fn with_content_thumbnail(mut self, png_bytes: Option<&[u8]>) -> Result<Self, Error> {
+        self.write_thumbnail(ICON_SIZE, ICON, png_bytes.unwrap_or(&[]))?;
+        Ok(self)
+    }
+
+    /// The thumbnail shown for the game the package belongs to
+    pub fn with_title_thumbnail(mut self, png_bytes: Option<&[u8]>) -> Result<Self, Error> {
+        self.write_thumbnail(TITLE_ICON_SIZE, TITLE_ICON, png_bytes.unwrap_or(&[]))?;
+        Ok(self)
+    }
+
+    /// Takes PNGs as they are; `load_icon` and `encode_icon` make them fit
+    fn write_thumbnail(
+        &mut self,
+        size_offset: usize,
+        offset: usize,
+        png_bytes: &[u8],
+    ) -> Result<(), Error> {
+        if png_bytes.len() > ICON_SLOT_SIZE {
+            bail!(
+                "icon is {} bytes, which does not fit into the {ICON_SLOT_SIZE} byte slot",
+                png_bytes.len()
+            );
+        }
+        if !png_bytes.is_empty() && !png_bytes.starts_with(PNG_MAGIC) {
+            bail!("icon is not a PNG image");
+        }
+
+        self.write_u32_be(size_offset, png_bytes.len() as u32);
+        self.write_bytes(offset, png_bytes);
+        self.buffer[offset + png_bytes.len()..offset + ICON_SLOT_SIZE].fill(0);
+        Ok(())
     }
 
+    /// Sets the English display name and the title name
     pub fn with_game_title(mut self, game_title: &str) -> Self {
-        self.write_utf16_be(0x0411, game_title);
-        self.write_utf16_be(0x1691, game_title);
+        self.write_utf16_be_field(DISPLAY_NAMES, 0x100, game_title);
+        self.write_utf16_be_field(TITLE_NAME, 0x80, game_title);
+        self
+    }
+
+    /// The name of the game the package belongs to, as opposed to the package display name
+    pub fn with_title_name(mut self, title_name: &str) -> Self {
+        self.write_utf16_be_field(TITLE_NAME, 0x80, title_name);
+        self
+    }
+
+    pub fn with_publisher(mut self, publisher: &str) -> Self {
+        self.write_utf16_be_field(PUBLISHER, 0x80, publisher);
+        self
+    }
+
+    /// Sets the English description
+    pub fn with_description(self, description: &str) -> Self {
+        self.with_localized_description(Locale::English, description)
+    }
+
+    pub fn with_localized_description(mut self, locale: Locale, description: &str) -> Self {
+        let offset = locale.slot_offset(DESCRIPTIONS, EXTRA_DESCRIPTIONS);
+        self.write_utf16_be_field(offset, 0x100, description);
         self
     }
 
-    pub fn with_mht_hash(mut self, mht_hash: &[u8; 20]) -> Self {
-        self.write_bytes(0x037d, mht_hash);
+    /// Replaces all license entries; the template allows anyone
+    pub fn with_licenses(mut self, licenses: &[LicenseEntry]) -> Result<Self, Error> {
+        if licenses.len() > LICENSE_ENTRY_COUNT {
+            bail!(
+                "{} license entries do not fit into the {LICENSE_ENTRY_COUNT} slots",
+                licenses.len()
+            );
+        }
+
+        self.buffer[LICENSES..LICENSES + LICENSE_ENTRY_COUNT * 0x10].fill(0);
+        for (i, license) in licenses.iter().enumerate() {
+            let offset = LICENSES + i * 0x10;
+            self.write_u64_be(offset, license.licensee_id);
+            self.write_u32_be(offset + 0x08, license.license_bits);
+            self.write_u32_be(offset + 0x0c, license.license_flags);
+        }
+        Ok(self)
+    }
+
+    /// The XUID of the profile owning the package, 0 for none
+    pub fn with_profile_id(mut self, profile_id: u64) -> Self {
+        self.write_u64_be(PROFILE_ID, profile_id);
         self
     }
 
+    pub fn with_console_id(mut self, console_id: &[u8; CONSOLE_ID_SIZE]) -> Self {
+        self.write_bytes(CONSOLE_ID, console_id);
+        self
+    }
+
+    /// The storage device the package is bound to, all zeroes for none
+    pub fn with_device_id(mut self, device_id: &[u8; DEVICE_ID_SIZE]) -> Self {
+        self.write_bytes(DEVICE_ID, device_id);
+        self
+    }
+
+    pub fn with_transfer_flags(mut self, transfer_flags: TransferFlags) -> Self {
+        self.write_u8(TRANSFER_FLAGS, transfer_flags.bits());
+        self
+    }
+
+    /// Version 2, the template's, adds the extra display name and description slots
+    /// and shrinks the thumbnails to make room for them
+    pub fn with_metadata_version(mut self, metadata_version: u32) -> Self {
+        self.write_u32_be(METADATA_VERSION, metadata_version);
+        self
+    }
+
+    /// Sets the display name shown on consoles set to `locale`;
+    /// `with_game_title` only sets the English one.
+    pub fn with_localized_game_title(mut self, locale: Locale, game_title: &str) -> Self {
+        let offset = locale.slot_offset(DISPLAY_NAMES, EXTRA_DISPLAY_NAMES);
+        self.write_utf16_be_field(offset, 0x100, game_title);
+        self
+    }
+
+    /// Makes `finalize` sign the header as a `CON ` package of that console
+    pub fn with_console_keys(mut self, console_keys: ConsoleKeys) -> Self {
+        self.console_keys = Some(console_keys);
+        self.with_package_type(PackageType::Con)
+    }
+
     pub fn finalize(mut self) -> Vec<u8> {
-        self.buffer[0x035b] = 0;
-        self.buffer[0x035f] = 0;
-        self.buffer[0x0391] = 0;
+        let digest: [u8; 20] = Sha1::digest(&self.buffer[CONTENT_TYPE..]).into();
+        self.write_bytes(HEADER_HASH, &digest);
 
-        let digest: [u8; 20] = Sha1::digest(&self.buffer[0x0344..(0x0344 + 0xacbc)]).into();
-        self.write_bytes(0x032c, &digest);
+        if let Some(console_keys) = self.console_keys.take() {
+            let signature = console_keys.sign(&signed_digest(&self.buffer));
+            self.write_bytes(0, PackageType::Con.magic());
+            self.write_bytes(CERTIFICATE, console_keys.certificate.as_bytes());
+            self.write_bytes(SIGNATURE, &signature);
+        }
 
         self.buffer
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn metadata_round_trips() {
+        let license = LicenseEntry {
+            licensee_id: 0xe000_0123_4567_89ab,
+            license_bits: 1,
+            license_flags: 0,
+        };
+
+        let header = ConHeaderBuilder::new()
+            .with_game_title("Halo 3")
+            .with_description("Finish the fight")
+            .with_localized_description(Locale::Polish, "Dokończ walkę")
+            .with_publisher("Microsoft Game Studios")
+            .with_licenses(&[license])
+            .unwrap()
+            .with_profile_id(0xe000_0123_4567_89ab)
+            .with_console_id(&[1, 2, 3, 4, 5])
+            .with_transfer_flags(TransferFlags::PROFILE_ID_TRANSFER)
+            .with_content_thumbnail(Some(b"\x89PNG\r\n\x1a\ncontent"))
+            .unwrap()
+            .with_title_thumbnail(Some(b"\x89PNG\r\n\x1a\ntitle"))
+            .unwrap()
+            .finalize();
+
+        let header = ConHeader::read(&header[..]).unwrap();
+        assert_eq!(
+            header.description(Locale::English),
+            Some("Finish the fight")
+        );
+        assert_eq!(header.description(Locale::Polish), Some("Dokończ walkę"));
+        assert_eq!(header.publisher, "Microsoft Game Studios");
+        assert_eq!(header.title_name, "Halo 3");
+        assert_eq!(header.licenses, [license]);
+        assert_eq!(header.profile_id, 0xe000_0123_4567_89ab);
+        assert_eq!(header.console_id, [1, 2, 3, 4, 5]);
+        assert_eq!(header.transfer_flags, TransferFlags::PROFILE_ID_TRANSFER);
+        assert!(header.icon.as_ref().unwrap().ends_with(b"content"));
+        assert!(header.title_icon.as_ref().unwrap().ends_with(b"title"));
+        assert!(header.verify_header_hash());
+    }
+
+    #[test]
+    fn updates_existing_headers() {
+        let original = ConHeaderBuilder::new()
+            .with_game_title("A rather long wrong title")
+            .with_svod_volume_descriptor(&SvodVolumeDescriptor::new([0x42; 20], 0x100))
+            .finalize();
+        let original = ConHeader::read(&original[..]).unwrap();
+
+        let updated = ConHeaderBuilder::from_header(&original)
+            .with_game_title("Right")
+            .with_disc_info(2, 2)
+            .finalize();
+
+        assert_eq!(updated.len(), original.as_bytes().len());
+        let updated = ConHeader::read(&updated[..]).unwrap();
+        let volume_descriptor = updated.svod_volume_descriptor().unwrap();
+        assert_eq!(volume_descriptor.root_hash, [0x42; 20]);
+        assert_eq!(volume_descriptor.data_block_count, 0x100);
+        assert_eq!(updated.title_name, "Right");
+        assert_eq!(updated.display_name(Locale::English), Some("Right"));
+        assert_eq!(updated.execution_info.disc_number, 2);
+        assert!(updated.verify_header_hash());
+    }
+
+    #[test]
+    fn keeps_unmanaged_template_fields() {
+        let template = ConHeaderBuilder::new()
+            .with_game_title("Old")
+            .with_localized_game_title(Locale::Polish, "Stary")
+            .with_description("Old description")
+            .with_publisher("Some Studio")
+            .with_game_icon(Some(b"\x89PNG\r\n\x1a\nold"))
+            .unwrap()
+            .with_transfer_flags(TransferFlags::DEVICE_ID_TRANSFER)
+            .finalize();
+
+        let header = ConHeaderBuilder::from_template(&template[..])
+            .unwrap()
+            .with_game_title("New")
+            .finalize();
+        let header = ConHeader::read(&header[..]).unwrap();
+        assert_eq!(header.transfer_flags, TransferFlags::DEVICE_ID_TRANSFER);
+        assert_eq!(header.title_name, "New");
+        assert_eq!(header.display_name(Locale::English), Some("New"));
+        assert_eq!(header.display_name(Locale::Polish), Some("Stary"));
+        assert_eq!(header.description(Locale::English), Some("Old description"));
+        assert_eq!(header.publisher, "Some Studio");
+        assert!(header.icon.as_ref().unwrap().ends_with(b"old"));
+        assert!(header.title_icon.as_ref().unwrap().ends_with(b"old"));
+
+        let mut bad_magic = template.clone();
+        bad_magic[0..4].copy_from_slice(b"NOPE");
+        assert!(ConHeaderBuilder::from_template(&bad_magic[..]).is_err());
+        assert!(ConHeaderBuilder::from_template(&template[..0x1000]).is_err());
+
+        let mut bad_version = template.clone();
+        BE::write_u32(&mut bad_version[METADATA_VERSION..], 3);
+        assert!(ConHeaderBuilder::from_template(&bad_version[..]).is_err());
+    }
+
+    #[test]
+    fn upgrades_version_1_templates() {
+        let mut template = ConHeaderBuilder::new()
+            .with_content_thumbnail(Some(b"\x89PNG\r\n\x1a\nsmall"))
+            .unwrap()
+            .finalize();
+        BE::write_u32(&mut template[METADATA_VERSION..], 1);
+        // a version 1 title thumbnail fills the slot version 2 has extra descriptions in
+        BE::write_u32(&mut template[TITLE_ICON_SIZE..], 0x4000);
+        template[TITLE_ICON..TITLE_ICON + 8].copy_from_slice(PNG_MAGIC);
+        template[EXTRA_DESCRIPTIONS..MIN_HEADER_SIZE].fill(0x41);
+
+        let header = ConHeaderBuilder::from_template(&template[..])
+            .unwrap()
+            .finalize();
+        let header = ConHeader::read(&header[..]).unwrap();
+        assert_eq!(header.metadata_version, 2);
+        // the extra locales come after the first nine
+        assert!(header.descriptions.iter().all(|(l, _)| (*l as usize) < 9));
+        assert!(header.icon.as_ref().unwrap().ends_with(b"small"));
+        assert_eq!(header.title_icon, None);
+    }
+
+    #[test]
+    fn drops_template_signatures() {
+        let mut template = ConHeaderBuilder::new().finalize();
+        template[0..4].copy_from_slice(PackageType::Con.magic());
+        template[CERTIFICATE..SIGNED_DATA].fill(0x42);
+
+        let header = ConHeaderBuilder::from_template(&template[..])
+            .unwrap()
+            .finalize();
+        let header = ConHeader::read(&header[..]).unwrap();
+        assert_eq!(header.package_type, PackageType::Live);
+        assert!(
+            header.as_bytes()[CERTIFICATE..SIGNED_DATA]
+                .iter()
+                .all(|b| *b == 0)
+        );
+    }
+
+    #[test]
+    fn rejects_oversized_icons() {
+        let mut icon = PNG_MAGIC.to_vec();
+        icon.resize(ICON_SLOT_SIZE + 1, 0);
+        assert!(ConHeaderBuilder::new().with_game_icon(Some(&icon)).is_err());
+        assert!(
+            ConHeaderBuilder::new()
+                .with_game_icon(Some(b"GIF89a"))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn rejects_too_many_licenses() {
+        let licenses = [LicenseEntry::ANYONE; LICENSE_ENTRY_COUNT + 1];
+        assert!(ConHeaderBuilder::new().with_licenses(&licenses).is_err());
+        assert!(
+            ConHeaderBuilder::new()
+                .with_licenses(&licenses[..LICENSE_ENTRY_COUNT])
+                .is_ok()
+        );
+    }
+}
@@ -0,0 +1,256 @@
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Error, bail};
+use byteorder::{BE, ByteOrder};
+use rsa::pkcs1v15::Pkcs1v15Sign;
+use rsa::traits::PublicKeyParts;
+use rsa::{BigUint, RsaPrivateKey, RsaPublicKey};
+use sha1::Sha1;
+
+pub const CONSOLE_CERTIFICATE_SIZE: usize = 0x1a8;
+pub const CONSOLE_PRIVATE_KEY_SIZE: usize = 0x1d0;
+pub const SIGNATURE_SIZE: usize = 0x80;
+
+/// Where the certificate and the private key live in a decrypted keyvault
+const KEYVAULT_SIZE: usize = 0x4000;
+const KEYVAULT_PRIVATE_KEY: usize = 0x0298;
+const KEYVAULT_CERTIFICATE: usize = 0x09c8;
+
+/// A console certificate, as stored in the keyvault and copied into CON headers
+#[derive(Clone, Debug)]
+pub struct ConsoleCertificate {
+    pub console_id: [u8; 5],
+    pub part_number: String,
+    pub console_type: u32,
+    /// As "MM-DD-YY"
+    pub generation_date: String,
+    pub public_exponent: u32,
+    /// In XeCrypt order, least significant 64-bit word first
+    pub public_modulus: [u8; 0x80],
+    bytes: Box<[u8; CONSOLE_CERTIFICATE_SIZE]>,
+}
+
+impl ConsoleCertificate {
+    pub fn parse(bytes: &[u8]) -> Result<ConsoleCertificate, Error> {
+        let Ok(bytes) = <[u8; CONSOLE_CERTIFICATE_SIZE]>::try_from(bytes) else {
+            bail!("console certificate must be {CONSOLE_CERTIFICATE_SIZE:#x} bytes long");
+        };
+
+        if BE::read_u16(&bytes[0x00..]) as usize != CONSOLE_CERTIFICATE_SIZE {
+            bail!("invalid console certificate size field");
+        }
+
+        let text = |range: std::ops::Range<usize>| {
+            String::from_utf8_lossy(&bytes[range])
+                .trim_end_matches('\0')
+                .to_owned()
+        };
+
+        Ok(ConsoleCertificate {
+            console_id: bytes[0x02..0x07].try_into().unwrap(),
+            part_number: text(0x07..0x1b),
+            console_type: BE::read_u32(&bytes[0x1b..]),
+            generation_date: text(0x1f..0x27),
+            public_exponent: BE::read_u32(&bytes[0x27..]),
+            public_modulus: bytes[0x2b..0xab].try_into().unwrap(),
+            bytes: Box::new(bytes),
+        })
+    }
+
+    pub fn as_bytes(&self) -> &[u8; CONSOLE_CERTIFICATE_SIZE] {
+        &self.bytes
+    }
+
+    pub fn public_key(&self) -> Result<RsaPublicKey, Error> {
+        let n = BigUint::from_bytes_be(&from_xecrypt(&self.public_modulus));
+        let e = BigUint::from(self.public_exponent);
+        RsaPublicKey::new(n, e).context("invalid console public key")
+    }
+
+    /// Checks a header signature as written by `ConsoleKeys::sign`
+    pub fn verify(
+        &self,
+        digest: &[u8; 20],
+        signature: &[u8; SIGNATURE_SIZE],
+    ) -> Result<bool, Error> {
+        let mut signature = *signature;
+        signature.reverse();
+
+        let public_key = self.public_key()?;
+        Ok(public_key
+            .verify(Pkcs1v15Sign::new::<Sha1>(), digest, &signature)
+            .is_ok())
+    }
+}
+
+/// A console's own certificate and private key, for signing CON packages
+#[derive(Clone)]
+pub struct ConsoleKeys {
+    pub certificate: ConsoleCertificate,
+    private_key: RsaPrivateKey,
+}
+
+impl ConsoleKeys {
+    /// `private_key` is an `XECRYPT_RSAPRV_1024` structure, as in the keyvault
+    pub fn from_parts(certificate: &[u8], private_key: &[u8]) -> Result<ConsoleKeys, Error> {
+        let certificate = ConsoleCertificate::parse(certificate)?;
+
+        if private_key.len() != CONSOLE_PRIVATE_KEY_SIZE {
+            bail!("console private key must be {CONSOLE_PRIVATE_KEY_SIZE:#x} bytes long");
+        }
+
+        // header: size in 64-bit words, public exponent, reserved
+        if BE::read_u32(&private_key[0x00..]) != 0x10 {
+            bail!("console private key is not a 1024 bit key");
+        }
+        let public_exponent = BE::read_u32(&private_key[0x04..]);
+
+        let modulus = &private_key[0x10..0x90];
+        let p = BigUint::from_bytes_be(&from_xecrypt(&private_key[0x90..0xd0]));
+        let q = BigUint::from_bytes_be(&from_xecrypt(&private_key[0xd0..0x110]));
+
+        if modulus != certificate.public_modulus || public_exponent != certificate.public_exponent {
+            bail!("console certificate and private key do not match");
+        }
+
+        let private_key = RsaPrivateKey::from_p_q(p, q, BigUint::from(public_exponent))
+            .context("invalid console private key")?;
+
+        if private_key.n().to_bytes_be() != from_xecrypt(modulus) {
+            bail!("console private key is inconsistent");
+        }
+
+        Ok(ConsoleKeys {
+            certificate,
+            private_key,
+        })
+    }
+
+    /// Takes the keys from a decrypted keyvault dump
+    pub fn from_keyvault(keyvault: &[u8]) -> Result<ConsoleKeys, Error> {
+        if keyvault.len() != KEYVAULT_SIZE {
+            bail!("keyvault must be {KEYVAULT_SIZE:#x} bytes long, and decrypted");
+        }
+
+        Self::from_parts(
+            &keyvault[KEYVAULT_CERTIFICATE..KEYVAULT_CERTIFICATE + CONSOLE_CERTIFICATE_SIZE],
+            &keyvault[KEYVAULT_PRIVATE_KEY..KEYVAULT_PRIVATE_KEY + CONSOLE_PRIVATE_KEY_SIZE],
+        )
+    }
+
+    pub fn load(certificate_path: &Path, private_key_path: &Path) -> Result<ConsoleKeys, Error> {
+        let certificate =
+            fs::read(certificate_path).context("error reading console certificate")?;
+        let private_key =
+            fs::read(private_key_path).context("error reading console private key")?;
+        Self::from_parts(&certificate, &private_key)
+    }
+
+    pub fn load_keyvault(path: &Path) -> Result<ConsoleKeys, Error> {
+        Self::from_keyvault(&fs::read(path).context("error reading keyvault")?)
+    }
+
+    /// PKCS#1 v1.5 over a SHA-1 digest; the result is byte-reversed,
+    /// as XeCrypt keeps big numbers little-endian.
+    pub fn sign(&self, digest: &[u8; 20]) -> [u8; SIGNATURE_SIZE] {
+        let signature = self
+            .private_key
+            .sign(Pkcs1v15Sign::new::<Sha1>(), digest)
+            .expect("a 1024 bit key always fits a SHA-1 signature");
+
+        let mut signature: [u8; SIGNATURE_SIZE] = signature.try_into().unwrap();
+        signature.reverse();
+        signature
+    }
+}
+
+/// XeCrypt stores big numbers as 64-bit big-endian words, least significant word first
+fn from_xecrypt(bytes: &[u8]) -> Vec<u8> {
+    bytes.chunks_exact(8).rev().flatten().copied().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::god::{ConHeader, ConHeaderBuilder, PackageType};
+
+    /// Converts a big-endian number into XeCrypt word order; the conversion is its own inverse
+    fn to_xecrypt(bytes: &[u8]) -> Vec<u8> {
+        from_xecrypt(bytes)
+    }
+
+    fn hex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    /// A made-up console, with a freshly generated 1024 bit key
+    fn test_keys() -> ConsoleKeys {
+        let p = hex(TEST_P);
+        let q = hex(TEST_Q);
+        let n = (BigUint::from_bytes_be(&p) * BigUint::from_bytes_be(&q)).to_bytes_be();
+
+        let mut certificate = [0_u8; CONSOLE_CERTIFICATE_SIZE];
+        BE::write_u16(&mut certificate[0x00..], CONSOLE_CERTIFICATE_SIZE as u16);
+        certificate[0x02..0x07].copy_from_slice(&[0x12, 0x34, 0x56, 0x78, 0x9a]);
+        certificate[0x07..0x12].copy_from_slice(b"X800000-001");
+        BE::write_u32(&mut certificate[0x1b..], 2);
+        certificate[0x1f..0x27].copy_from_slice(b"01-01-06");
+        BE::write_u32(&mut certificate[0x27..], 0x10001);
+        certificate[0x2b..0xab].copy_from_slice(&to_xecrypt(&n));
+
+        let mut private_key = [0_u8; CONSOLE_PRIVATE_KEY_SIZE];
+        BE::write_u32(&mut private_key[0x00..], 0x10);
+        BE::write_u32(&mut private_key[0x04..], 0x10001);
+        private_key[0x10..0x90].copy_from_slice(&to_xecrypt(&n));
+        private_key[0x90..0xd0].copy_from_slice(&to_xecrypt(&p));
+        private_key[0xd0..0x110].copy_from_slice(&to_xecrypt(&q));
+
+        ConsoleKeys::from_parts(&certificate, &private_key).unwrap()
+    }
+
+    const TEST_P: &str = "f179c5b2a60bcbbf0644e833433baa2d6a544c989ff19f15841b3b60f163dad1fa2553a3e93816b873a3be004b089db5e8d94e990c6d254e3ea9dcec64a2cb65";
+    const TEST_Q: &str = "c1c690918d10d79fd61645f6610f8b4836bc696f1320d12373044b1bedb97c96d4d268218c84bba768a2733f6a8df002e0f0b785b3c146689737f25f9d5a113f";
+
+    #[test]
+    fn signatures_verify_with_the_certificate() {
+        let keys = test_keys();
+        let digest = [7_u8; 20];
+
+        let signature = keys.sign(&digest);
+        assert!(keys.certificate.verify(&digest, &signature).unwrap());
+        assert!(!keys.certificate.verify(&[8_u8; 20], &signature).unwrap());
+    }
+
+    #[test]
+    fn mismatched_keys_are_rejected() {
+        let keys = test_keys();
+        let mut certificate = *keys.certificate.as_bytes();
+        certificate[0x2b] ^= 1;
+
+        let mut private_key = [0_u8; CONSOLE_PRIVATE_KEY_SIZE];
+        BE::write_u32(&mut private_key[0x00..], 0x10);
+        assert!(ConsoleKeys::from_parts(&certificate, &private_key).is_err());
+    }
+
+    #[test]
+    fn signed_headers_verify() {
+        let header = ConHeaderBuilder::new()
+            .with_title_id(0x4d5307e6)
+            .with_console_keys(test_keys())
+            .finalize();
+
+        let parsed = ConHeader::read(&header[..]).unwrap();
+        assert_eq!(parsed.package_type, PackageType::Con);
+        assert!(parsed.verify_header_hash());
+        assert!(parsed.verify_signature().unwrap());
+
+        let mut tampered = header.clone();
+        tampered[0x0230] ^= 1;
+        let parsed = ConHeader::read(&tampered[..]).unwrap();
+        assert!(!parsed.verify_signature().unwrap());
+    }
+}
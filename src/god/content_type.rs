@@ -0,0 +1,184 @@
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::{Context, Error};
+use num_enum::TryFromPrimitive;
+
+/// XContent package types, as stored at offset 0x344 of the header
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, TryFromPrimitive)]
+pub enum ContentType {
+    SavedGame = 0x0000_0001,
+    MarketplaceContent = 0x0000_0002,
+    Publisher = 0x0000_0003,
+    Xbox360Title = 0x0000_1000,
+    IptvPauseBuffer = 0x0000_2000,
+    InstalledGame = 0x0000_4000,
+    XboxOriginal = 0x0000_5000,
+    GamesOnDemand = 0x0000_7000,
+    AvatarItem = 0x0000_9000,
+    Profile = 0x0001_0000,
+    GamerPicture = 0x0002_0000,
+    Theme = 0x0003_0000,
+    CacheFile = 0x0004_0000,
+    StorageDownload = 0x0005_0000,
+    XboxSavedGame = 0x0006_0000,
+    XboxDownload = 0x0007_0000,
+    GameDemo = 0x0008_0000,
+    Video = 0x0009_0000,
+    GameTitle = 0x000a_0000,
+    /// Title updates are packaged as installers
+    Installer = 0x000b_0000,
+    GameTrailer = 0x000c_0000,
+    ArcadeTitle = 0x000d_0000,
+    Xna = 0x000e_0000,
+    LicenseStore = 0x000f_0000,
+    Movie = 0x0010_0000,
+    Tv = 0x0020_0000,
+    MusicVideo = 0x0030_0000,
+    GameVideo = 0x0040_0000,
+    PodcastVideo = 0x0050_0000,
+    ViralVideo = 0x0060_0000,
+    CommunityGame = 0x0200_0000,
+}
+
+impl ContentType {
+    pub const ALL: [ContentType; 31] = [
+        ContentType::SavedGame,
+        ContentType::MarketplaceContent,
+        ContentType::Publisher,
+        ContentType::Xbox360Title,
+        ContentType::IptvPauseBuffer,
+        ContentType::InstalledGame,
+        ContentType::XboxOriginal,
+        ContentType::GamesOnDemand,
+        ContentType::AvatarItem,
+        ContentType::Profile,
+        ContentType::GamerPicture,
+        ContentType::Theme,
+        ContentType::CacheFile,
+        ContentType::StorageDownload,
+        ContentType::XboxSavedGame,
+        ContentType::XboxDownload,
+        ContentType::GameDemo,
+        ContentType::Video,
+        ContentType::GameTitle,
+        ContentType::Installer,
+        ContentType::GameTrailer,
+        ContentType::ArcadeTitle,
+        ContentType::Xna,
+        ContentType::LicenseStore,
+        ContentType::Movie,
+        ContentType::Tv,
+        ContentType::MusicVideo,
+        ContentType::GameVideo,
+        ContentType::PodcastVideo,
+        ContentType::ViralVideo,
+        ContentType::CommunityGame,
+    ];
+
+    /// Whether this is a whole game, stored as an SVOD disc image like the ones we write
+    pub fn is_disc_image(&self) -> bool {
+        matches!(
+            self,
+            ContentType::InstalledGame | ContentType::XboxOriginal | ContentType::GamesOnDemand
+        )
+    }
+}
+
+impl fmt::Display for ContentType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            ContentType::SavedGame => "Saved Game",
+            ContentType::MarketplaceContent => "Marketplace Content",
+            ContentType::Publisher => "Publisher",
+            ContentType::Xbox360Title => "Xbox 360 Title",
+            ContentType::IptvPauseBuffer => "IPTV Pause Buffer",
+            ContentType::InstalledGame => "Installed Game",
+            ContentType::XboxOriginal => "Xbox Original",
+            ContentType::GamesOnDemand => "Games on Demand",
+            ContentType::AvatarItem => "Avatar Item",
+            ContentType::Profile => "Profile",
+            ContentType::GamerPicture => "Gamer Picture",
+            ContentType::Theme => "Theme",
+            ContentType::CacheFile => "Cache File",
+            ContentType::StorageDownload => "Storage Download",
+            ContentType::XboxSavedGame => "Xbox Saved Game",
+            ContentType::XboxDownload => "Xbox Download",
+            ContentType::GameDemo => "Game Demo",
+            ContentType::Video => "Video",
+            ContentType::GameTitle => "Game Title",
+            ContentType::Installer => "Installer",
+            ContentType::GameTrailer => "Game Trailer",
+            ContentType::ArcadeTitle => "Arcade Title",
+            ContentType::Xna => "XNA",
+            ContentType::LicenseStore => "License Store",
+            ContentType::Movie => "Movie",
+            ContentType::Tv => "TV",
+            ContentType::MusicVideo => "Music Video",
+            ContentType::GameVideo => "Game Video",
+            ContentType::PodcastVideo => "Podcast Video",
+            ContentType::ViralVideo => "Viral Video",
+            ContentType::CommunityGame => "Community Game",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// Accepts the display names, ignoring case, spaces and dashes,
+/// and hexadecimal values like "00007000" or "0x7000".
+impl FromStr for ContentType {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<ContentType, Error> {
+        let normalize = |s: &str| -> String {
+            s.chars()
+                .filter(|c| c.is_ascii_alphanumeric())
+                .map(|c| c.to_ascii_lowercase())
+                .collect()
+        };
+
+        let wanted = normalize(s);
+        if let Some(content_type) = Self::ALL
+            .into_iter()
+            .find(|t| normalize(&t.to_string()) == wanted)
+        {
+            return Ok(content_type);
+        }
+
+        let hex = s.trim_start_matches("0x").trim_start_matches("0X");
+        let value =
+            u32::from_str_radix(hex, 16).with_context(|| format!("unknown content type {s:?}"))?;
+
+        ContentType::try_from(value).with_context(|| format!("unknown content type {value:08X}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn content_types_parse_from_names_and_values() {
+        for content_type in ContentType::ALL {
+            assert_eq!(
+                content_type.to_string().parse::<ContentType>().unwrap(),
+                content_type
+            );
+        }
+
+        assert_eq!(
+            "installed-game".parse::<ContentType>().unwrap(),
+            ContentType::InstalledGame
+        );
+        assert_eq!(
+            "00007000".parse::<ContentType>().unwrap(),
+            ContentType::GamesOnDemand
+        );
+        assert_eq!(
+            "0xD0000".parse::<ContentType>().unwrap(),
+            ContentType::ArcadeTitle
+        );
+        assert!("0x1234".parse::<ContentType>().is_err());
+    }
+}
@@ -4,6 +4,18 @@ use crate::executable::TitleExecutionInfo;
 
 use super::*;
 
+/// Part files are named like this inside the `.data` folder next to the header
+pub fn part_file_name(part_index: u64) -> String {
+    format!("Data{:04}", part_index)
+}
+
+/// The `.data` folder with the part files of the package with this header
+pub fn data_dir_for_header(header_path: &Path) -> PathBuf {
+    let mut data_dir = header_path.as_os_str().to_owned();
+    data_dir.push(".data");
+    PathBuf::from(data_dir)
+}
+
 pub struct FileLayout<'a> {
     base_path: &'a Path,
     exe_info: &'a TitleExecutionInfo,
@@ -33,12 +45,12 @@ impl<'a> FileLayout<'a> {
 
     fn media_id_string(&self) -> String {
         match self.content_type {
-            ContentType::GamesOnDemand => {
-                format!("{:08X}", self.exe_info.media_id)
-            }
             ContentType::XboxOriginal => {
                 format!("{:08X}", self.exe_info.title_id)
             }
+            _ => {
+                format!("{:08X}", self.exe_info.media_id)
+            }
         }
     }
 
@@ -50,7 +62,7 @@ impl<'a> FileLayout<'a> {
     }
 
     pub fn part_file_path(&'a self, part_index: u64) -> PathBuf {
-        self.data_dir_path().join(format!("Data{:04}", part_index))
+        self.data_dir_path().join(part_file_name(part_index))
     }
 
     pub fn con_header_file_path(&self) -> PathBuf {
@@ -0,0 +1,82 @@
+use std::io::Cursor;
+
+use anyhow::{Context, Error, bail};
+use image::codecs::png::{CompressionType, FilterType, PngEncoder};
+use image::{ImageFormat, RgbaImage, imageops};
+
+/// Dashboard icons are square images of this size
+pub const ICON_DIMENSION: u32 = 64;
+
+/// Each of the two image slots in the CON header is this large;
+/// with metadata version 2, the extra display strings follow each slot
+pub const ICON_SLOT_SIZE: usize = 0x3d00;
+
+/// Resizes `image` to the dashboard icon size and encodes it as a PNG
+/// that fits into a CON header image slot.
+pub fn encode_icon(image: &RgbaImage) -> Result<Vec<u8>, Error> {
+    let image = if image.dimensions() == (ICON_DIMENSION, ICON_DIMENSION) {
+        image.clone()
+    } else {
+        imageops::resize(
+            image,
+            ICON_DIMENSION,
+            ICON_DIMENSION,
+            imageops::FilterType::Triangle,
+        )
+    };
+
+    let mut png_bytes = Vec::new();
+    image.write_with_encoder(PngEncoder::new_with_quality(
+        Cursor::new(&mut png_bytes),
+        CompressionType::Best,
+        FilterType::Adaptive,
+    ))?;
+
+    if png_bytes.len() > ICON_SLOT_SIZE {
+        bail!(
+            "encoded icon is {} bytes, which does not fit into the {ICON_SLOT_SIZE} byte slot",
+            png_bytes.len()
+        );
+    }
+
+    Ok(png_bytes)
+}
+
+/// Decodes a PNG, JPEG or BMP image of any size, and encodes it like `encode_icon`
+pub fn load_icon(bytes: &[u8]) -> Result<Vec<u8>, Error> {
+    let format = image::guess_format(bytes).context("unrecognized image format")?;
+    if !matches!(
+        format,
+        ImageFormat::Png | ImageFormat::Jpeg | ImageFormat::Bmp
+    ) {
+        bail!("unsupported image format {format:?}, expected PNG, JPEG or BMP");
+    }
+
+    let image = image::load_from_memory_with_format(bytes, format)
+        .with_context(|| format!("error decoding {format:?} image"))?;
+
+    encode_icon(&image.to_rgba8())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn loads_and_shrinks_images() {
+        let mut bmp = Vec::new();
+        RgbaImage::from_fn(300, 200, |x, y| image::Rgba([x as u8, y as u8, 0x80, 0xff]))
+            .write_to(&mut Cursor::new(&mut bmp), ImageFormat::Bmp)
+            .unwrap();
+
+        let png = load_icon(&bmp).unwrap();
+        let icon = image::load_from_memory_with_format(&png, ImageFormat::Png).unwrap();
+        assert_eq!(
+            (icon.width(), icon.height()),
+            (ICON_DIMENSION, ICON_DIMENSION)
+        );
+
+        assert!(load_icon(b"not an image").is_err());
+        assert!(load_icon(&bmp[..bmp.len() / 2]).is_err());
+    }
+}
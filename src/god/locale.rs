@@ -0,0 +1,96 @@
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::{Error, bail};
+
+/// Languages of the per-language CON header strings, in slot order
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Locale {
+    English = 0,
+    Japanese,
+    German,
+    French,
+    Spanish,
+    Italian,
+    Korean,
+    TraditionalChinese,
+    Portuguese,
+    SimplifiedChinese,
+    Polish,
+    Russian,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 12] = [
+        Locale::English,
+        Locale::Japanese,
+        Locale::German,
+        Locale::French,
+        Locale::Spanish,
+        Locale::Italian,
+        Locale::Korean,
+        Locale::TraditionalChinese,
+        Locale::Portuguese,
+        Locale::SimplifiedChinese,
+        Locale::Polish,
+        Locale::Russian,
+    ];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Locale::English => "en",
+            Locale::Japanese => "ja",
+            Locale::German => "de",
+            Locale::French => "fr",
+            Locale::Spanish => "es",
+            Locale::Italian => "it",
+            Locale::Korean => "ko",
+            Locale::TraditionalChinese => "zh-TW",
+            Locale::Portuguese => "pt",
+            Locale::SimplifiedChinese => "zh-CN",
+            Locale::Polish => "pl",
+            Locale::Russian => "ru",
+        }
+    }
+
+    /// The first nine strings sit in one block; the last three were added
+    /// with metadata version 2, and live after the end of the first image slot.
+    pub(super) fn slot_offset(&self, first_block: usize, extra_block: usize) -> usize {
+        match *self as usize {
+            i @ 0..9 => first_block + i * 0x100,
+            i => extra_block + (i - 9) * 0x100,
+        }
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+/// Accepts language codes with an optional region, like "de" or "pt-BR"
+impl FromStr for Locale {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Locale, Error> {
+        let lower = s.to_ascii_lowercase().replace('_', "-");
+        let (language, region) = lower.split_once('-').unwrap_or((&lower, ""));
+
+        match language {
+            "en" => Ok(Locale::English),
+            "ja" => Ok(Locale::Japanese),
+            "de" => Ok(Locale::German),
+            "fr" => Ok(Locale::French),
+            "es" => Ok(Locale::Spanish),
+            "it" => Ok(Locale::Italian),
+            "ko" => Ok(Locale::Korean),
+            "zh" if matches!(region, "tw" | "hk" | "mo" | "hant") => Ok(Locale::TraditionalChinese),
+            "zh" => Ok(Locale::SimplifiedChinese),
+            "pt" => Ok(Locale::Portuguese),
+            "pl" => Ok(Locale::Polish),
+            "ru" => Ok(Locale::Russian),
+            _ => bail!("unsupported locale {s:?}"),
+        }
+    }
+}
@@ -0,0 +1,310 @@
+use std::fmt;
+use std::fs::{self, File};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Error};
+use serde::{Deserialize, Serialize};
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+
+use super::{ConHeader, data_dir_for_header, part_file_name};
+
+/// Sizes and digests of a single file, digests in lowercase hex
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileChecksums {
+    pub name: String,
+    pub size: u64,
+    pub sha1: String,
+    pub sha256: String,
+}
+
+impl FileChecksums {
+    pub fn compute(path: &Path) -> Result<FileChecksums, Error> {
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let file = File::open(path).with_context(|| format!("error opening {}", path.display()))?;
+        Self::compute_reader(name, file)
+            .with_context(|| format!("error reading {}", path.display()))
+    }
+
+    pub fn compute_reader<R: Read>(name: String, reader: R) -> Result<FileChecksums, Error> {
+        ChecksumReader::new(reader).finish(name)
+    }
+}
+
+/// Hashes everything read through it, so that a file can be checksummed
+/// on the way to somewhere else instead of being read twice
+pub struct ChecksumReader<R> {
+    inner: R,
+    sha1: Sha1,
+    sha256: Sha256,
+    size: u64,
+}
+
+impl<R: Read> ChecksumReader<R> {
+    pub fn new(inner: R) -> ChecksumReader<R> {
+        ChecksumReader {
+            inner,
+            sha1: Sha1::new(),
+            sha256: Sha256::new(),
+            size: 0,
+        }
+    }
+
+    /// Hashes the next `len` bytes, or up to the end, without passing them on
+    pub fn skip(&mut self, len: u64) -> Result<(), Error> {
+        let mut buf = vec![0_u8; 0x100000];
+        let mut left = len;
+        while left > 0 {
+            let chunk_len = left.min(buf.len() as u64) as usize;
+            let read = self.read(&mut buf[..chunk_len])?;
+            if read == 0 {
+                break;
+            }
+            left -= read as u64;
+        }
+        Ok(())
+    }
+
+    /// Hashes whatever is left, and returns the checksums of everything read
+    pub fn finish(mut self, name: String) -> Result<FileChecksums, Error> {
+        self.skip(u64::MAX)?;
+
+        Ok(FileChecksums {
+            name,
+            size: self.size,
+            sha1: to_hex(&self.sha1.finalize()),
+            sha256: to_hex(&self.sha256.finalize()),
+        })
+    }
+}
+
+impl<R: Read> Read for ChecksumReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let len = self.inner.read(buf)?;
+        self.sha1.update(&buf[..len]);
+        self.sha256.update(&buf[..len]);
+        self.size += len as u64;
+        Ok(len)
+    }
+}
+
+/// Checksums of a GOD package, kept next to its CON header, so that the
+/// package can be checked for bit rot without a full structural verify
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Manifest {
+    pub iso2god_version: String,
+    pub title_id: String,
+    /// The image the package was converted from, if it was hashed
+    pub source: Option<FileChecksums>,
+    /// As stored in the CON header
+    pub header_hash: String,
+    /// The root hash from the SVOD volume descriptor
+    pub mht_root: String,
+    pub header: FileChecksums,
+    pub parts: Vec<FileChecksums>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ManifestMismatch {
+    Missing(String),
+    Size {
+        name: String,
+        expected: u64,
+        actual: u64,
+    },
+    Checksum(String),
+    /// A part file the manifest does not know about
+    Unexpected(String),
+}
+
+impl fmt::Display for ManifestMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing(name) => write!(f, "{name} is missing"),
+            Self::Size {
+                name,
+                expected,
+                actual,
+            } => write!(f, "{name} is {actual} bytes long, expected {expected}"),
+            Self::Checksum(name) => write!(f, "{name} does not match its checksums"),
+            Self::Unexpected(name) => write!(f, "{name} is not in the manifest"),
+        }
+    }
+}
+
+impl Manifest {
+    /// Where the manifest of the package with this header is kept
+    pub fn path_for(header_path: &Path) -> PathBuf {
+        let mut path = header_path.as_os_str().to_owned();
+        path.push(".manifest.json");
+        PathBuf::from(path)
+    }
+
+    /// Hashes the header and the part files, one file at a time, so that hard drives
+    /// are read sequentially
+    pub fn create(header_path: &Path, source: Option<FileChecksums>) -> Result<Manifest, Error> {
+        let header_file = File::open(header_path).context("error opening con header file")?;
+        let header = ConHeader::read(header_file).context("error reading con header")?;
+
+        let mht_root = header.svod_volume_descriptor()?.root_hash;
+
+        let data_dir = data_dir_for_header(header_path);
+        let part_count = (0..)
+            .take_while(|i| data_dir.join(part_file_name(*i)).exists())
+            .count() as u64;
+
+        let parts = (0..part_count)
+            .map(|i| FileChecksums::compute(&data_dir.join(part_file_name(i))))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Manifest {
+            iso2god_version: env!("CARGO_PKG_VERSION").to_owned(),
+            title_id: format!("{:08X}", header.execution_info.title_id),
+            source,
+            header_hash: to_hex(&header.header_hash),
+            mht_root: to_hex(&mht_root),
+            header: FileChecksums::compute(header_path)?,
+            parts,
+        })
+    }
+
+    /// Takes in a header that was changed in place, like with `ConHeaderBuilder::from_header`
+    pub fn update_header(&mut self, header_path: &Path) -> Result<(), Error> {
+        let header_file = File::open(header_path).context("error opening con header file")?;
+        let header = ConHeader::read(header_file).context("error reading con header")?;
+
+        self.header_hash = to_hex(&header.header_hash);
+        self.header = FileChecksums::compute(header_path)?;
+        Ok(())
+    }
+
+    pub fn load(path: &Path) -> Result<Manifest, Error> {
+        let contents =
+            fs::read(path).with_context(|| format!("error reading {}", path.display()))?;
+        serde_json::from_slice(&contents)
+            .with_context(|| format!("error parsing {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        fs::write(path, serde_json::to_vec_pretty(self)?)
+            .with_context(|| format!("error writing {}", path.display()))
+    }
+
+    /// Rehashes the header and the part files, and lists everything that changed.
+    /// Sizes are compared first, so truncated files are reported without reading them.
+    pub fn check(&self, header_path: &Path) -> Result<Vec<ManifestMismatch>, Error> {
+        let data_dir = data_dir_for_header(header_path);
+
+        let files = [(header_path.to_owned(), &self.header)].into_iter().chain(
+            self.parts
+                .iter()
+                .enumerate()
+                .map(|(i, expected)| (data_dir.join(part_file_name(i as u64)), expected)),
+        );
+
+        let mut mismatches = files
+            .map(|(path, expected)| check_file(&path, expected))
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+
+        let extra_part = part_file_name(self.parts.len() as u64);
+        if data_dir.join(&extra_part).exists() {
+            mismatches.push(ManifestMismatch::Unexpected(extra_part));
+        }
+
+        Ok(mismatches)
+    }
+}
+
+fn check_file(path: &Path, expected: &FileChecksums) -> Result<Option<ManifestMismatch>, Error> {
+    let name = expected.name.clone();
+
+    let Ok(metadata) = path.metadata() else {
+        return Ok(Some(ManifestMismatch::Missing(name)));
+    };
+    if metadata.len() != expected.size {
+        return Ok(Some(ManifestMismatch::Size {
+            name,
+            expected: expected.size,
+            actual: metadata.len(),
+        }));
+    }
+
+    let actual = FileChecksums::compute(path)?;
+    if actual.sha1 != expected.sha1 || actual.sha256 != expected.sha256 {
+        return Ok(Some(ManifestMismatch::Checksum(name)));
+    }
+
+    Ok(None)
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::god::{ConHeaderBuilder, SvodVolumeDescriptor};
+
+    #[test]
+    fn detects_changed_and_truncated_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let header_path = dir.path().join("4D53B0A5");
+        let data_dir = data_dir_for_header(&header_path);
+        fs::create_dir_all(&data_dir).unwrap();
+
+        let header = ConHeaderBuilder::new()
+            .with_svod_volume_descriptor(&SvodVolumeDescriptor::new([1; 20], 2))
+            .finalize();
+        fs::write(&header_path, header).unwrap();
+        fs::write(data_dir.join(part_file_name(0)), [0x11; 0x3000]).unwrap();
+        fs::write(data_dir.join(part_file_name(1)), [0x22; 0x3000]).unwrap();
+
+        let manifest = Manifest::create(&header_path, None).unwrap();
+        assert_eq!(manifest.parts.len(), 2);
+        let clean = manifest.check(&header_path).unwrap();
+
+        fs::write(data_dir.join(part_file_name(0)), [0x11; 0x2000]).unwrap();
+        fs::write(data_dir.join(part_file_name(1)), [0x23; 0x3000]).unwrap();
+        fs::write(data_dir.join(part_file_name(2)), [0x33; 0x1000]).unwrap();
+        let dirty = manifest.check(&header_path).unwrap();
+
+        assert!(clean.is_empty());
+        assert_eq!(
+            dirty,
+            [
+                ManifestMismatch::Size {
+                    name: "Data0000".to_owned(),
+                    expected: 0x3000,
+                    actual: 0x2000
+                },
+                ManifestMismatch::Checksum("Data0001".to_owned()),
+                ManifestMismatch::Unexpected("Data0002".to_owned()),
+            ]
+        );
+    }
+
+    #[test]
+    fn checksums_what_is_read_through() {
+        let data: Vec<u8> = (0..0x3000_u32).map(|i| (i % 251) as u8).collect();
+
+        let mut reader = ChecksumReader::new(&data[..]);
+        reader.skip(0x1000).unwrap();
+        let mut middle = Vec::new();
+        (&mut reader).take(0x1000).read_to_end(&mut middle).unwrap();
+        let checksums = reader.finish("image.iso".to_owned()).unwrap();
+
+        assert_eq!(middle, data[0x1000..0x2000]);
+        assert_eq!(checksums.size, 0x3000);
+        assert_eq!(checksums.sha1, to_hex(&Sha1::digest(&data)));
+        assert_eq!(checksums.sha256, to_hex(&Sha256::digest(&data)));
+    }
+}
@@ -0,0 +1,297 @@
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::sync::Mutex;
+use std::sync::mpsc::{Receiver, SyncSender, sync_channel};
+use std::thread;
+
+use anyhow::{Error, bail};
+
+use super::{BLOCK_SIZE, HashList, SUBPART_SIZE, SUBPARTS_PER_PART};
+
+/// A subpart along with its sub hash table, as it goes from the hashing workers to the writer
+struct HashedSubpart {
+    data: Vec<u8>,
+    hash_list: HashList,
+    digest: [u8; 20],
+}
+
+type HashJob = (Vec<u8>, SyncSender<HashedSubpart>);
+
+/// Splits `data_volume` into GOD part files, and returns their linked master hash tables.
+///
+/// One thread reads `data_volume` strictly sequentially, `num_threads` workers hash
+/// the subparts, and the calling thread writes them out in order, so that neither
+/// the source nor the destination ever sees a seek storm. Part files come from
+/// `create_part_file`, and `on_part_written` is called as each one is done.
+pub fn write_parts<R, W>(
+    data_volume: R,
+    num_threads: usize,
+    mut create_part_file: impl FnMut(u64) -> Result<W, Error>,
+    mut on_part_written: impl FnMut(u64),
+) -> Result<Vec<HashList>, Error>
+where
+    R: Read + Send,
+    W: Write + Seek,
+{
+    let num_threads = num_threads.max(1);
+
+    // both queues are bounded, so that at most a few subparts per worker are in memory
+    let (job_tx, job_rx) = sync_channel::<HashJob>(num_threads);
+    let (order_tx, order_rx) = sync_channel::<Receiver<HashedSubpart>>(num_threads * 2);
+    let job_rx = Mutex::new(job_rx);
+
+    let (read, written) = thread::scope(|scope| {
+        let reader = scope.spawn(move || read_subparts(data_volume, job_tx, order_tx));
+
+        for _ in 0..num_threads {
+            scope.spawn(|| hash_subparts(&job_rx));
+        }
+
+        // returning early drops `order_rx`, which stops the reader, and then the workers
+        let written = write_subparts(order_rx, &mut create_part_file, &mut on_part_written);
+        let read = reader.join().expect("reader thread panicked");
+        (read, written)
+    });
+
+    read?;
+    let (part_files, mut master_hash_lists) = written?;
+
+    if part_files.is_empty() {
+        bail!("nothing to write, the data volume is empty");
+    }
+
+    link_master_hash_lists(&mut master_hash_lists);
+
+    for (part_file, master_hash_list) in part_files.into_iter().zip(&master_hash_lists) {
+        write_part_mht(part_file, master_hash_list)?;
+    }
+
+    Ok(master_hash_lists)
+}
+
+/// How much of the data volume to split into parts: `block_count` whole blocks,
+/// or up to the end of the source if it ends part-way through the last one
+pub fn data_volume_read_size(block_count: u64, source_size: u64) -> u64 {
+    (block_count * BLOCK_SIZE).min(source_size)
+}
+
+/// Each master hash table ends with the digest of the next part's one,
+/// so they are linked from the last part to the first
+pub fn link_master_hash_lists(master_hash_lists: &mut [HashList]) {
+    for i in (1..master_hash_lists.len()).rev() {
+        let next_digest = master_hash_lists[i].digest();
+        master_hash_lists[i - 1].add_hash(&next_digest);
+    }
+}
+
+/// Writes a linked master hash table into the room left for it at the start of the part file
+pub fn write_part_mht<W: Write + Seek>(
+    mut part_file: W,
+    master_hash_list: &HashList,
+) -> Result<(), Error> {
+    part_file.seek(SeekFrom::Start(0))?;
+    master_hash_list.write(part_file)
+}
+
+/// Hands out subparts in order; the writer gets a receiver for each one
+/// before any worker gets to hash it
+fn read_subparts<R: Read>(
+    mut data_volume: R,
+    job_tx: SyncSender<HashJob>,
+    order_tx: SyncSender<Receiver<HashedSubpart>>,
+) -> Result<(), Error> {
+    loop {
+        let mut data = Vec::with_capacity(SUBPART_SIZE as usize);
+        data_volume
+            .by_ref()
+            .take(SUBPART_SIZE)
+            .read_to_end(&mut data)?;
+
+        if data.is_empty() {
+            return Ok(());
+        }
+        let is_last = data.len() < SUBPART_SIZE as usize;
+
+        let (subpart_tx, subpart_rx) = sync_channel(1);
+        if order_tx.send(subpart_rx).is_err() || job_tx.send((data, subpart_tx)).is_err() {
+            // the writer gave up
+            return Ok(());
+        }
+
+        if is_last {
+            return Ok(());
+        }
+    }
+}
+
+fn hash_subparts(job_rx: &Mutex<Receiver<HashJob>>) {
+    loop {
+        let job = job_rx.lock().unwrap().recv();
+        let Ok((data, subpart_tx)) = job else {
+            return;
+        };
+
+        let mut hash_list = HashList::new();
+        for block in data.chunks(BLOCK_SIZE as usize) {
+            hash_list.add_block_hash(block);
+        }
+        let digest = hash_list.digest();
+
+        // nobody is waiting for it if the writer gave up
+        let _ = subpart_tx.send(HashedSubpart {
+            data,
+            hash_list,
+            digest,
+        });
+    }
+}
+
+fn write_subparts<W: Write + Seek>(
+    order_rx: Receiver<Receiver<HashedSubpart>>,
+    create_part_file: &mut impl FnMut(u64) -> Result<W, Error>,
+    on_part_written: &mut impl FnMut(u64),
+) -> Result<(Vec<W>, Vec<HashList>), Error> {
+    let mut part_files: Vec<W> = Vec::new();
+    let mut master_hash_lists: Vec<HashList> = Vec::new();
+
+    for (subpart_index, subpart_rx) in order_rx.into_iter().enumerate() {
+        let Ok(subpart) = subpart_rx.recv() else {
+            // a worker panicked, which the thread scope reports
+            break;
+        };
+
+        if subpart_index % SUBPARTS_PER_PART as usize == 0 {
+            let part_index = part_files.len() as u64;
+            if part_index > 0 {
+                on_part_written(part_index - 1);
+            }
+
+            let mut part_file = create_part_file(part_index)?;
+            // the master hash table goes first, once it is linked
+            part_file.seek(SeekFrom::Start(BLOCK_SIZE))?;
+            part_files.push(part_file);
+            master_hash_lists.push(HashList::new());
+        }
+
+        let part_file = part_files.last_mut().unwrap();
+        subpart.hash_list.write(&mut *part_file)?;
+        part_file.write_all(&subpart.data)?;
+
+        master_hash_lists
+            .last_mut()
+            .unwrap()
+            .add_hash(&subpart.digest);
+    }
+
+    if !part_files.is_empty() {
+        on_part_written(part_files.len() as u64 - 1);
+    }
+
+    Ok((part_files, master_hash_lists))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use sha1::{Digest, Sha1};
+
+    use super::*;
+
+    #[test]
+    fn master_hash_lists_link_back_to_front() {
+        let mut mhts: Vec<_> = (0..3_u8)
+            .map(|i| {
+                let mut mht = HashList::new();
+                mht.add_block_hash(&[i; 0x10]);
+                mht
+            })
+            .collect();
+        let last = mhts[2].bytes().to_owned();
+
+        link_master_hash_lists(&mut mhts);
+
+        assert_eq!(mhts[2].bytes(), &last);
+        assert_eq!(mhts[1].bytes()[20..40], mhts[2].digest());
+        assert_eq!(mhts[0].bytes()[20..40], mhts[1].digest());
+    }
+
+    #[test]
+    fn writes_subparts_in_order() {
+        // two full subparts and a short one, each with different contents
+        let data_volume: Vec<u8> = (0..2 * SUBPART_SIZE + 0x1800)
+            .map(|i| (i / SUBPART_SIZE) as u8 + 1)
+            .collect();
+
+        let dir = tempfile::tempdir().unwrap();
+        let part_path = dir.path().join("Data0000");
+
+        let mut written = Vec::new();
+        let mhts = write_parts(
+            Cursor::new(&data_volume),
+            4,
+            |_| Ok(std::fs::File::create(&part_path)?),
+            |part_index| written.push(part_index),
+        )
+        .unwrap();
+
+        let part_files = std::fs::read(&part_path).unwrap();
+
+        assert_eq!(written, [0]);
+        assert_eq!(mhts.len(), 1);
+        assert_eq!(part_files.len(), 0x1000 + 3 * 0x1000 + data_volume.len());
+        assert_eq!(part_files[..0x1000], mhts[0].bytes()[..]);
+
+        let subpart_stride = (BLOCK_SIZE + SUBPART_SIZE) as usize;
+        for (i, subpart) in data_volume.chunks(SUBPART_SIZE as usize).enumerate() {
+            let sub_hash_list = 0x1000 + i * subpart_stride;
+            let data = sub_hash_list + 0x1000;
+
+            assert_eq!(
+                part_files[sub_hash_list..sub_hash_list + 20],
+                Sha1::digest(&subpart[..0x1000])[..]
+            );
+            assert_eq!(part_files[data..data + subpart.len()], *subpart);
+            assert_eq!(
+                mhts[0].bytes()[i * 20..i * 20 + 20],
+                Sha1::digest(&part_files[sub_hash_list..data])[..]
+            );
+        }
+    }
+
+    #[test]
+    fn last_part_holds_whole_blocks() {
+        let source = vec![0x5a_u8; 3 * BLOCK_SIZE as usize];
+        let dir = tempfile::tempdir().unwrap();
+        let part_path = dir.path().join("Data0000");
+
+        // a trimmed volume ending part-way through its third block, read up to
+        // that block's end, or up to the source end if it ends there too
+        for (source_size, expected_data_size) in [(source.len(), 0x3000), (0x2005, 0x2005)] {
+            let block_count = 0x2005_u64.div_ceil(BLOCK_SIZE);
+            let read_size = data_volume_read_size(block_count, source_size as u64);
+
+            write_parts(
+                Cursor::new(&source[..source_size]).take(read_size),
+                2,
+                |_| Ok(std::fs::File::create(&part_path)?),
+                |_| {},
+            )
+            .unwrap();
+
+            // a master and a sub hash table ahead of the data
+            let part_size = std::fs::metadata(&part_path).unwrap().len();
+            assert_eq!(part_size, 2 * BLOCK_SIZE + expected_data_size);
+        }
+    }
+
+    #[test]
+    fn refuses_empty_volumes() {
+        let result = write_parts(
+            Cursor::new(Vec::new()),
+            2,
+            |_| Ok(Cursor::new(Vec::new())),
+            |_| {},
+        );
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,174 @@
+use std::fs::File;
+use std::path::Path;
+
+use anyhow::{Context, Error, bail};
+use bitflags::bitflags;
+use byteorder::{BE, ByteOrder};
+
+use super::{BLOCK_SIZE, HashList, SUBPART_SIZE, VOLUME_DESCRIPTOR_SIZE, part_file_name};
+
+bitflags! {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct SvodFeatures: u8 {
+        const ENHANCED_GDF_LAYOUT = 0x40;
+        const SHOULD_BE_ZERO_FOR_DOWNLEVEL_CLIENTS = 0x80;
+    }
+}
+
+/// The SVOD flavour of the CON header volume descriptor, as used by GOD packages
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SvodVolumeDescriptor {
+    pub block_cache_element_count: u8,
+    pub worker_thread_processor: u8,
+    pub worker_thread_priority: u8,
+    /// SHA-1 of the master hash table of the first part
+    pub root_hash: [u8; 20],
+    pub device_features: SvodFeatures,
+    /// Data blocks in all the parts, not counting hash tables
+    pub data_block_count: u32,
+    pub data_block_offset: u32,
+}
+
+impl SvodVolumeDescriptor {
+    /// With the cache and worker thread settings of the header template
+    pub fn new(root_hash: [u8; 20], data_block_count: u32) -> SvodVolumeDescriptor {
+        SvodVolumeDescriptor {
+            block_cache_element_count: 0x05,
+            worker_thread_processor: 0x05,
+            worker_thread_priority: 0x11,
+            root_hash,
+            device_features: SvodFeatures::empty(),
+            data_block_count,
+            data_block_offset: 0,
+        }
+    }
+
+    pub fn parse(bytes: &[u8; VOLUME_DESCRIPTOR_SIZE]) -> Result<SvodVolumeDescriptor, Error> {
+        if bytes[0] as usize != VOLUME_DESCRIPTOR_SIZE {
+            bail!("invalid SVOD volume descriptor size {:#x}", bytes[0]);
+        }
+
+        Ok(SvodVolumeDescriptor {
+            block_cache_element_count: bytes[0x01],
+            worker_thread_processor: bytes[0x02],
+            worker_thread_priority: bytes[0x03],
+            root_hash: bytes[0x04..0x18].try_into().unwrap(),
+            device_features: SvodFeatures::from_bits_retain(bytes[0x18]),
+            // big-endian, as this tool has always written them
+            data_block_count: BE::read_u24(&bytes[0x19..]),
+            data_block_offset: BE::read_u24(&bytes[0x1c..]),
+        })
+    }
+
+    pub fn to_bytes(&self) -> [u8; VOLUME_DESCRIPTOR_SIZE] {
+        let mut bytes = [0_u8; VOLUME_DESCRIPTOR_SIZE];
+        bytes[0x00] = VOLUME_DESCRIPTOR_SIZE as u8;
+        bytes[0x01] = self.block_cache_element_count;
+        bytes[0x02] = self.worker_thread_processor;
+        bytes[0x03] = self.worker_thread_priority;
+        bytes[0x04..0x18].copy_from_slice(&self.root_hash);
+        bytes[0x18] = self.device_features.bits();
+        BE::write_u24(&mut bytes[0x19..], self.data_block_count);
+        BE::write_u24(&mut bytes[0x1c..], self.data_block_offset);
+        bytes
+    }
+
+    /// Checks the root hash and the block count against the part files in `data_dir`
+    pub fn validate_parts(&self, data_dir: &Path) -> Result<(), Error> {
+        let first_part_path = data_dir.join(part_file_name(0));
+        let mut first_part = File::open(&first_part_path)
+            .with_context(|| format!("error opening {}", first_part_path.display()))?;
+        let master_hash_list =
+            HashList::read(&mut first_part).context("error reading part file MHT")?;
+
+        if master_hash_list.digest() != self.root_hash {
+            bail!("root hash does not match the first part file");
+        }
+
+        let mut data_size = 0;
+        for part_index in 0.. {
+            let part_path = data_dir.join(part_file_name(part_index));
+            let Ok(metadata) = part_path.metadata() else {
+                break;
+            };
+            data_size += part_data_size(metadata.len())
+                .with_context(|| format!("{} is truncated", part_path.display()))?;
+        }
+
+        let data_block_count = data_size.div_ceil(BLOCK_SIZE);
+        if data_block_count != self.data_block_count as u64 {
+            bail!(
+                "part files hold {data_block_count} data blocks, but the header says {}",
+                self.data_block_count
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// The size of a part file without the master hash table and the sub hash tables
+fn part_data_size(part_size: u64) -> Option<u64> {
+    let subparts_size = part_size.checked_sub(BLOCK_SIZE)?;
+    let subpart_count = subparts_size.div_ceil(BLOCK_SIZE + SUBPART_SIZE);
+    subparts_size.checked_sub(subpart_count * BLOCK_SIZE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_bytes() {
+        let descriptor = SvodVolumeDescriptor::new([0x42; 20], 0x012345);
+        let bytes = descriptor.to_bytes();
+        assert_eq!(bytes[0x00..0x04], [0x24, 0x05, 0x05, 0x11]);
+        assert_eq!(SvodVolumeDescriptor::parse(&bytes).unwrap(), descriptor);
+    }
+
+    #[test]
+    fn validates_against_part_files() {
+        let data_dir = tempfile::tempdir().unwrap();
+        let data_dir = data_dir.path();
+
+        let data_volume = vec![0x5a_u8; (SUBPART_SIZE + 0x1800) as usize];
+        let mhts = crate::god::write_parts(
+            std::io::Cursor::new(&data_volume),
+            1,
+            |i| Ok(File::create(data_dir.join(part_file_name(i)))?),
+            |_| {},
+        )
+        .unwrap();
+
+        let root_hash = mhts[0].digest();
+        let block_count = (data_volume.len() as u64).div_ceil(BLOCK_SIZE) as u32;
+
+        let good = SvodVolumeDescriptor::new(root_hash, block_count);
+        let bad_count = SvodVolumeDescriptor::new(root_hash, block_count + 1);
+        let bad_hash = SvodVolumeDescriptor::new([0; 20], block_count);
+
+        let results = [
+            good.validate_parts(data_dir),
+            bad_count.validate_parts(data_dir),
+            bad_hash.validate_parts(data_dir),
+        ];
+
+        assert!(results[0].is_ok());
+        assert!(results[1].is_err());
+        assert!(results[2].is_err());
+    }
+
+    #[test]
+    fn part_data_size_skips_hash_tables() {
+        let full_subpart = BLOCK_SIZE + SUBPART_SIZE;
+        assert_eq!(
+            part_data_size(BLOCK_SIZE + full_subpart),
+            Some(SUBPART_SIZE)
+        );
+        assert_eq!(
+            part_data_size(BLOCK_SIZE + full_subpart + BLOCK_SIZE + 0x800),
+            Some(SUBPART_SIZE + 0x800)
+        );
+        assert_eq!(part_data_size(0x800), None);
+    }
+}
@@ -35,7 +35,7 @@ impl<R: Read + Seek> IsoReader<R> {
         Ok(&mut self.reader)
     }
 
-    pub fn get_entry(&mut self, path: &WindowsPath) -> Result<Option<&mut R>, Error> {
+    pub fn find_entry(&self, path: &WindowsPath) -> Option<&DirectoryEntry> {
         let mut entry: Option<&DirectoryEntry> = None;
         let mut dir = Some(&self.directory_table);
 
@@ -44,7 +44,11 @@ impl<R: Read + Seek> IsoReader<R> {
             dir = entry.and_then(|entry| entry.subdirectory.as_ref());
         }
 
-        if let Some(entry) = entry {
+        entry
+    }
+
+    pub fn get_entry(&mut self, path: &WindowsPath) -> Result<Option<&mut R>, Error> {
+        if let Some(entry) = self.find_entry(path) {
             let position = self.volume_descriptor.root_offset
                 + (entry.sector as u64) * self.volume_descriptor.sector_size;
 
@@ -56,6 +60,20 @@ impl<R: Read + Seek> IsoReader<R> {
         }
     }
 
+    /// Reads the whole contents of a file into memory.
+    pub fn read_entry(&mut self, path: &WindowsPath) -> Result<Option<Vec<u8>>, Error> {
+        let Some(size) = self.find_entry(path).map(|e| e.size) else {
+            return Ok(None);
+        };
+
+        let mut buf = vec![0_u8; size as usize];
+        if let Some(reader) = self.get_entry(path)? {
+            reader.read_exact(&mut buf)?;
+        }
+
+        Ok(Some(buf))
+    }
+
     pub fn get_max_used_prefix_size(&self) -> u64 {
         // The data volume must contain the volume descriptor and the root
         // directory table, and neither appears as a directory entry - so
@@ -2,3 +2,5 @@ pub mod executable;
 pub mod game_list;
 pub mod god;
 pub mod iso;
+pub mod metadata;
+pub mod stfs;
@@ -0,0 +1,168 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+
+use anyhow::{Context, Error};
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+/// Stores the answers of a slow provider on disk, one small JSON file
+/// per title ID. Misses are cached too, so that unknown titles are not
+/// queried over and over again, but only for [`MISS_TTL`], since the
+/// provider may learn about them later.
+pub struct CachingProvider<P: TitleMetadataProvider> {
+    inner: P,
+    cache_dir: PathBuf,
+}
+
+/// How long a title the provider did not know is left alone before asking again
+pub const MISS_TTL: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+#[derive(Serialize, Deserialize)]
+struct CacheEntry {
+    /// Seconds since the Unix epoch; entries written before this was stored read as expired
+    #[serde(default)]
+    cached_at: u64,
+    name: Option<String>,
+    title_type: Option<String>,
+    #[serde(default)]
+    localized_names: BTreeMap<String, String>,
+}
+
+impl<P: TitleMetadataProvider> CachingProvider<P> {
+    pub fn new(inner: P, cache_dir: PathBuf) -> CachingProvider<P> {
+        CachingProvider { inner, cache_dir }
+    }
+
+    fn cache_file_path(&self, title_id: u32) -> PathBuf {
+        let provider_dir: String = self
+            .inner
+            .name()
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+
+        self.cache_dir
+            .join(provider_dir)
+            .join(format!("{title_id:08X}.json"))
+    }
+
+    fn read_cache(&self, title_id: u32) -> Option<Option<TitleMetadata>> {
+        let contents = fs::read(self.cache_file_path(title_id)).ok()?;
+        let entry: CacheEntry = serde_json::from_slice(&contents).ok()?;
+
+        if entry.name.is_none() {
+            let cached_at = SystemTime::UNIX_EPOCH + Duration::from_secs(entry.cached_at);
+            let age = SystemTime::now()
+                .duration_since(cached_at)
+                .unwrap_or_default();
+            if age >= MISS_TTL {
+                return None;
+            }
+        }
+
+        Some(entry.name.map(|name| {
+            TitleMetadata {
+                title_id,
+                name,
+                title_type: entry.title_type.and_then(|t| t.parse().ok()),
+                localized_names: entry
+                    .localized_names
+                    .into_iter()
+                    .filter_map(|(locale, name)| Some((locale.parse().ok()?, name)))
+                    .collect(),
+            }
+        }))
+    }
+
+    fn write_cache(&self, title_id: u32, metadata: Option<&TitleMetadata>) -> Result<(), Error> {
+        let path = self.cache_file_path(title_id);
+        fs::create_dir_all(path.parent().unwrap())?;
+
+        let cached_at = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+
+        let entry = CacheEntry {
+            cached_at,
+            name: metadata.map(|m| m.name.clone()),
+            title_type: metadata.and_then(|m| m.title_type).map(|t| t.to_string()),
+            localized_names: metadata
+                .map(|m| {
+                    m.localized_names
+                        .iter()
+                        .map(|(locale, name)| (locale.to_string(), name.clone()))
+                        .collect()
+                })
+                .unwrap_or_default(),
+        };
+
+        fs::write(path, serde_json::to_vec(&entry)?)?;
+        Ok(())
+    }
+}
+
+impl<P: TitleMetadataProvider> TitleMetadataProvider for CachingProvider<P> {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn lookup(&self, title_id: u32) -> Result<Option<TitleMetadata>, Error> {
+        if let Some(cached) = self.read_cache(title_id) {
+            return Ok(cached);
+        }
+
+        let metadata = self.inner.lookup(title_id)?;
+        self.write_cache(title_id, metadata.as_ref())
+            .context("error writing title metadata cache")?;
+
+        Ok(metadata)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    struct CountingProvider {
+        lookups: Cell<u32>,
+    }
+
+    impl TitleMetadataProvider for CountingProvider {
+        fn name(&self) -> &str {
+            "counting"
+        }
+
+        fn lookup(&self, _title_id: u32) -> Result<Option<TitleMetadata>, Error> {
+            self.lookups.set(self.lookups.get() + 1);
+            Ok(None)
+        }
+    }
+
+    #[test]
+    fn misses_expire() {
+        let cache_dir = tempfile::tempdir().unwrap();
+        let provider = CachingProvider::new(
+            CountingProvider {
+                lookups: Cell::new(0),
+            },
+            cache_dir.path().to_path_buf(),
+        );
+
+        assert!(provider.lookup(0x4D5307E6).unwrap().is_none());
+        assert!(provider.lookup(0x4D5307E6).unwrap().is_none());
+        assert_eq!(provider.inner.lookups.get(), 1);
+
+        let path = provider.cache_file_path(0x4D5307E6);
+        let mut entry: CacheEntry = serde_json::from_slice(&fs::read(&path).unwrap()).unwrap();
+        entry.cached_at -= MISS_TTL.as_secs();
+        fs::write(&path, serde_json::to_vec(&entry).unwrap()).unwrap();
+
+        assert!(provider.lookup(0x4D5307E6).unwrap().is_none());
+        assert_eq!(provider.inner.lookups.get(), 2);
+    }
+}
@@ -0,0 +1,148 @@
+use std::time::Duration;
+
+use anyhow::Error;
+use reqwest::blocking as http;
+use serde::Deserialize;
+
+use super::*;
+
+pub const XBOX_UNITY_URL: &str = "http://xboxunity.net/";
+
+/// Queries an XboxUnity-compatible title search API.
+pub struct HttpProvider {
+    client: http::Client,
+    base_url: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct TitleList {
+    items: Vec<Title>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct Title {
+    #[serde(rename = "TitleID")]
+    title_id: String,
+    name: String,
+    title_type: String,
+}
+
+impl HttpProvider {
+    /// `base_url` is the server root, such as `XBOX_UNITY_URL`
+    pub fn new(base_url: &str) -> Result<HttpProvider, Error> {
+        let client = http::Client::builder()
+            .connect_timeout(Duration::from_secs(10))
+            .timeout(Duration::from_secs(30))
+            .user_agent(format!(
+                "{} / {} {}",
+                env!("CARGO_PKG_NAME"),
+                env!("CARGO_PKG_VERSION"),
+                env!("CARGO_PKG_REPOSITORY"),
+            ))
+            .build()?;
+
+        Ok(HttpProvider {
+            client,
+            base_url: base_url.trim_end_matches('/').to_owned(),
+        })
+    }
+}
+
+impl TitleMetadataProvider for HttpProvider {
+    fn name(&self) -> &str {
+        "XboxUnity"
+    }
+
+    fn lookup(&self, title_id: u32) -> Result<Option<TitleMetadata>, Error> {
+        let title_id_str = format!("{title_id:08X}");
+
+        let title_list: TitleList = self
+            .client
+            .get(format!("{}/Resources/Lib/TitleList.php", self.base_url))
+            .query(&[
+                ("search", title_id_str.as_str()),
+                ("page", "0"),
+                ("count", "10"),
+                ("sort", "3"),
+                ("direction", "1"),
+                ("category", "0"),
+                ("filter", "0"),
+            ])
+            .send()?
+            .error_for_status()?
+            .json()?;
+
+        // the search is fuzzy, and the same ID may be listed for several platforms
+        let best = title_list
+            .items
+            .into_iter()
+            .filter(|t| t.title_id.eq_ignore_ascii_case(&title_id_str))
+            .map(|t| TitleMetadata {
+                title_id,
+                name: t.name.trim().to_owned(),
+                title_type: t.title_type.parse().ok(),
+                localized_names: Vec::new(),
+            })
+            .min_by_key(|m| match m.title_type {
+                Some(TitleType::Xbox360) => 0,
+                Some(TitleType::Xbla) => 1,
+                _ => 2,
+            });
+
+        Ok(best)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    #[test]
+    fn queries_a_mock_server() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}/", listener.local_addr().unwrap());
+
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+
+            let mut request_line = String::new();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            reader.read_line(&mut request_line).unwrap();
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line == "\r\n" {
+                    break;
+                }
+            }
+
+            let body = r#"{"Items":[
+                {"TitleID":"4D5307E6","Name":"Halo 3 (XBLA)","TitleType":"XBLA"},
+                {"TitleID":"4D5307E6","Name":"Halo 3","TitleType":"360"},
+                {"TitleID":"4D5307E7","Name":"Something Else","TitleType":"360"}
+            ]}"#;
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+
+            request_line
+        });
+
+        let provider = HttpProvider::new(&base_url).unwrap();
+        let metadata = provider.lookup(0x4D5307E6).unwrap().unwrap();
+
+        assert_eq!(metadata.name, "Halo 3");
+        assert_eq!(metadata.title_type, Some(TitleType::Xbox360));
+
+        let request_line = server.join().unwrap();
+        assert!(request_line.starts_with("GET /Resources/Lib/TitleList.php?search=4D5307E6&"));
+    }
+}
@@ -0,0 +1,176 @@
+use anyhow::Error;
+
+use crate::executable::TitleInfo;
+use crate::game_list::{self, GameListEntry, TitleDatabase, TitleType};
+use crate::god::Locale;
+
+mod cache;
+pub use cache::*;
+
+#[cfg(feature = "online")]
+mod http;
+#[cfg(feature = "online")]
+pub use http::*;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TitleMetadata {
+    pub title_id: u32,
+    pub name: String,
+    pub title_type: Option<TitleType>,
+    pub localized_names: Vec<(Locale, String)>,
+}
+
+impl TitleMetadata {
+    /// The name for `locale`, falling back to the default one
+    pub fn name_for(&self, locale: Option<Locale>) -> &str {
+        locale
+            .and_then(|locale| self.localized_names.iter().find(|(l, _)| *l == locale))
+            .map(|(_, name)| name.as_str())
+            .unwrap_or(&self.name)
+    }
+
+    /// Takes names for the locales this metadata has none for, like from the executable
+    pub fn add_missing_localized_names(&mut self, names: &[(Locale, String)]) {
+        for (locale, name) in names {
+            if !self.localized_names.iter().any(|(l, _)| l == locale) {
+                self.localized_names.push((*locale, name.clone()));
+            }
+        }
+    }
+}
+
+impl From<&GameListEntry> for TitleMetadata {
+    fn from(entry: &GameListEntry) -> TitleMetadata {
+        TitleMetadata {
+            title_id: entry.title_id,
+            name: entry.name.to_string(),
+            title_type: entry.title_type,
+            localized_names: entry
+                .localized_names
+                .iter()
+                .map(|(locale, name)| (*locale, name.to_string()))
+                .collect(),
+        }
+    }
+}
+
+/// A source of title names and other metadata, keyed by title ID
+pub trait TitleMetadataProvider {
+    /// Human-readable name, used to report where the metadata came from
+    fn name(&self) -> &str;
+
+    /// `Ok(None)` means the provider simply does not know this title
+    fn lookup(&self, title_id: u32) -> Result<Option<TitleMetadata>, Error>;
+}
+
+/// The title list embedded into the binary
+pub struct BuiltInProvider;
+
+impl TitleMetadataProvider for BuiltInProvider {
+    fn name(&self) -> &str {
+        "built-in database"
+    }
+
+    fn lookup(&self, title_id: u32) -> Result<Option<TitleMetadata>, Error> {
+        Ok(game_list::find_entry_by_id(title_id).map(TitleMetadata::from))
+    }
+}
+
+/// User-supplied title database files; built-in entries are left to `BuiltInProvider`
+pub struct TitleDatabaseProvider {
+    pub title_db: TitleDatabase,
+}
+
+impl TitleMetadataProvider for TitleDatabaseProvider {
+    fn name(&self) -> &str {
+        "title database file"
+    }
+
+    fn lookup(&self, title_id: u32) -> Result<Option<TitleMetadata>, Error> {
+        Ok(self
+            .title_db
+            .find_overlay_entry_by_id(title_id)
+            .map(TitleMetadata::from))
+    }
+}
+
+/// Metadata embedded into the executable itself: the title names of the XEX
+/// SPA resource, or the title name of the XBE certificate.
+pub struct ExecutableProvider {
+    metadata: Option<TitleMetadata>,
+}
+
+impl ExecutableProvider {
+    pub fn new(title_info: &TitleInfo) -> ExecutableProvider {
+        let spa = title_info.spa.as_ref();
+        let spa_name = spa.and_then(|spa| spa.title_name());
+
+        let metadata = spa_name
+            .or(title_info.executable.title_name())
+            .map(|name| TitleMetadata {
+                title_id: title_info.execution_info.title_id,
+                name: name.to_owned(),
+                title_type: None,
+                localized_names: spa.map(|spa| spa.title_names.clone()).unwrap_or_default(),
+            });
+
+        ExecutableProvider { metadata }
+    }
+}
+
+impl TitleMetadataProvider for ExecutableProvider {
+    fn name(&self) -> &str {
+        "executable"
+    }
+
+    fn lookup(&self, title_id: u32) -> Result<Option<TitleMetadata>, Error> {
+        Ok(self.metadata.clone().filter(|m| m.title_id == title_id))
+    }
+}
+
+/// Asks each provider in turn, in the order they were added.
+#[derive(Default)]
+pub struct ProviderChain {
+    providers: Vec<Box<dyn TitleMetadataProvider>>,
+}
+
+/// Metadata along with the name of the provider that supplied it
+pub struct ProviderAnswer {
+    pub metadata: TitleMetadata,
+    pub provider: String,
+}
+
+impl ProviderChain {
+    pub fn new() -> ProviderChain {
+        Default::default()
+    }
+
+    pub fn with_provider<P: TitleMetadataProvider + 'static>(mut self, provider: P) -> Self {
+        self.providers.push(Box::new(provider));
+        self
+    }
+
+    /// A failing provider does not stop the chain; its error is only
+    /// returned if no other provider knows the title either.
+    pub fn lookup(&self, title_id: u32) -> Result<Option<ProviderAnswer>, Error> {
+        let mut last_error = None;
+
+        for provider in self.providers.iter() {
+            match provider.lookup(title_id) {
+                Ok(Some(metadata)) => {
+                    return Ok(Some(ProviderAnswer {
+                        metadata,
+                        provider: provider.name().to_owned(),
+                    }));
+                }
+                Ok(None) => {}
+                Err(e) => last_error = Some(e),
+            }
+        }
+
+        match last_error {
+            Some(e) => Err(e),
+            None => Ok(None),
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use anyhow::{Error, bail};
+use bitflags::bitflags;
+use byteorder::{BE, ByteOrder, LE};
+
+pub const FILE_ENTRY_SIZE: usize = 0x40;
+pub const FILE_ENTRIES_PER_BLOCK: usize = 0x40;
+pub const MAX_NAME_LENGTH: usize = 0x28;
+
+/// `parent` value of the entries in the root directory
+pub const ROOT_DIRECTORY: u16 = 0xffff;
+
+bitflags! {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct FileEntryFlags: u8 {
+        /// The data blocks follow each other, so the hash table links need not be followed
+        const CONSECUTIVE = 0x40;
+        const DIRECTORY = 0x80;
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StfsFileEntry {
+    pub name: String,
+    pub flags: FileEntryFlags,
+    pub block_count: u32,
+    pub first_block: u32,
+    /// Index of the parent directory entry in the file table, or `ROOT_DIRECTORY`
+    pub parent: u16,
+    pub size: u32,
+    /// FAT timestamps
+    pub update_time: u32,
+    pub access_time: u32,
+}
+
+impl StfsFileEntry {
+    /// Returns `None` for the empty entry that ends the table
+    pub fn parse(bytes: &[u8]) -> Result<Option<StfsFileEntry>, Error> {
+        let name_length = (bytes[0x28] & 0x3f) as usize;
+        if name_length == 0 {
+            return Ok(None);
+        }
+        if name_length > MAX_NAME_LENGTH {
+            bail!("invalid file name length {name_length}");
+        }
+
+        Ok(Some(StfsFileEntry {
+            name: String::from_utf8_lossy(&bytes[..name_length]).into_owned(),
+            flags: FileEntryFlags::from_bits_truncate(bytes[0x28]),
+            block_count: LE::read_u24(&bytes[0x29..]),
+            first_block: LE::read_u24(&bytes[0x2f..]),
+            parent: BE::read_u16(&bytes[0x32..]),
+            size: BE::read_u32(&bytes[0x34..]),
+            update_time: BE::read_u32(&bytes[0x38..]),
+            access_time: BE::read_u32(&bytes[0x3c..]),
+        }))
+    }
+
+    pub fn write(&self, bytes: &mut [u8]) -> Result<(), Error> {
+        let name = self.name.as_bytes();
+        if name.is_empty() || name.len() > MAX_NAME_LENGTH {
+            bail!(
+                "file name {:?} is not 1 to {MAX_NAME_LENGTH} bytes long",
+                self.name
+            );
+        }
+
+        bytes[..FILE_ENTRY_SIZE].fill(0);
+        bytes[..name.len()].copy_from_slice(name);
+        bytes[0x28] = self.flags.bits() | name.len() as u8;
+        // the block count is stored twice, as allocated and as valid blocks
+        LE::write_u24(&mut bytes[0x29..], self.block_count);
+        LE::write_u24(&mut bytes[0x2c..], self.block_count);
+        LE::write_u24(&mut bytes[0x2f..], self.first_block);
+        BE::write_u16(&mut bytes[0x32..], self.parent);
+        BE::write_u32(&mut bytes[0x34..], self.size);
+        BE::write_u32(&mut bytes[0x38..], self.update_time);
+        BE::write_u32(&mut bytes[0x3c..], self.access_time);
+        Ok(())
+    }
+
+    pub fn is_directory(&self) -> bool {
+        self.flags.contains(FileEntryFlags::DIRECTORY)
+    }
+}
@@ -0,0 +1,144 @@
+//! Where blocks live in an STFS volume.
+//!
+//! Data blocks are numbered without the hash tables, which are interleaved
+//! with them: a level 0 table before each run of 0xaa data blocks, a level 1
+//! table after the first such run of each 0xaa runs, and a single level 2
+//! table after the first level 1 group. Writable packages keep two copies of
+//! each table, so every table takes up two blocks there.
+
+use byteorder::{BE, ByteOrder};
+
+pub const BLOCK_SIZE: u64 = 0x1000;
+pub const HASHES_PER_TABLE: u32 = 0xaa;
+pub const HASH_ENTRY_SIZE: usize = 0x18;
+
+const LEVEL_1_SPAN: u32 = HASHES_PER_TABLE * HASHES_PER_TABLE;
+
+/// A hash table entry; in level 0 tables, it also links the data blocks of a file
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HashEntry {
+    pub hash: [u8; 20],
+    /// In level 0 tables, 0x80 means the block is in use; in upper levels,
+    /// 0x40 selects the active copy of the lower table.
+    pub status: u8,
+    /// In level 0 tables, the next data block of the file, or 0xffffff
+    pub next_block: u32,
+}
+
+impl HashEntry {
+    pub const USED: u8 = 0x80;
+    pub const ACTIVE_COPY: u8 = 0x40;
+    pub const END_OF_CHAIN: u32 = 0xff_ffff;
+
+    pub fn parse(bytes: &[u8]) -> HashEntry {
+        HashEntry {
+            hash: bytes[0x00..0x14].try_into().unwrap(),
+            status: bytes[0x14],
+            next_block: BE::read_u24(&bytes[0x15..]),
+        }
+    }
+
+    pub fn write(&self, bytes: &mut [u8]) {
+        bytes[0x00..0x14].copy_from_slice(&self.hash);
+        bytes[0x14] = self.status;
+        BE::write_u24(&mut bytes[0x15..], self.next_block);
+    }
+}
+
+/// Block arithmetic for one package flavour
+#[derive(Clone, Copy, Debug)]
+pub struct BlockLayout {
+    /// log2 of the number of copies of each hash table
+    copies_shift: u32,
+}
+
+impl BlockLayout {
+    pub fn new(read_only: bool) -> BlockLayout {
+        BlockLayout {
+            copies_shift: if read_only { 0 } else { 1 },
+        }
+    }
+
+    pub fn table_copies(&self) -> u32 {
+        1 << self.copies_shift
+    }
+
+    /// Backing blocks between two level 0 tables, and between two level 1 tables
+    fn steps(&self) -> (u32, u32) {
+        let level_0_step = HASHES_PER_TABLE + self.table_copies();
+        let level_1_step = HASHES_PER_TABLE * level_0_step + self.table_copies();
+        (level_0_step, level_1_step)
+    }
+
+    /// Level of the topmost hash table, which depends on the total block count
+    pub fn top_level(&self, block_count: u32) -> u32 {
+        if block_count <= HASHES_PER_TABLE {
+            0
+        } else if block_count <= LEVEL_1_SPAN {
+            1
+        } else {
+            2
+        }
+    }
+
+    /// Position of a data block among all blocks, hash tables included
+    pub fn data_block_position(&self, block: u32) -> u32 {
+        let s = self.copies_shift;
+        let mut position = (((block + HASHES_PER_TABLE) / HASHES_PER_TABLE) << s) + block;
+        if block >= HASHES_PER_TABLE {
+            position += ((block + LEVEL_1_SPAN) / LEVEL_1_SPAN) << s;
+        }
+        if block >= LEVEL_1_SPAN {
+            position += 1 << s;
+        }
+        position
+    }
+
+    /// Position of the first copy of the hash table at `level` covering data block `block`
+    pub fn hash_table_position(&self, block: u32, level: u32) -> u32 {
+        let s = self.copies_shift;
+        let (level_0_step, level_1_step) = self.steps();
+
+        match level {
+            0 if block < HASHES_PER_TABLE => 0,
+            0 => {
+                let mut position = (block / HASHES_PER_TABLE) * level_0_step;
+                position += ((block / LEVEL_1_SPAN) + 1) << s;
+                if block >= LEVEL_1_SPAN {
+                    position += 1 << s;
+                }
+                position
+            }
+            1 if block < LEVEL_1_SPAN => level_0_step,
+            1 => (1 << s) + (block / LEVEL_1_SPAN) * level_1_step,
+            _ => level_1_step,
+        }
+    }
+
+    /// Index of the entry for `block` within its table at `level`
+    pub fn hash_entry_index(&self, block: u32, level: u32) -> usize {
+        let span = HASHES_PER_TABLE.pow(level);
+        ((block / span) % HASHES_PER_TABLE) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn data_blocks_skip_hash_tables() {
+        let read_only = BlockLayout::new(true);
+        assert_eq!(read_only.data_block_position(0), 1);
+        assert_eq!(read_only.data_block_position(0xa9), 0xaa);
+        assert_eq!(read_only.hash_table_position(0, 1), 0xab);
+        assert_eq!(read_only.hash_table_position(0xaa, 0), 0xac);
+        assert_eq!(read_only.data_block_position(0xaa), 0xad);
+
+        let writable = BlockLayout::new(false);
+        assert_eq!(writable.data_block_position(0), 2);
+        assert_eq!(writable.hash_table_position(0, 1), 0xac);
+        assert_eq!(writable.hash_table_position(0xaa, 0), 0xae);
+        assert_eq!(writable.data_block_position(0xaa), 0xb0);
+    }
+}
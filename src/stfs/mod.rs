@@ -0,0 +1,443 @@
+use std::io::{self, Read, Seek, SeekFrom};
+
+use anyhow::{Error, bail};
+use sha1::{Digest, Sha1};
+
+use crate::god::{ConHeader, VolumeType};
+use crate::iso::WindowsPath;
+
+pub mod file_table;
+pub mod hash_table;
+pub mod volume_descriptor;
+mod writer;
+
+pub use file_table::*;
+pub use hash_table::*;
+pub use volume_descriptor::*;
+pub use writer::*;
+
+/// Reads STFS packages: CON, LIVE and PIRS files with an STFS volume,
+/// such as arcade titles, downloadable content and title updates.
+pub struct StfsReader<R: Read + Seek> {
+    pub header: ConHeader,
+    pub volume_descriptor: StfsVolumeDescriptor,
+    /// In file table order, which `StfsFileEntry::parent` refers to
+    pub files: Vec<StfsFileEntry>,
+    layout: BlockLayout,
+    reader: R,
+}
+
+impl<R: Read + Seek> StfsReader<R> {
+    pub fn read(mut reader: R) -> Result<StfsReader<R>, Error> {
+        reader.seek(SeekFrom::Start(0))?;
+        let header = ConHeader::read(&mut reader)?;
+
+        if header.volume_type != VolumeType::Stfs {
+            bail!("not an STFS package");
+        }
+
+        let volume_descriptor = StfsVolumeDescriptor::parse(&header.volume_descriptor)?;
+        let layout = BlockLayout::new(volume_descriptor.is_read_only());
+
+        let mut stfs = StfsReader {
+            header,
+            volume_descriptor,
+            files: Vec::new(),
+            layout,
+            reader,
+        };
+
+        stfs.files = stfs.read_file_table()?;
+        Ok(stfs)
+    }
+
+    fn read_file_table(&mut self) -> Result<Vec<StfsFileEntry>, Error> {
+        let blocks = self.block_chain(
+            self.volume_descriptor.file_table_block_number,
+            self.volume_descriptor.file_table_block_count as u32,
+            false,
+        )?;
+
+        let mut files = Vec::new();
+        for block in blocks {
+            let data = self.read_data_block(block)?;
+            for bytes in data.chunks_exact(FILE_ENTRY_SIZE) {
+                match StfsFileEntry::parse(bytes)? {
+                    Some(entry) => files.push(entry),
+                    None => return Ok(files),
+                }
+            }
+        }
+
+        Ok(files)
+    }
+
+    fn read_raw_block(&mut self, position: u32) -> Result<Box<[u8; BLOCK_SIZE as usize]>, Error> {
+        let offset = self.header.data_offset() + (position as u64) * BLOCK_SIZE;
+        self.reader.seek(SeekFrom::Start(offset))?;
+
+        // the last block of a package may be cut short
+        let mut block = Box::new([0_u8; BLOCK_SIZE as usize]);
+        let mut len = 0;
+        while len < block.len() {
+            match self.reader.read(&mut block[len..])? {
+                0 => break,
+                n => len += n,
+            }
+        }
+        if len == 0 {
+            bail!("block {position:#x} is past the end of the package");
+        }
+
+        Ok(block)
+    }
+
+    pub fn read_data_block(&mut self, block: u32) -> Result<Box<[u8; BLOCK_SIZE as usize]>, Error> {
+        if block >= self.volume_descriptor.allocated_block_count {
+            bail!("data block {block:#x} is out of range");
+        }
+        self.read_raw_block(self.layout.data_block_position(block))
+    }
+
+    fn top_level(&self) -> u32 {
+        self.layout
+            .top_level(self.volume_descriptor.allocated_block_count)
+    }
+
+    /// Reads the active copy of the hash table at `level` that covers data block `block`
+    pub fn read_hash_table(
+        &mut self,
+        block: u32,
+        level: u32,
+    ) -> Result<Box<[u8; BLOCK_SIZE as usize]>, Error> {
+        let mut position = self.layout.hash_table_position(block, level);
+
+        if self.layout.table_copies() > 1 {
+            let second_copy = if level >= self.top_level() {
+                self.volume_descriptor.block_separation & 2 != 0
+            } else {
+                self.hash_entry(block, level + 1)?.status & HashEntry::ACTIVE_COPY != 0
+            };
+            position += second_copy as u32;
+        }
+
+        self.read_raw_block(position)
+    }
+
+    /// The hash table entry for data block `block` at `level`
+    pub fn hash_entry(&mut self, block: u32, level: u32) -> Result<HashEntry, Error> {
+        let table = self.read_hash_table(block, level)?;
+        let offset = self.layout.hash_entry_index(block, level) * HASH_ENTRY_SIZE;
+        Ok(HashEntry::parse(&table[offset..]))
+    }
+
+    fn block_chain(
+        &mut self,
+        first_block: u32,
+        block_count: u32,
+        consecutive: bool,
+    ) -> Result<Vec<u32>, Error> {
+        if consecutive {
+            return Ok((first_block..first_block + block_count).collect());
+        }
+
+        let mut blocks = Vec::with_capacity(block_count as usize);
+        let mut block = first_block;
+
+        for _ in 0..block_count {
+            if block >= self.volume_descriptor.allocated_block_count {
+                bail!("block chain leaves the volume at block {block:#x}");
+            }
+            blocks.push(block);
+            block = self.hash_entry(block, 0)?.next_block;
+        }
+
+        Ok(blocks)
+    }
+
+    /// Full path of a file table entry, with `\` as the separator
+    pub fn entry_path(&self, index: usize) -> String {
+        let mut components = Vec::new();
+        let mut index = Some(index);
+
+        // bounded, in case of a parent loop
+        for _ in 0..=self.files.len() {
+            let Some(entry) = index.and_then(|i| self.files.get(i)) else {
+                break;
+            };
+            components.push(entry.name.as_str());
+            index = Some(entry.parent as usize).filter(|_| entry.parent != ROOT_DIRECTORY);
+        }
+
+        components.reverse();
+        components.join("\\")
+    }
+
+    /// Index of the entry at `path` in `files`, ignoring ascii case
+    pub fn find_entry(&self, path: &WindowsPath) -> Option<usize> {
+        let path = path.components.join("\\");
+        (0..self.files.len()).find(|i| self.entry_path(*i).eq_ignore_ascii_case(&path))
+    }
+
+    pub fn open_entry(&mut self, index: usize) -> Result<StfsFileReader<'_, R>, Error> {
+        let Some(entry) = self.files.get(index).cloned() else {
+            bail!("no file table entry {index}");
+        };
+        if entry.is_directory() {
+            bail!("{} is a directory", self.entry_path(index));
+        }
+
+        let block_count = entry.size.div_ceil(BLOCK_SIZE as u32);
+        let blocks = self.block_chain(
+            entry.first_block,
+            block_count,
+            entry.flags.contains(FileEntryFlags::CONSECUTIVE),
+        )?;
+
+        Ok(StfsFileReader {
+            stfs: self,
+            blocks,
+            size: entry.size as u64,
+            position: 0,
+        })
+    }
+
+    pub fn get_entry(
+        &mut self,
+        path: &WindowsPath,
+    ) -> Result<Option<StfsFileReader<'_, R>>, Error> {
+        match self.find_entry(path) {
+            Some(index) => Ok(Some(self.open_entry(index)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Reads the whole contents of a file into memory.
+    pub fn read_entry(&mut self, path: &WindowsPath) -> Result<Option<Vec<u8>>, Error> {
+        let Some(mut reader) = self.get_entry(path)? else {
+            return Ok(None);
+        };
+
+        let mut buf = Vec::with_capacity(reader.size as usize);
+        reader.read_to_end(&mut buf)?;
+        Ok(Some(buf))
+    }
+
+    /// Checks the header digest, and the hash tree over the file table and
+    /// all file data. Does not check the signature.
+    pub fn verify(&mut self) -> Result<(), Error> {
+        if !self.header.verify_header_hash() {
+            bail!("header hash mismatch");
+        }
+
+        let top_level = self.top_level();
+        let top_table = self.read_hash_table(0, top_level)?;
+        if Sha1::digest(&top_table[..]).as_slice() != self.volume_descriptor.top_hash_table_hash {
+            bail!("top hash table hash mismatch");
+        }
+
+        for level in 0..top_level {
+            let span = HASHES_PER_TABLE.pow(level + 1);
+            let mut block = 0;
+            while block < self.volume_descriptor.allocated_block_count {
+                let table = self.read_hash_table(block, level)?;
+                let expected = self.hash_entry(block, level + 1)?.hash;
+                if Sha1::digest(&table[..]).as_slice() != expected {
+                    bail!("level {level} hash table for block {block:#x} does not match its hash");
+                }
+                block += span;
+            }
+        }
+
+        let mut chains = vec![(
+            self.volume_descriptor.file_table_block_number,
+            self.volume_descriptor.file_table_block_count as u32,
+            false,
+        )];
+        for entry in self.files.iter().filter(|e| !e.is_directory()) {
+            chains.push((
+                entry.first_block,
+                entry.size.div_ceil(BLOCK_SIZE as u32),
+                entry.flags.contains(FileEntryFlags::CONSECUTIVE),
+            ));
+        }
+
+        for (first_block, block_count, consecutive) in chains {
+            for block in self.block_chain(first_block, block_count, consecutive)? {
+                let data = self.read_data_block(block)?;
+                if Sha1::digest(&data[..]).as_slice() != self.hash_entry(block, 0)?.hash {
+                    bail!("data block {block:#x} does not match its hash");
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Reads a single file out of an STFS package
+pub struct StfsFileReader<'a, R: Read + Seek> {
+    stfs: &'a mut StfsReader<R>,
+    blocks: Vec<u32>,
+    size: u64,
+    position: u64,
+}
+
+impl<R: Read + Seek> StfsFileReader<'_, R> {
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+}
+
+impl<R: Read + Seek> Read for StfsFileReader<'_, R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.position >= self.size || buf.is_empty() {
+            return Ok(0);
+        }
+
+        let block = self.blocks[(self.position / BLOCK_SIZE) as usize];
+        let offset_in_block = self.position % BLOCK_SIZE;
+        let len = (BLOCK_SIZE - offset_in_block)
+            .min(self.size - self.position)
+            .min(buf.len() as u64) as usize;
+
+        let offset = self.stfs.header.data_offset()
+            + (self.stfs.layout.data_block_position(block) as u64) * BLOCK_SIZE
+            + offset_in_block;
+        self.stfs.reader.seek(SeekFrom::Start(offset))?;
+        self.stfs.reader.read_exact(&mut buf[..len])?;
+
+        self.position += len as u64;
+        Ok(len)
+    }
+}
+
+impl<R: Read + Seek> Seek for StfsFileReader<'_, R> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let position = match pos {
+            SeekFrom::Start(p) => Some(p),
+            SeekFrom::End(d) => self.size.checked_add_signed(d),
+            SeekFrom::Current(d) => self.position.checked_add_signed(d),
+        };
+
+        match position {
+            Some(p) => {
+                self.position = p;
+                Ok(p)
+            }
+            None => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "seek to a negative position",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::god::{ConHeaderBuilder, ContentType};
+    use std::io::Cursor;
+
+    /// A read-only package with `file_data` in `\dir\file.bin`, spread over
+    /// non-consecutive blocks, with the file table in block 0.
+    fn build_package(file_data: &[u8]) -> Vec<u8> {
+        let file_block_count = file_data.len().div_ceil(BLOCK_SIZE as usize) as u32;
+        let block_count = 1 + file_block_count;
+
+        let mut blocks = vec![[0_u8; BLOCK_SIZE as usize]; block_count as usize];
+
+        let dir = StfsFileEntry {
+            name: "dir".to_owned(),
+            flags: FileEntryFlags::DIRECTORY,
+            block_count: 0,
+            first_block: 0,
+            parent: ROOT_DIRECTORY,
+            size: 0,
+            update_time: 0,
+            access_time: 0,
+        };
+        let file = StfsFileEntry {
+            name: "file.bin".to_owned(),
+            flags: FileEntryFlags::empty(),
+            block_count: file_block_count,
+            first_block: 1,
+            parent: 0,
+            size: file_data.len() as u32,
+            update_time: 0,
+            access_time: 0,
+        };
+        dir.write(&mut blocks[0][0x00..]).unwrap();
+        file.write(&mut blocks[0][0x40..]).unwrap();
+
+        for (i, chunk) in file_data.chunks(BLOCK_SIZE as usize).enumerate() {
+            blocks[1 + i][..chunk.len()].copy_from_slice(chunk);
+        }
+
+        let mut table = [0_u8; BLOCK_SIZE as usize];
+        for (i, block) in blocks.iter().enumerate() {
+            let next_block = if i == 0 || i == blocks.len() - 1 {
+                HashEntry::END_OF_CHAIN
+            } else {
+                i as u32 + 1
+            };
+            HashEntry {
+                hash: Sha1::digest(block).into(),
+                status: HashEntry::USED,
+                next_block,
+            }
+            .write(&mut table[i * HASH_ENTRY_SIZE..]);
+        }
+
+        let volume_descriptor = StfsVolumeDescriptor {
+            block_separation: 1,
+            file_table_block_count: 1,
+            file_table_block_number: 0,
+            top_hash_table_hash: Sha1::digest(table).into(),
+            allocated_block_count: block_count,
+            unallocated_block_count: 0,
+        };
+
+        let mut package = ConHeaderBuilder::new()
+            .with_content_type(ContentType::ArcadeTitle)
+            .with_game_title("Test Title")
+            .with_volume_descriptor(VolumeType::Stfs, &volume_descriptor.to_bytes())
+            .finalize();
+
+        package.extend_from_slice(&table);
+        for block in blocks.iter() {
+            package.extend_from_slice(block);
+        }
+        package
+    }
+
+    #[test]
+    fn reads_files_from_a_package() {
+        let file_data: Vec<u8> = (0..0x2345_u32).map(|i| (i * 7) as u8).collect();
+        let mut stfs = StfsReader::read(Cursor::new(build_package(&file_data))).unwrap();
+
+        assert_eq!(stfs.header.content_type, ContentType::ArcadeTitle);
+        assert_eq!(stfs.header.title_name, "Test Title");
+        assert_eq!(stfs.files.len(), 2);
+        assert_eq!(stfs.entry_path(1), "dir\\file.bin");
+        stfs.verify().unwrap();
+
+        let read = stfs.read_entry(&"\\DIR\\FILE.BIN".into()).unwrap().unwrap();
+        assert_eq!(read, file_data);
+
+        let mut reader = stfs.get_entry(&"dir\\file.bin".into()).unwrap().unwrap();
+        let mut buf = [0_u8; 4];
+        reader.seek(SeekFrom::Start(0x1ffe)).unwrap();
+        reader.read_exact(&mut buf).unwrap();
+        assert_eq!(buf, file_data[0x1ffe..0x2002]);
+    }
+
+    #[test]
+    fn verify_catches_corrupted_data() {
+        let mut package = build_package(&[1, 2, 3]);
+        *package.last_mut().unwrap() ^= 1;
+
+        let mut stfs = StfsReader::read(Cursor::new(package)).unwrap();
+        assert!(stfs.verify().is_err());
+    }
+}
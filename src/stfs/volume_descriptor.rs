@@ -0,0 +1,51 @@
+use anyhow::{Error, bail};
+use byteorder::{BE, ByteOrder, LE};
+
+use crate::god::VOLUME_DESCRIPTOR_SIZE;
+
+/// The STFS flavour of the CON header volume descriptor
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StfsVolumeDescriptor {
+    /// Bit 0 is set for read-only packages, which keep a single copy of each
+    /// hash table; bit 1 selects the active copy of the top table otherwise
+    pub block_separation: u8,
+    pub file_table_block_count: u16,
+    pub file_table_block_number: u32,
+    pub top_hash_table_hash: [u8; 20],
+    pub allocated_block_count: u32,
+    pub unallocated_block_count: u32,
+}
+
+impl StfsVolumeDescriptor {
+    pub fn parse(bytes: &[u8; VOLUME_DESCRIPTOR_SIZE]) -> Result<StfsVolumeDescriptor, Error> {
+        if bytes[0] as usize != VOLUME_DESCRIPTOR_SIZE {
+            bail!("invalid STFS volume descriptor size {:#x}", bytes[0]);
+        }
+
+        Ok(StfsVolumeDescriptor {
+            block_separation: bytes[0x02],
+            // sic! these two are little-endian, unlike everything around them
+            file_table_block_count: LE::read_u16(&bytes[0x03..]),
+            file_table_block_number: LE::read_u24(&bytes[0x05..]),
+            top_hash_table_hash: bytes[0x08..0x1c].try_into().unwrap(),
+            allocated_block_count: BE::read_u32(&bytes[0x1c..]),
+            unallocated_block_count: BE::read_u32(&bytes[0x20..]),
+        })
+    }
+
+    pub fn to_bytes(&self) -> [u8; VOLUME_DESCRIPTOR_SIZE] {
+        let mut bytes = [0_u8; VOLUME_DESCRIPTOR_SIZE];
+        bytes[0x00] = VOLUME_DESCRIPTOR_SIZE as u8;
+        bytes[0x02] = self.block_separation;
+        LE::write_u16(&mut bytes[0x03..], self.file_table_block_count);
+        LE::write_u24(&mut bytes[0x05..], self.file_table_block_number);
+        bytes[0x08..0x1c].copy_from_slice(&self.top_hash_table_hash);
+        BE::write_u32(&mut bytes[0x1c..], self.allocated_block_count);
+        BE::write_u32(&mut bytes[0x20..], self.unallocated_block_count);
+        bytes
+    }
+
+    pub fn is_read_only(&self) -> bool {
+        self.block_separation & 1 != 0
+    }
+}
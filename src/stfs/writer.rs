@@ -0,0 +1,301 @@
+use std::fs::{self, File};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Error, bail};
+use sha1::{Digest, Sha1};
+
+use crate::god::{ConHeaderBuilder, VolumeType};
+
+use super::*;
+
+/// Data offset of packages written from the `ConHeaderBuilder` template
+const DATA_OFFSET: u64 = 0xb000;
+
+/// Three levels of hash tables cover this many data blocks
+const MAX_BLOCK_COUNT: u32 = HASHES_PER_TABLE * HASHES_PER_TABLE * HASHES_PER_TABLE;
+
+struct PendingEntry {
+    entry: StfsFileEntry,
+    source: Option<PathBuf>,
+}
+
+/// Writes the contents of `source_dir` as a read-only STFS package.
+///
+/// `header` should already have the package type, content type, title ID,
+/// display name and icon set; the volume descriptor and content size are
+/// filled in here. Files are stored in consecutive blocks, in name order.
+pub fn write_stfs_package<W: Write + Seek>(
+    source_dir: &Path,
+    header: ConHeaderBuilder,
+    mut package: W,
+) -> Result<(), Error> {
+    let mut entries = Vec::new();
+    collect_entries(source_dir, ROOT_DIRECTORY, &mut entries)?;
+
+    if entries.len() >= ROOT_DIRECTORY as usize {
+        bail!("too many files for an STFS package");
+    }
+
+    let file_table_block_count = entries.len().div_ceil(FILE_ENTRIES_PER_BLOCK).max(1) as u32;
+
+    let mut block_count = file_table_block_count;
+    for pending in entries.iter_mut().filter(|p| !p.entry.is_directory()) {
+        pending.entry.block_count = pending.entry.size.div_ceil(BLOCK_SIZE as u32);
+        if pending.entry.block_count > 0 {
+            pending.entry.first_block = block_count;
+        }
+        block_count = block_count
+            .checked_add(pending.entry.block_count)
+            .filter(|c| *c <= MAX_BLOCK_COUNT)
+            .context("the files do not fit into an STFS package")?;
+    }
+
+    let layout = BlockLayout::new(true);
+    let mut block_hashes = Vec::with_capacity(block_count as usize);
+
+    let mut write_block = |package: &mut W, block: u32, data: &[u8]| -> Result<(), Error> {
+        let mut buf = [0_u8; BLOCK_SIZE as usize];
+        buf[..data.len()].copy_from_slice(data);
+
+        let position = layout.data_block_position(block) as u64;
+        package.seek(SeekFrom::Start(DATA_OFFSET + position * BLOCK_SIZE))?;
+        package.write_all(&buf)?;
+
+        block_hashes.push(Sha1::digest(buf).into());
+        Ok(())
+    };
+
+    let mut file_table = vec![0_u8; file_table_block_count as usize * BLOCK_SIZE as usize];
+    for (pending, bytes) in entries
+        .iter()
+        .zip(file_table.chunks_exact_mut(FILE_ENTRY_SIZE))
+    {
+        pending.entry.write(bytes)?;
+    }
+
+    for (i, data) in file_table.chunks(BLOCK_SIZE as usize).enumerate() {
+        write_block(&mut package, i as u32, data)?;
+    }
+
+    for pending in entries.iter() {
+        let Some(source) = &pending.source else {
+            continue;
+        };
+
+        let mut file =
+            File::open(source).with_context(|| format!("error opening {}", source.display()))?;
+
+        let mut buf = vec![0_u8; BLOCK_SIZE as usize];
+        for i in 0..pending.entry.block_count {
+            let len = read_full(&mut file, &mut buf)
+                .with_context(|| format!("error reading {}", source.display()))?;
+            write_block(&mut package, pending.entry.first_block + i, &buf[..len])?;
+        }
+    }
+
+    if block_hashes.len() != block_count as usize {
+        bail!("files changed while being packaged");
+    }
+
+    let chain_ends = chain_ends(&entries, file_table_block_count);
+    let top_hash_table_hash = write_hash_tables(&mut package, &layout, &block_hashes, |block| {
+        chain_ends.binary_search(&block).is_ok()
+    })?;
+
+    let volume_descriptor = StfsVolumeDescriptor {
+        block_separation: 1,
+        file_table_block_count: file_table_block_count as u16,
+        file_table_block_number: 0,
+        top_hash_table_hash,
+        allocated_block_count: block_count,
+        unallocated_block_count: 0,
+    };
+
+    let header = header
+        .with_volume_descriptor(VolumeType::Stfs, &volume_descriptor.to_bytes())
+        .with_content_size(block_count as u64 * BLOCK_SIZE)
+        .finalize();
+
+    if header.len() as u64 != DATA_OFFSET {
+        bail!("unexpected CON header size {:#x}", header.len());
+    }
+
+    package.seek(SeekFrom::Start(0))?;
+    package.write_all(&header)?;
+    package.flush()?;
+
+    Ok(())
+}
+
+/// Directories come right before their contents, so parents always precede children
+fn collect_entries(dir: &Path, parent: u16, entries: &mut Vec<PendingEntry>) -> Result<(), Error> {
+    let mut dir_entries = fs::read_dir(dir)
+        .with_context(|| format!("error reading {}", dir.display()))?
+        .collect::<Result<Vec<_>, _>>()?;
+    dir_entries.sort_by_key(|e| e.file_name());
+
+    for dir_entry in dir_entries {
+        let path = dir_entry.path();
+        let Some(name) = dir_entry.file_name().to_str().map(String::from) else {
+            bail!("file name {} is not valid UTF-8", path.display());
+        };
+
+        let metadata = dir_entry.metadata()?;
+        let is_directory = metadata.is_dir();
+
+        let size = if is_directory {
+            0
+        } else {
+            u32::try_from(metadata.len())
+                .with_context(|| format!("{} is too large", path.display()))?
+        };
+
+        let index = entries.len() as u16;
+        entries.push(PendingEntry {
+            entry: StfsFileEntry {
+                name,
+                flags: if is_directory {
+                    FileEntryFlags::DIRECTORY
+                } else {
+                    FileEntryFlags::CONSECUTIVE
+                },
+                block_count: 0,
+                first_block: 0,
+                parent,
+                size,
+                update_time: 0,
+                access_time: 0,
+            },
+            source: Some(path.clone()).filter(|_| !is_directory),
+        });
+
+        if is_directory {
+            collect_entries(&path, index, entries)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// The last block of each chain, sorted, for the level 0 link fields
+fn chain_ends(entries: &[PendingEntry], file_table_block_count: u32) -> Vec<u32> {
+    let mut ends: Vec<u32> = entries
+        .iter()
+        .map(|p| &p.entry)
+        .filter(|e| e.block_count > 0)
+        .map(|e| e.first_block + e.block_count - 1)
+        .chain([file_table_block_count - 1])
+        .collect();
+    ends.sort();
+    ends
+}
+
+/// Writes all levels of hash tables, returning the hash of the topmost one
+fn write_hash_tables<W: Write + Seek>(
+    package: &mut W,
+    layout: &BlockLayout,
+    block_hashes: &[[u8; 20]],
+    is_chain_end: impl Fn(u32) -> bool,
+) -> Result<[u8; 20], Error> {
+    let block_count = block_hashes.len() as u32;
+    let top_level = layout.top_level(block_count);
+
+    let mut level_entries: Vec<HashEntry> = block_hashes
+        .iter()
+        .enumerate()
+        .map(|(block, hash)| HashEntry {
+            hash: *hash,
+            status: HashEntry::USED,
+            next_block: if is_chain_end(block as u32) {
+                HashEntry::END_OF_CHAIN
+            } else {
+                block as u32 + 1
+            },
+        })
+        .collect();
+
+    for level in 0..=top_level {
+        let span = HASHES_PER_TABLE.pow(level);
+        let mut table_hashes = Vec::new();
+
+        for (i, chunk) in level_entries.chunks(HASHES_PER_TABLE as usize).enumerate() {
+            let mut table = [0_u8; BLOCK_SIZE as usize];
+            for (entry, bytes) in chunk.iter().zip(table.chunks_exact_mut(HASH_ENTRY_SIZE)) {
+                entry.write(bytes);
+            }
+
+            let first_block = i as u32 * HASHES_PER_TABLE * span;
+            let position = layout.hash_table_position(first_block, level) as u64;
+            package.seek(SeekFrom::Start(DATA_OFFSET + position * BLOCK_SIZE))?;
+            package.write_all(&table)?;
+
+            table_hashes.push(HashEntry {
+                hash: Sha1::digest(table).into(),
+                status: 0,
+                next_block: 0,
+            });
+        }
+
+        level_entries = table_hashes;
+    }
+
+    Ok(level_entries[0].hash)
+}
+
+/// Like `read_exact`, but stops early at the end of the file
+fn read_full<R: Read>(reader: &mut R, buf: &mut [u8]) -> io::Result<usize> {
+    let mut len = 0;
+    while len < buf.len() {
+        match reader.read(&mut buf[len..])? {
+            0 => break,
+            n => len += n,
+        }
+    }
+    Ok(len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::god::{ContentType, PackageType};
+    use std::io::Cursor;
+
+    #[test]
+    fn written_packages_read_back() {
+        let source_dir = tempfile::tempdir().unwrap();
+        let source_dir = source_dir.path();
+        fs::create_dir_all(source_dir.join("media/empty_dir")).unwrap();
+
+        // large enough to need a level 1 hash table
+        let big: Vec<u8> = (0..0xab_u32 * 0x1000 + 5)
+            .map(|i| (i % 251) as u8)
+            .collect();
+        fs::write(source_dir.join("default.xex"), b"XEX2 not really").unwrap();
+        fs::write(source_dir.join("media/big.bin"), &big).unwrap();
+        fs::write(source_dir.join("media/empty.txt"), b"").unwrap();
+
+        let header = ConHeaderBuilder::new()
+            .with_package_type(PackageType::Pirs)
+            .with_content_type(ContentType::MarketplaceContent)
+            .with_title_id(0x4d5307e6)
+            .with_game_title("Test Content");
+
+        let mut package = Cursor::new(Vec::new());
+        write_stfs_package(source_dir, header, &mut package).unwrap();
+
+        let mut stfs = StfsReader::read(Cursor::new(package.into_inner())).unwrap();
+        stfs.verify().unwrap();
+
+        assert_eq!(stfs.header.package_type, PackageType::Pirs);
+        assert_eq!(stfs.header.content_type, ContentType::MarketplaceContent);
+        assert_eq!(stfs.header.execution_info.title_id, 0x4d5307e6);
+        assert_eq!(stfs.files.len(), 5);
+
+        let read = |stfs: &mut StfsReader<_>, path: &str| stfs.read_entry(&path.into()).unwrap();
+        assert_eq!(read(&mut stfs, "default.xex").unwrap(), b"XEX2 not really");
+        assert_eq!(read(&mut stfs, "media\\big.bin").unwrap(), big);
+        assert_eq!(read(&mut stfs, "media\\empty.txt").unwrap(), b"");
+        assert!(stfs.find_entry(&"media\\empty_dir".into()).is_some());
+    }
+}